@@ -45,6 +45,7 @@ export default async function Applications(context: GeneratorContext<object, Ent
                             ),
                             accessories: applicationAccessories(id, experimentalWindowTracking),
                             icon: data.icon, // TODO lazy icons
+                            keywords: data.keywords,
                             "__linux__": {
                                 startupWmClass: data.startup_wm_class,
                                 desktopFilePath: data.desktop_file_path
@@ -63,6 +64,7 @@ export default async function Applications(context: GeneratorContext<object, Ent
                             ),
                             accessories: applicationAccessories(id, experimentalWindowTracking),
                             icon: data.icon, // TODO lazy icons
+                            keywords: data.keywords,
                             "__linux__": {
                                 startupWmClass: data.startup_wm_class,
                                 desktopFilePath: data.desktop_file_path
@@ -30,6 +30,7 @@ export interface GeneratedEntrypoint {
     actions: GeneratedEntrypointAction[]
     icon?: ArrayBuffer
     accessories?: GeneratedEntrypointAccessory[]
+    keywords?: string[]
 }
 
 export type GeneratedEntrypointAction = GeneratedEntrypointActionRun | GeneratedEntrypointActionView
@@ -37,13 +38,18 @@ export type GeneratedEntrypointAction = GeneratedEntrypointActionRun | Generated
 export interface GeneratedEntrypointActionRun {
     ref?: string
     label: string
-    run: () => void
+    run: (context: GeneratedEntrypointActionRunContext) => void
+}
+
+export type GeneratedEntrypointActionRunContext = {
+    args: { [key: string]: string },
+    query?: string,
 }
 
 export interface GeneratedEntrypointActionView {
     ref?: string
     label: string
-    view: FC
+    view: FC<ViewProps>
 }
 
 export type GeneratedEntrypointAccessory = GeneratedEntrypointTextAccessory | GeneratedEntrypointIconAccessory;
@@ -68,9 +74,16 @@ export type GeneratorContext<P = object, E = object> = {
     entrypointPreferences: E,
 };
 
+// props passed to view entrypoint, query is set if view was opened using keyword alias
+export type ViewProps = {
+    query?: string,
+};
+
 export type CommandContext<P = object, E = object> = {
     pluginPreferences: P,
     entrypointPreferences: E,
+    args: { [key: string]: string },
+    query?: string,
 };
 
 export const Clipboard: Clipboard = {
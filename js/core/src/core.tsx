@@ -88,8 +88,8 @@ export async function runPluginLoop() {
                         break;
                     }
 
-                    const view: FC = (await import(`gauntlet:entrypoint?${entrypointId}`)).default;
-                    renderView(entrypointId, getEntrypointName(entrypointId), view)
+                    const view: FC<{ query?: string }> = (await import(`gauntlet:entrypoint?${entrypointId}`)).default;
+                    renderView(entrypointId, getEntrypointName(entrypointId), view, pluginEvent.query ?? undefined)
                 } catch (e) {
                     console.error("Error occurred when rendering view", entrypointId, e)
                     show_plugin_error_view(entrypointId, "View")
@@ -119,13 +119,17 @@ export async function runPluginLoop() {
                     type CommandContext<P = object, E = object> = {
                         pluginPreferences: P,
                         entrypointPreferences: E,
+                        args: { [key: string]: string },
+                        query?: string,
                     };
 
                     const pluginPreferences = get_plugin_preferences();
                     const entrypointPreferences = get_entrypoint_preferences(pluginEvent.entrypointId);
+                    const args = pluginEvent.args;
+                    const query = pluginEvent.query ?? undefined;
 
                     const command: (context: CommandContext) => Promise<void> | void = (await import(`gauntlet:entrypoint?${pluginEvent.entrypointId}`)).default;
-                    command({ pluginPreferences, entrypointPreferences })
+                    command({ pluginPreferences, entrypointPreferences, args, query })
                 } catch (e) {
                     console.error("Error occurred when running a command", pluginEvent.entrypointId, e)
                 }
@@ -133,7 +137,7 @@ export async function runPluginLoop() {
             }
             case "RunGeneratedEntrypoint": {
                 try {
-                    runGeneratedEntrypoint(pluginEvent.entrypointId, pluginEvent.actionIndex)
+                    runGeneratedEntrypoint(pluginEvent.entrypointId, pluginEvent.actionIndex, pluginEvent.args, pluginEvent.query ?? undefined)
                 } catch (e) {
                     console.error("Error occurred when running a generated command", pluginEvent.entrypointId, e)
                 }
@@ -16,6 +16,7 @@ interface GeneratedEntrypoint { // TODO is it possible to import api here
     actions: GeneratedEntrypointAction[]
     icon?: ArrayBuffer
     accessories?: GeneratedEntrypointAccessory[]
+    keywords?: string[]
 }
 
 type GeneratedEntrypointAction = GeneratedEntrypointActionRun | GeneratedEntrypointActionView
@@ -23,13 +24,18 @@ type GeneratedEntrypointAction = GeneratedEntrypointActionRun | GeneratedEntrypo
 interface GeneratedEntrypointActionRun {
     ref?: string
     label: string
-    run: () => void
+    run: (context: GeneratedEntrypointActionRunContext) => void
+}
+
+type GeneratedEntrypointActionRunContext = {
+    args: { [key: string]: string },
+    query?: string,
 }
 
 interface GeneratedEntrypointActionView {
     ref?: string
     label: string
-    view: FC
+    view: FC<{ query?: string }>
 }
 
 export type GeneratorContext<P = object, E = object> = {
@@ -57,14 +63,14 @@ interface GeneratedEntrypointDerivedActionRun {
     type: "Command"
     ref?: string
     label: string
-    run: () => void
+    run: (context: GeneratedEntrypointActionRunContext) => void
 }
 
 interface GeneratedEntrypointDerivedActionView {
     type: "View"
     ref?: string
     label: string
-    view: FC
+    view: FC<{ query?: string }>
 }
 
 
@@ -211,7 +217,8 @@ export function generatedEntrypointSearchIndex(): GeneratedSearchItem[] {
                 action_type: action.type,
                 label: action.label
             })),
-        entrypoint_accessories: value.command.accessories || []
+        entrypoint_accessories: value.command.accessories || [],
+        entrypoint_keywords: value.command.keywords || []
     }))
 }
 
@@ -223,19 +230,19 @@ export async function runGeneratedEntrypointAction(entrypointId: string, key: st
         if (id) {
             const action = command.derivedActions.find(value => value.ref == id);
             if (action) {
-                runAction(entrypointId, action)
+                runAction(entrypointId, action, { args: {} })
             }
         }
     }
 }
 
-export function runGeneratedEntrypoint(entrypointId: string, action_index: number) {
+export function runGeneratedEntrypoint(entrypointId: string, action_index: number, args: { [key: string]: string }, query: string | undefined) {
     const generatedEntrypoint = storedGeneratedEntrypoints[entrypointId];
 
     if (generatedEntrypoint) {
         const action = generatedEntrypoint.derivedActions[action_index];
         if (action) {
-            runAction(entrypointId, action)
+            runAction(entrypointId, action, { args, query })
         } else {
             throw new Error("Generated command with entrypoint id '" + entrypointId + "' doesn't have action with index: " + action_index)
         }
@@ -244,10 +251,10 @@ export function runGeneratedEntrypoint(entrypointId: string, action_index: numbe
     }
 }
 
-function runAction(entrypointId: string, action: GeneratedEntrypointDerivedAction) {
+function runAction(entrypointId: string, action: GeneratedEntrypointDerivedAction, context: GeneratedEntrypointActionRunContext) {
     switch (action.type) {
         case "Command": {
-            action.run()
+            action.run(context)
 
             break;
         }
@@ -256,7 +263,7 @@ function runAction(entrypointId: string, action: GeneratedEntrypointDerivedActio
                 .command
                 .name
 
-            renderView(entrypointId, entrypointName, action.view)
+            renderView(entrypointId, entrypointName, action.view, context.query)
             break;
         }
     }
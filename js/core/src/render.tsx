@@ -9,9 +9,10 @@ import type { FC } from "react";
 let latestRootUiWidget: UiWidget | undefined = undefined
 let latestRootUiRenderLocation: RenderLocation | undefined = undefined
 
-export function renderView(entrypointId: string, entrypointName: string, View: FC) {
+// query is the rest of main search bar text if view was opened using keyword alias
+export function renderView(entrypointId: string, entrypointName: string, View: FC<{ query?: string }>, query: string | undefined) {
     latestRootUiRenderLocation = "View";
-    latestRootUiWidget = render(entrypointId, entrypointName, "View", <View/>);
+    latestRootUiWidget = render(entrypointId, entrypointName, "View", <View query={query}/>);
 }
 
 export function popMainView() {
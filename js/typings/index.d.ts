@@ -19,6 +19,7 @@ type LinuxDesktopApplicationData = {
     icon: ArrayBuffer | undefined,
     desktop_file_path: string,
     startup_wm_class: string | undefined,
+    keywords: string[],
 }
 
 type MacOSDesktopApplicationData = {
@@ -72,6 +73,7 @@ type NotReactsKeyboardEvent = {
 type OpenView = {
     type: "OpenView"
     entrypointId: string
+    query: string | null
 }
 
 type CloseView = {
@@ -86,12 +88,16 @@ type PopView = {
 type RunCommand = {
     type: "RunCommand"
     entrypointId: string
+    args: { [key: string]: string }
+    query: string | null
 }
 
 type RunGeneratedEntrypoint = {
     type: "RunGeneratedEntrypoint"
     entrypointId: string
     actionIndex: number
+    args: { [key: string]: string }
+    query: string | null
 }
 
 type OpenInlineView = {
@@ -140,6 +146,7 @@ type GeneratedSearchItem = {
     entrypoint_icon: ArrayBuffer | undefined,
     entrypoint_actions: GeneratedSearchItemAction[],
     entrypoint_accessories: GeneratedEntrypointAccessory[],
+    entrypoint_keywords: string[],
 }
 
 type GeneratedSearchItemAction = {
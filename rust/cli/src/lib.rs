@@ -1,15 +1,32 @@
 use std::backtrace::Backtrace;
 use std::fs::File;
 use std::io::Write;
+use std::path::PathBuf;
 use std::process::exit;
 use std::time::SystemTime;
 use std::time::UNIX_EPOCH;
 
 use clap::Parser;
+use gauntlet_common::cli::doctor;
+use gauntlet_common::cli::events;
+use gauntlet_common::cli::export_frecency;
+use gauntlet_common::cli::import_frecency;
+use gauntlet_common::cli::install_plugin;
 use gauntlet_common::cli::is_server_running;
+use gauntlet_common::cli::list_actions;
+use gauntlet_common::cli::list_entrypoints;
+use gauntlet_common::cli::list_plugins;
 use gauntlet_common::cli::open_settings_window;
 use gauntlet_common::cli::open_window;
+use gauntlet_common::cli::quit;
+use gauntlet_common::cli::reload_plugins;
+use gauntlet_common::cli::remove_plugin;
+use gauntlet_common::cli::restart;
 use gauntlet_common::cli::run_action;
+use gauntlet_common::cli::run_history;
+use gauntlet_common::cli::search;
+use gauntlet_common::cli::set_plugin_state;
+use gauntlet_common::cli::sync_plugins;
 use gauntlet_common::dirs::Dirs;
 use gauntlet_server::PLUGIN_CONNECT_ENV;
 use gauntlet_server::PLUGIN_UUID_ENV;
@@ -52,6 +69,174 @@ enum Commands {
         /// `:primary` (action run with Enter shortcut) or
         /// `:secondary` (action run with Shift+Enter shortcut)
         action_id: String,
+
+        /// Argument passed to the command, can be specified multiple times.
+        /// Available in plugin as `args` property of command context
+        #[arg(long = "arg", value_name = "KEY=VALUE", value_parser = parse_key_value)]
+        arguments: Vec<(String, String)>,
+
+        /// Search text passed to the command.
+        /// Available in plugin as `query` property of command context
+        #[arg(long)]
+        query: Option<String>,
+    },
+    /// Search the same way as main window does and print results
+    Search {
+        /// Search query
+        query: String,
+
+        /// Print results as JSON instead of plain text
+        #[arg(long)]
+        json: bool,
+    },
+    /// Inspect and manage installed plugins
+    #[command(visible_alias = "plugin")]
+    Plugins {
+        #[command(subcommand)]
+        command: PluginsCommands,
+    },
+    /// Inspect entrypoints of specific plugin
+    Entrypoints {
+        #[command(subcommand)]
+        command: EntrypointsCommands,
+    },
+    /// Inspect actions of specific entrypoint of specific plugin
+    Actions {
+        #[command(subcommand)]
+        command: ActionsCommands,
+    },
+    /// Export or import frecency (how often and how recently entrypoints were used) used to order search results
+    Frecency {
+        #[command(subcommand)]
+        command: FrecencyCommands,
+    },
+    /// List most recently run entrypoints, most recent first.
+    /// How long history is kept can be changed in settings
+    History {
+        /// Maximum number of entrypoints to list
+        #[arg(long)]
+        limit: Option<usize>,
+
+        /// Print results as JSON instead of table
+        #[arg(long)]
+        json: bool,
+    },
+    /// Stop all plugins and exit running server
+    Quit,
+    /// Stop all plugins, exit running server and start it again with the same `--minimized` flag
+    Restart,
+    /// Check server, config, global shortcuts and plugins for problems.
+    /// Exits with non-zero code if any problem is found
+    Doctor {
+        /// Print report as JSON instead of plain text
+        #[arg(long)]
+        json: bool,
+    },
+    /// Print events happening in running server (window shown, entrypoint run, plugin crashed, etc.) until interrupted
+    Events {
+        /// Print each event as a single line JSON object instead of plain text
+        #[arg(long)]
+        json: bool,
+    },
+}
+
+#[derive(Debug, clap::Subcommand)]
+enum PluginsCommands {
+    /// List installed plugins
+    List {
+        /// Print results as JSON instead of table
+        #[arg(long)]
+        json: bool,
+    },
+    /// Download and install plugin, exits with non-zero code if download fails.
+    /// Installed plugin is disabled by default
+    Install {
+        /// Plugin ID, Git repository url of the plugin.
+        /// Branch, tag or commit can be selected by appending `#<ref>`, e.g. `https://github.com/user/plugin.git#v1.0.0`.
+        /// Alternatively, url of `.tar.gz` or `.zip` archive of built plugin, local (`file://`) or remote (`http(s)://`).
+        /// Expected sha256 hash of archive is specified by appending `#sha256=<hash>`, required for remote archives
+        plugin_id: String,
+    },
+    /// Remove installed plugin
+    Remove {
+        /// Plugin ID, can be found using `gauntlet plugins list`
+        plugin_id: String,
+    },
+    /// Enable plugin or specific entrypoint of plugin
+    Enable {
+        /// Plugin ID, can be found using `gauntlet plugins list`
+        plugin_id: String,
+
+        /// Entrypoint ID, can be found using `gauntlet entrypoints list <plugin-id>`.
+        /// If not specified, whole plugin is enabled
+        entrypoint_id: Option<String>,
+
+        /// Allow enabling plugin which is not signed by any of trusted keys
+        #[arg(long)]
+        allow_unsigned: bool,
+    },
+    /// Disable plugin or specific entrypoint of plugin
+    Disable {
+        /// Plugin ID, can be found using `gauntlet plugins list`
+        plugin_id: String,
+
+        /// Entrypoint ID, can be found using `gauntlet entrypoints list <plugin-id>`.
+        /// If not specified, whole plugin is disabled
+        entrypoint_id: Option<String>,
+    },
+    /// Start enabled plugins that are not running and stop disabled plugins that are running
+    Reload,
+    /// Install plugins and apply their settings from `[[plugins]]` list in config, waits until plugins are installed.
+    /// Also done automatically when server is started
+    Sync {
+        /// Only print changes which would be made, without making them
+        #[arg(long)]
+        dry_run: bool,
+    },
+}
+
+#[derive(Debug, clap::Subcommand)]
+enum FrecencyCommands {
+    /// Export frecency of all entrypoints as JSON
+    Export {
+        /// Write JSON to file instead of printing it
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+    /// Import frecency from JSON created by `gauntlet frecency export`.
+    /// Frecency of entrypoints present in the file is replaced, other entrypoints are left untouched
+    Import {
+        /// Path to JSON file
+        input: PathBuf,
+    },
+}
+
+#[derive(Debug, clap::Subcommand)]
+enum EntrypointsCommands {
+    /// List entrypoints of plugin, including generated ones
+    List {
+        /// Plugin ID, can be found using `gauntlet plugins list`
+        plugin_id: String,
+
+        /// Print results as JSON instead of table
+        #[arg(long)]
+        json: bool,
+    },
+}
+
+#[derive(Debug, clap::Subcommand)]
+enum ActionsCommands {
+    /// List actions of entrypoint which can be used with `gauntlet run`
+    List {
+        /// Plugin ID, can be found using `gauntlet plugins list`
+        plugin_id: String,
+
+        /// Entrypoint ID, can be found using `gauntlet entrypoints list <plugin-id>`
+        entrypoint_id: String,
+
+        /// Print results as JSON instead of table
+        #[arg(long)]
+        json: bool,
     },
 }
 
@@ -115,14 +300,89 @@ pub fn init() {
                     plugin_id,
                     entrypoint_id,
                     action_id,
+                    arguments,
+                    query,
                 } => {
-                    run_action(plugin_id, entrypoint_id, action_id);
+                    run_action(
+                        plugin_id,
+                        entrypoint_id,
+                        action_id,
+                        arguments.into_iter().collect(),
+                        query,
+                    );
+                }
+                Commands::Search { query, json } => exit_on_error(search(query, json)),
+                Commands::Plugins { command } => {
+                    match command {
+                        PluginsCommands::List { json } => exit_on_error(list_plugins(json)),
+                        PluginsCommands::Install { plugin_id } => exit_on_error(install_plugin(plugin_id)),
+                        PluginsCommands::Remove { plugin_id } => exit_on_error(remove_plugin(plugin_id)),
+                        PluginsCommands::Enable {
+                            plugin_id,
+                            entrypoint_id,
+                            allow_unsigned,
+                        } => exit_on_error(set_plugin_state(plugin_id, entrypoint_id, true, allow_unsigned)),
+                        PluginsCommands::Disable {
+                            plugin_id,
+                            entrypoint_id,
+                        } => exit_on_error(set_plugin_state(plugin_id, entrypoint_id, false, false)),
+                        PluginsCommands::Reload => exit_on_error(reload_plugins()),
+                        PluginsCommands::Sync { dry_run } => exit_on_error(sync_plugins(dry_run)),
+                    }
+                }
+                Commands::Entrypoints { command } => {
+                    match command {
+                        EntrypointsCommands::List { plugin_id, json } => {
+                            exit_on_error(list_entrypoints(plugin_id, json))
+                        }
+                    }
+                }
+                Commands::Actions { command } => {
+                    match command {
+                        ActionsCommands::List {
+                            plugin_id,
+                            entrypoint_id,
+                            json,
+                        } => exit_on_error(list_actions(plugin_id, entrypoint_id, json)),
+                    }
+                }
+                Commands::Frecency { command } => {
+                    match command {
+                        FrecencyCommands::Export { output } => exit_on_error(export_frecency(output)),
+                        FrecencyCommands::Import { input } => exit_on_error(import_frecency(input)),
+                    }
                 }
+                Commands::History { limit, json } => exit_on_error(run_history(limit, json)),
+                Commands::Quit => exit_on_error(quit()),
+                Commands::Restart => exit_on_error(restart()),
+                Commands::Doctor { json } => {
+                    match doctor(json) {
+                        Ok(true) => {}
+                        Ok(false) => exit(1),
+                        Err(err) => exit_on_error(Err(err)),
+                    }
+                }
+                Commands::Events { json } => exit_on_error(events(json)),
             };
         }
     }
 }
 
+fn parse_key_value(value: &str) -> Result<(String, String), String> {
+    let (key, value) = value
+        .split_once('=')
+        .ok_or_else(|| format!("invalid KEY=VALUE: no `=` found in `{}`", value))?;
+
+    Ok((key.to_string(), value.to_string()))
+}
+
+fn exit_on_error(result: anyhow::Result<()>) {
+    if let Err(err) = result {
+        tracing::error!("Error occurred when handling command: {:#}", err);
+        exit(1)
+    }
+}
+
 #[cfg(target_os = "macos")]
 fn setup_auto_launch_macos() -> anyhow::Result<()> {
     use anyhow::Context;
@@ -2,6 +2,8 @@ use std::collections::HashMap;
 use std::collections::HashSet;
 use std::sync::Arc;
 use std::sync::Mutex;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
 
 use client_context::ClientContext;
 use gauntlet_common::model::EntrypointId;
@@ -13,6 +15,7 @@ use gauntlet_common::model::RootWidget;
 use gauntlet_common::model::SearchResult;
 use gauntlet_common::model::SearchResultEntrypointActionType;
 use gauntlet_common::model::SearchResultEntrypointType;
+use gauntlet_common::model::SearchScope;
 use gauntlet_common::model::UiRenderLocation;
 use gauntlet_common::model::UiTheme;
 use gauntlet_common::model::UiWidgetId;
@@ -48,6 +51,7 @@ use iced::widget::column;
 use iced::widget::container;
 use iced::widget::horizontal_rule;
 use iced::widget::horizontal_space;
+use iced::widget::row;
 use iced::widget::scrollable;
 use iced::widget::text;
 use iced::widget::text::Shaping;
@@ -107,6 +111,7 @@ use crate::ui::state::LoadingBarState;
 use crate::ui::state::MainViewState;
 use crate::ui::state::PluginViewData;
 use crate::ui::state::PluginViewState;
+use crate::ui::state::main_view::pin_action_widget_id;
 use crate::ui::state::main_view::search_result_action_panel;
 use crate::ui::state::main_view::search_result_bot_panel_right_info;
 use crate::ui::widget::events::ComponentWidgetEvent;
@@ -116,6 +121,9 @@ use crate::ui::windows::WindowActionMsg;
 #[cfg(target_os = "linux")]
 use crate::ui::windows::x11_focus::x11_linux_focus_change_subscription;
 
+// search performed event is sent when user stops typing instead of on every keystroke
+const SEARCH_EVENT_DEBOUNCE: std::time::Duration = std::time::Duration::from_millis(500);
+
 pub struct AppModel {
     // logic
     application_manager: Arc<ApplicationManager>,
@@ -128,12 +136,14 @@ pub struct AppModel {
 
     // ephemeral state
     prompt: String,
+    search_scope: Option<SearchScope>,
 
     // state
     client_context: ClientContext,
     global_state: GlobalState,
     search_results: ScrollContent<SearchResult>,
     loading_bar_state: HashSet<(PluginId, EntrypointId)>,
+    restart_requested: Arc<AtomicBool>,
 }
 
 #[derive(Debug, Clone)]
@@ -141,11 +151,13 @@ pub enum AppMsg {
     OpenView {
         plugin_id: PluginId,
         entrypoint_id: EntrypointId,
+        query: Option<String>,
     },
     OpenGeneratedView {
         plugin_id: PluginId,
         entrypoint_id: EntrypointId,
         action_index: usize,
+        query: Option<String>,
     },
     OpenNewView {
         plugin_id: PluginId,
@@ -159,11 +171,17 @@ pub enum AppMsg {
     RunCommand {
         plugin_id: PluginId,
         entrypoint_id: EntrypointId,
+        query: Option<String>,
     },
-    RunGeneratedEntrypoint(PluginId, EntrypointId, usize),
-    RequestPluginViewOpen(PluginId, EntrypointId),
+    RunGeneratedEntrypoint(PluginId, EntrypointId, usize, Option<String>),
+    RequestPluginViewOpen(PluginId, EntrypointId, Option<String>),
     RequestPluginViewPop(PluginId, EntrypointId),
     RunSearchItemAction(SearchResult, usize),
+    SetSearchItemPinned {
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        pinned: bool,
+    },
     RunPluginAction {
         render_location: UiRenderLocation,
         plugin_id: PluginId,
@@ -173,7 +191,13 @@ pub enum AppMsg {
     PromptChanged(String),
     PromptSubmit,
     UpdateSearchResults,
-    SetSearchResults(Vec<SearchResult>),
+    SearchDebounced {
+        prompt: String,
+    },
+    SetSearchResults {
+        search_results: Vec<SearchResult>,
+        prompt_changed: bool,
+    },
     RenderPluginUI {
         plugin_id: PluginId,
         plugin_name: String,
@@ -195,6 +219,9 @@ pub enum AppMsg {
         widget_event: ComponentWidgetEvent,
     },
     Noop,
+    Exit {
+        restart: bool,
+    },
     HandleGlobalShortcut(GlobalShortcutPressedEvent),
     ToggleActionPanel {
         keyboard: bool,
@@ -288,7 +315,12 @@ pub enum AppMsg {
 }
 
 pub fn run(minimized: bool, scenario_runner_data: Option<ScenarioRunnerData>) {
-    let boot = move || new(minimized, scenario_runner_data.clone());
+    let restart_requested = Arc::new(AtomicBool::new(false));
+
+    let boot = {
+        let restart_requested = restart_requested.clone();
+        move || new(minimized, restart_requested.clone(), scenario_runner_data.clone())
+    };
 
     iced::daemon::<AppModel, AppMsg, GauntletComplexTheme, Renderer>(boot, update, view)
         .title(title)
@@ -306,9 +338,33 @@ pub fn run(minimized: bool, scenario_runner_data: Option<ScenarioRunnerData>) {
         .theme(|state, _| state.theme.clone())
         .run()
         .expect("Unable to start application");
+
+    // started only after previous instance stopped accepting connections,
+    // otherwise new instance would consider server to be already running
+    if restart_requested.load(Ordering::SeqCst) {
+        tracing::info!("Restarting...");
+
+        restart(minimized);
+    }
 }
 
-fn new(minimized: bool, #[allow(unused)] scenario_runner_data: Option<ScenarioRunnerData>) -> (AppModel, Task<AppMsg>) {
+fn restart(minimized: bool) {
+    let current_exe = std::env::current_exe().expect("unable to get current_exe");
+
+    let mut command = std::process::Command::new(current_exe);
+
+    if minimized {
+        command.arg("--minimized");
+    }
+
+    command.spawn().expect("unable to start new instance");
+}
+
+fn new(
+    minimized: bool,
+    restart_requested: Arc<AtomicBool>,
+    #[allow(unused)] scenario_runner_data: Option<ScenarioRunnerData>,
+) -> (AppModel, Task<AppMsg>) {
     #[cfg(target_os = "linux")]
     let wayland = std::env::var("WAYLAND_DISPLAY")
         .or_else(|_| std::env::var("WAYLAND_SOCKET"))
@@ -371,12 +427,14 @@ fn new(minimized: bool, #[allow(unused)] scenario_runner_data: Option<ScenarioRu
 
             // ephemeral state
             prompt: "".to_string(),
+            search_scope: None,
 
             // state
             global_state,
             client_context,
             search_results: ScrollContent::new(vec![]),
             loading_bar_state: HashSet::new(),
+            restart_requested,
         },
         Task::batch(tasks),
     )
@@ -396,6 +454,7 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
         AppMsg::OpenView {
             plugin_id,
             entrypoint_id,
+            query,
         } => {
             Task::batch([
                 GlobalState::pending_plugin_main_view(
@@ -403,7 +462,7 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
                     plugin_id.clone(),
                     entrypoint_id.clone(),
                 ),
-                Task::done(AppMsg::RequestPluginViewOpen(plugin_id, entrypoint_id)),
+                Task::done(AppMsg::RequestPluginViewOpen(plugin_id, entrypoint_id, query)),
                 Task::done(AppMsg::PendingPluginViewLoadingBar),
             ])
         }
@@ -413,7 +472,7 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
         } => {
             Task::batch([
                 GlobalState::pending_plugin(&mut state.global_state, plugin_id.clone(), entrypoint_id.clone()),
-                Task::done(AppMsg::RequestPluginViewOpen(plugin_id, entrypoint_id)),
+                Task::done(AppMsg::RequestPluginViewOpen(plugin_id, entrypoint_id, None)),
                 Task::done(AppMsg::WindowAction(WindowActionMsg::ShowWindow)),
             ])
         }
@@ -421,6 +480,7 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
             plugin_id,
             entrypoint_id,
             action_index,
+            query,
         } => {
             Task::batch([
                 GlobalState::pending_plugin_main_view(
@@ -428,7 +488,12 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
                     plugin_id.clone(),
                     entrypoint_id.clone(),
                 ),
-                Task::done(AppMsg::RunGeneratedEntrypoint(plugin_id, entrypoint_id, action_index)),
+                Task::done(AppMsg::RunGeneratedEntrypoint(
+                    plugin_id,
+                    entrypoint_id,
+                    action_index,
+                    query,
+                )),
                 Task::done(AppMsg::PendingPluginViewLoadingBar),
             ])
         }
@@ -439,26 +504,32 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
         } => {
             Task::batch([
                 GlobalState::pending_plugin(&mut state.global_state, plugin_id.clone(), entrypoint_id.clone()),
-                Task::done(AppMsg::RunGeneratedEntrypoint(plugin_id, entrypoint_id, action_index)),
+                Task::done(AppMsg::RunGeneratedEntrypoint(
+                    plugin_id,
+                    entrypoint_id,
+                    action_index,
+                    None,
+                )),
                 Task::done(AppMsg::WindowAction(WindowActionMsg::ShowWindow)),
             ])
         }
         AppMsg::RunCommand {
             plugin_id,
             entrypoint_id,
+            query,
         } => {
             Task::batch([
                 Task::done(AppMsg::WindowAction(WindowActionMsg::HideWindow)),
-                state.run_command(plugin_id, entrypoint_id),
+                state.run_command(plugin_id, entrypoint_id, query),
             ])
         }
-        AppMsg::RunGeneratedEntrypoint(plugin_id, entrypoint_id, action_index) => {
-            state.run_generated_entrypoint(plugin_id, entrypoint_id, action_index)
+        AppMsg::RunGeneratedEntrypoint(plugin_id, entrypoint_id, action_index, query) => {
+            state.run_generated_entrypoint(plugin_id, entrypoint_id, action_index, query)
         }
-        AppMsg::RequestPluginViewOpen(plugin_id, entrypoint_id) => {
+        AppMsg::RequestPluginViewOpen(plugin_id, entrypoint_id, query) => {
             let msg = state
                 .application_manager
-                .request_render_view(plugin_id, entrypoint_id)
+                .request_render_view(plugin_id, entrypoint_id, query)
                 .map(|action_shortcuts| AppMsg::OnOpenView { action_shortcuts })
                 .unwrap_or_else(|err| AppMsg::ShowBackendError(err.into()));
 
@@ -515,12 +586,21 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
             }
         }
         AppMsg::RunSearchItemAction(search_result, action_index) => {
+            if action_index == pin_action_widget_id(&search_result) {
+                return Task::done(AppMsg::SetSearchItemPinned {
+                    plugin_id: search_result.plugin_id.clone(),
+                    entrypoint_id: search_result.entrypoint_id.clone(),
+                    pinned: !search_result.entrypoint_pinned,
+                });
+            }
+
             match search_result.entrypoint_type {
                 SearchResultEntrypointType::Command => {
                     if action_index == 0 {
                         Task::done(AppMsg::RunCommand {
                             entrypoint_id: search_result.entrypoint_id.clone(),
                             plugin_id: search_result.plugin_id.clone(),
+                            query: search_result.keyword_alias_query.clone(),
                         })
                     } else {
                         Task::none()
@@ -531,6 +611,7 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
                         Task::done(AppMsg::OpenView {
                             plugin_id: search_result.plugin_id.clone(),
                             entrypoint_id: search_result.entrypoint_id.clone(),
+                            query: search_result.keyword_alias_query.clone(),
                         })
                     } else {
                         Task::none()
@@ -546,6 +627,7 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
                                     search_result.plugin_id.clone(),
                                     search_result.entrypoint_id.clone(),
                                     action_index,
+                                    search_result.keyword_alias_query.clone(),
                                 )),
                             ])
                         }
@@ -554,12 +636,26 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
                                 plugin_id: search_result.plugin_id.clone(),
                                 entrypoint_id: search_result.entrypoint_id.clone(),
                                 action_index,
+                                query: search_result.keyword_alias_query.clone(),
                             })
                         }
                     }
                 }
             }
         }
+        AppMsg::SetSearchItemPinned {
+            plugin_id,
+            entrypoint_id,
+            pinned,
+        } => {
+            let msg = state
+                .application_manager
+                .set_entrypoint_pinned(plugin_id, entrypoint_id, pinned)
+                .map(|()| AppMsg::UpdateSearchResults)
+                .unwrap_or_else(|err| AppMsg::ShowBackendError(err.into()));
+
+            Task::done(msg)
+        }
         AppMsg::PromptChanged(mut new_prompt) => {
             let task = match &mut state.global_state {
                 GlobalState::MainView {
@@ -569,6 +665,22 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
                 } => {
                     new_prompt.truncate(100); // search query uses regex so just to be safe truncate the prompt
 
+                    if state.search_scope.is_some() {
+                        // text input reports backspace even if it is already empty, which removes the scope
+                        if new_prompt.is_empty() && state.prompt.is_empty() {
+                            state.search_scope = None;
+                        }
+                    } else {
+                        match state.application_manager.search_scope(&new_prompt) {
+                            Ok(Some((scope, rest))) => {
+                                state.search_scope = Some(scope);
+                                new_prompt = rest;
+                            }
+                            Ok(None) => {}
+                            Err(err) => return Task::done(AppMsg::ShowBackendError(err.into())),
+                        }
+                    }
+
                     state.prompt = new_prompt.clone();
 
                     MainViewState::initial(sub_state);
@@ -580,7 +692,28 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
                 _ => Task::none(),
             };
 
-            Task::batch([task, state.search(new_prompt, true)])
+            let debounce = {
+                let prompt = new_prompt.clone();
+
+                Task::perform(
+                    async move {
+                        tokio::time::sleep(SEARCH_EVENT_DEBOUNCE).await;
+
+                        AppMsg::SearchDebounced { prompt }
+                    },
+                    std::convert::identity,
+                )
+            };
+
+            Task::batch([task, state.search(new_prompt, true), debounce])
+        }
+        AppMsg::SearchDebounced { prompt } => {
+            // prompt has changed since, the newer prompt has its own debounce
+            if state.prompt == prompt {
+                state.application_manager.send_search_performed_event();
+            }
+
+            Task::none()
         }
         AppMsg::UpdateSearchResults => {
             match &state.global_state {
@@ -589,7 +722,10 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
             }
         }
         AppMsg::PromptSubmit => state.global_state.primary(&state.client_context, &state.search_results),
-        AppMsg::SetSearchResults(new_search_results) => {
+        AppMsg::SetSearchResults {
+            search_results,
+            prompt_changed,
+        } => {
             let first_focus = if state.search_results.items().is_empty() {
                 // this is supposed to only be useful only the first time main window is opened
                 // hopefully doesn't cause problems elsewhere
@@ -598,9 +734,33 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
                 Task::none()
             };
 
-            state.search_results.update(new_search_results);
+            // view found using keyword alias is opened as soon as alias followed by space is typed, the rest of the text can be typed in the view itself,
+            // commands and generated entrypoints are run on enter, so that their argument can be typed first
+            let keyword_alias_matches = search_results
+                .iter()
+                .filter(|item| item.keyword_alias_match)
+                .collect::<Vec<_>>();
+
+            let in_main_view = matches!(state.global_state, GlobalState::MainView { .. });
+
+            let keyword_alias_view = match keyword_alias_matches[..] {
+                [item]
+                    if prompt_changed
+                        && in_main_view
+                        && matches!(item.entrypoint_type, SearchResultEntrypointType::View) =>
+                {
+                    Task::done(AppMsg::OpenView {
+                        plugin_id: item.plugin_id.clone(),
+                        entrypoint_id: item.entrypoint_id.clone(),
+                        query: item.keyword_alias_query.clone(),
+                    })
+                }
+                _ => Task::none(),
+            };
 
-            first_focus
+            state.search_results.update(search_results);
+
+            Task::batch([first_focus, keyword_alias_view])
         }
         AppMsg::RenderPluginUI {
             plugin_id,
@@ -784,7 +944,14 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
                                 } => {
                                     match sub_state {
                                         MainViewState::None => {
-                                            AppModel::backspace_prompt(&mut state.prompt, search_field_id.clone())
+                                            if state.prompt.is_empty() && state.search_scope.is_some() {
+                                                Task::batch([
+                                                    focus(search_field_id.clone()),
+                                                    Task::done(AppMsg::PromptChanged("".to_string())),
+                                                ])
+                                            } else {
+                                                AppModel::backspace_prompt(&mut state.prompt, search_field_id.clone())
+                                            }
                                         }
                                         MainViewState::SearchResultActionPanel { .. } => Task::none(),
                                         MainViewState::InlineViewActionPanel { .. } => Task::none(),
@@ -852,6 +1019,13 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
             plugin_id,
         } => state.handle_plugin_event(widget_event, plugin_id),
         AppMsg::Noop => Task::none(),
+        AppMsg::Exit { restart } => {
+            tracing::info!("Exiting application");
+
+            state.restart_requested.store(restart, Ordering::SeqCst);
+
+            iced::exit()
+        }
         AppMsg::ShowPreferenceRequiredView {
             plugin_id,
             entrypoint_id,
@@ -927,9 +1101,7 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
                     match sub_state {
                         MainViewState::None => {
                             if let Some(search_item) = focused_search_result.get(&state.search_results) {
-                                if search_item.entrypoint_actions.len() > 0 {
-                                    MainViewState::search_result_action_panel(sub_state, keyboard, search_item.clone());
-                                }
+                                MainViewState::search_result_action_panel(sub_state, keyboard, search_item.clone());
                             } else {
                                 if let Some(view) = state.client_context.get_first_inline_view_container() {
                                     MainViewState::inline_result_action_panel(
@@ -1179,7 +1351,13 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
 
             view.focus_search_bar(widget_id)
         }
-        AppMsg::WindowAction(action) => state.main_window_state.handle_action(action),
+        AppMsg::WindowAction(action) => {
+            if let WindowActionMsg::SetMainWindowId(id) = &action {
+                state.application_manager.window_visibility_changed(id.is_some());
+            }
+
+            state.main_window_state.handle_action(action)
+        }
         AppMsg::SetTheme { theme } => {
             state.theme = GauntletComplexTheme::new(theme);
 
@@ -1221,7 +1399,7 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
 
             Task::future(async move {
                 application_manager
-                    .run_action(plugin_id, entrypoint_id, ":primary".to_string())
+                    .run_action(plugin_id, entrypoint_id, ":primary".to_string(), HashMap::new(), None)
                     .await
                     .map(|()| AppMsg::Noop)
                     .unwrap_or_else(|err| AppMsg::ShowBackendError(err.into()))
@@ -1530,6 +1708,20 @@ fn view_main(state: &AppModel) -> Element<'_, AppMsg> {
                 .width(Length::Fill)
                 .themed(TextInputStyle::MainSearch);
 
+            let input: Element<_> = match &state.search_scope {
+                None => input,
+                Some(scope) => {
+                    let scope: Element<_> = text(scope.plugin_name.clone())
+                        .shaping(Shaping::Advanced)
+                        .size(15)
+                        .into();
+
+                    let scope: Element<_> = container(scope).themed(ContainerStyle::MainSearchBarScope);
+
+                    row(vec![scope, input]).align_y(Vertical::Center).into()
+                }
+            };
+
             let search_list = search_list(&state.search_results, &focused_search_result)
                 .map(|search_result| AppMsg::OnPrimaryActionMainViewNoPanel { search_result });
 
@@ -1753,14 +1945,16 @@ fn subscription(#[allow(unused)] state: &AppModel) -> Subscription<AppMsg> {
 impl AppModel {
     fn reset_window_state(&mut self) -> Task<AppMsg> {
         self.prompt = "".to_string();
+        self.search_scope = None;
 
         self.client_context.clear_all_views();
 
         GlobalState::initial(&mut self.global_state)
     }
 
-    fn run_command(&self, plugin_id: PluginId, entrypoint_id: EntrypointId) -> Task<AppMsg> {
-        self.application_manager.run_command(plugin_id, entrypoint_id);
+    fn run_command(&self, plugin_id: PluginId, entrypoint_id: EntrypointId, query: Option<String>) -> Task<AppMsg> {
+        self.application_manager
+            .run_command(plugin_id, entrypoint_id, HashMap::new(), query);
 
         Task::none()
     }
@@ -1770,9 +1964,15 @@ impl AppModel {
         plugin_id: PluginId,
         entrypoint_id: EntrypointId,
         action_index: usize,
+        query: Option<String>,
     ) -> Task<AppMsg> {
-        self.application_manager
-            .request_run_generated_entrypoint(plugin_id, entrypoint_id, action_index);
+        self.application_manager.request_run_generated_entrypoint(
+            plugin_id,
+            entrypoint_id,
+            action_index,
+            HashMap::new(),
+            query,
+        );
 
         Task::none()
     }
@@ -1885,11 +2085,20 @@ impl AppModel {
         Task::none()
     }
 
-    fn search(&self, new_prompt: String, render_inline_view: bool) -> Task<AppMsg> {
+    fn search(&self, new_prompt: String, prompt_changed: bool) -> Task<AppMsg> {
         let msg = self
             .application_manager
-            .search(&new_prompt, render_inline_view)
-            .map(|search_result| AppMsg::SetSearchResults(search_result))
+            .search(
+                &new_prompt,
+                self.search_scope.as_ref().map(|scope| &scope.plugin_id),
+                prompt_changed,
+            )
+            .map(|search_results| {
+                AppMsg::SetSearchResults {
+                    search_results,
+                    prompt_changed,
+                }
+            })
             .unwrap_or_else(|err| AppMsg::ShowBackendError(err.into()));
 
         Task::done(msg)
@@ -1,4 +1,5 @@
 use std::collections::HashMap;
+use std::time::SystemTime;
 
 use gauntlet_common::model::IconAccessoryWidget;
 use gauntlet_common::model::ImageLike;
@@ -6,6 +7,7 @@ use gauntlet_common::model::SearchResult;
 use gauntlet_common::model::SearchResultAccessory;
 use gauntlet_common::model::SearchResultEntrypointType;
 use gauntlet_common::model::TextAccessoryWidget;
+use gauntlet_common::time::format_duration;
 use iced::Alignment;
 use iced::Length;
 use iced::advanced::image::Handle;
@@ -24,6 +26,7 @@ use crate::ui::theme::ThemableWidget;
 use crate::ui::theme::button::ButtonStyle;
 use crate::ui::theme::container::ContainerStyle;
 use crate::ui::theme::image::ImageStyle;
+use crate::ui::theme::row::RowStyle;
 use crate::ui::theme::space::ThemeKindSpace;
 use crate::ui::theme::text::TextStyle;
 use crate::ui::widget::accessories::render_icon_accessory;
@@ -33,7 +36,14 @@ pub fn search_list<'a>(
     search_results: &'a ScrollContent<SearchResult>,
     focused_search_result: &ScrollHandle,
 ) -> Element<'a, SearchResult> {
-    let items: Vec<Element<_>> = search_results
+    // recently run entrypoints are always at the beginning of the list
+    let recent_count = search_results
+        .items()
+        .values()
+        .take_while(|search_result| search_result.entrypoint_last_run.is_some())
+        .count();
+
+    let mut items: Vec<Element<_>> = search_results
         .items()
         .iter()
         .map(|(index, search_result)| {
@@ -73,7 +83,7 @@ pub fn search_list<'a>(
             button_content.push(entrypoint_name);
             button_content.push(plugin_name_text);
 
-            if let Some(alias) = &search_result.entrypoint_alias {
+            for alias in &search_result.entrypoint_aliases {
                 let alias: Element<_> = text(alias.clone()).shaping(Shaping::Advanced).size(15).into();
 
                 let alias: Element<_> = container(alias).themed(ContainerStyle::MainListItemAlias).into();
@@ -130,6 +140,19 @@ pub fn search_list<'a>(
                 }
             };
 
+            if let Some(last_run) = search_result.entrypoint_last_run {
+                let last_run = SystemTime::now().duration_since(last_run).unwrap_or_default();
+
+                let last_run_text: Element<_> = text(format!("{} ago", format_duration(last_run)))
+                    .size(15)
+                    .shaping(Shaping::Advanced)
+                    .themed(TextStyle::MainListItemSubtext);
+
+                let last_run_text: Element<_> = container(last_run_text).themed(ContainerStyle::MainListItemSubText);
+
+                button_content.push(last_run_text);
+            }
+
             let type_text: Element<_> = text(type_text.to_string())
                 .size(15)
                 .shaping(Shaping::Advanced)
@@ -161,5 +184,22 @@ pub fn search_list<'a>(
         })
         .collect();
 
+    if recent_count > 0 {
+        if recent_count < items.len() {
+            items.insert(recent_count, section_title("All", RowStyle::ListSectionTitle));
+        }
+
+        items.insert(0, section_title("Recent", RowStyle::ListFirstSectionTitle));
+    }
+
     column(items).into()
 }
+
+fn section_title<'a>(title: &'a str, style: RowStyle) -> Element<'a, SearchResult> {
+    let title: Element<_> = text(title)
+        .shaping(Shaping::Advanced)
+        .size(14)
+        .themed(TextStyle::ListSectionTitle);
+
+    row([title]).themed(style)
+}
@@ -1,6 +1,7 @@
 use std::ops::Deref;
 use std::sync::Arc;
 use std::sync::Mutex;
+use std::time::Duration;
 
 use gauntlet_common::model::UiSetupData;
 use gauntlet_common::rpc::frontend_api::FrontendApiRequestData;
@@ -71,7 +72,31 @@ pub fn setup(
 
     let mut tasks = vec![];
 
-    tasks.push(Task::future(async move { run_grpc_server(grpc_api).await }).discard());
+    #[cfg(not(feature = "scenario_runner"))]
+    match application_manager.sync_plugins_with_config(&global_hotkey_manager, false) {
+        Ok(report) => {
+            let application_manager = application_manager.clone();
+
+            tasks.push(
+                Task::future(async move { application_manager.install_plugins_from_config(report.installing).await })
+                    .discard(),
+            );
+        }
+        Err(err) => {
+            tracing::error!("Unable to sync plugins with config: {:?}", err);
+        }
+    }
+
+    let control_transport = application_manager.control_transport();
+    let control_tools_server = application_manager.control_tools_server();
+    let event_broadcaster = application_manager.event_broadcaster();
+
+    tasks.push(
+        Task::future(async move {
+            run_grpc_server(grpc_api, control_transport, control_tools_server, event_broadcaster).await
+        })
+        .discard(),
+    );
 
     tasks.push(Task::stream(stream::channel(10, |mut sender| {
         async move {
@@ -271,15 +296,19 @@ pub fn handle_server_message(
             plugin_id,
             entrypoint_id,
             action_id,
+            arguments,
+            query,
         } => {
             let application_manager = state.application_manager.clone();
             let plugin_id = plugin_id.clone();
             let entrypoint_id = entrypoint_id.clone();
             let action_id = action_id.clone();
+            let arguments = arguments.clone();
+            let query = query.clone();
 
             Task::future(async move {
                 let result = application_manager
-                    .run_action(plugin_id, entrypoint_id, action_id)
+                    .run_action(plugin_id, entrypoint_id, action_id, arguments, query)
                     .await
                     .map(|data| ServerGrpcApiResponseData::RunAction { data });
 
@@ -298,5 +327,188 @@ pub fn handle_server_message(
 
             Task::none()
         }
+        ServerGrpcApiRequestData::Search { text } => {
+            let application_manager = &state.application_manager;
+
+            let result = application_manager
+                .search_scope(&text)
+                .and_then(|scope| {
+                    match scope {
+                        None => application_manager.search(&text, None, false),
+                        Some((scope, text)) => application_manager.search(&text, Some(&scope.plugin_id), false),
+                    }
+                })
+                .map(|data| ServerGrpcApiResponseData::Search { data });
+
+            responder.respond(result);
+
+            Task::none()
+        }
+        ServerGrpcApiRequestData::Plugins {} => {
+            let result = state
+                .application_manager
+                .plugins()
+                .map(|data| ServerGrpcApiResponseData::Plugins { data });
+
+            responder.respond(result);
+
+            Task::none()
+        }
+        ServerGrpcApiRequestData::EntrypointActions {
+            plugin_id,
+            entrypoint_id,
+        } => {
+            let result = state
+                .application_manager
+                .entrypoint_actions(plugin_id.clone(), entrypoint_id.clone())
+                .map(|data| ServerGrpcApiResponseData::EntrypointActions { data });
+
+            responder.respond(result);
+
+            Task::none()
+        }
+        ServerGrpcApiRequestData::DownloadPlugin { source } => {
+            state.application_manager.download_plugin(source.clone());
+
+            responder.respond(Ok(ServerGrpcApiResponseData::DownloadPlugin { data: () }));
+
+            Task::none()
+        }
+        ServerGrpcApiRequestData::DownloadStatus {} => {
+            let data = state.application_manager.download_status();
+
+            responder.respond(Ok(ServerGrpcApiResponseData::DownloadStatus { data }));
+
+            Task::none()
+        }
+        ServerGrpcApiRequestData::RemovePlugin { plugin_id } => {
+            let result = state
+                .application_manager
+                .remove_plugin(plugin_id.clone())
+                .map(|data| ServerGrpcApiResponseData::RemovePlugin { data });
+
+            responder.respond(result);
+
+            Task::none()
+        }
+        ServerGrpcApiRequestData::SetPluginState {
+            plugin_id,
+            enabled,
+            acknowledge_unsigned,
+        } => {
+            let result = state
+                .application_manager
+                .set_plugin_state(plugin_id.clone(), *enabled, *acknowledge_unsigned)
+                .map(|data| ServerGrpcApiResponseData::SetPluginState { data });
+
+            responder.respond(result);
+
+            Task::none()
+        }
+        ServerGrpcApiRequestData::SetEntrypointState {
+            plugin_id,
+            entrypoint_id,
+            enabled,
+        } => {
+            let result = state
+                .application_manager
+                .set_entrypoint_state(plugin_id.clone(), entrypoint_id.clone(), *enabled)
+                .map(|data| ServerGrpcApiResponseData::SetEntrypointState { data });
+
+            responder.respond(result);
+
+            Task::none()
+        }
+        ServerGrpcApiRequestData::ReloadAllPlugins {} => {
+            let result = state
+                .application_manager
+                .reload_all_plugins()
+                .map(|data| ServerGrpcApiResponseData::ReloadAllPlugins { data });
+
+            responder.respond(result);
+
+            Task::none()
+        }
+        ServerGrpcApiRequestData::Shutdown { restart } => {
+            let application_manager = state.application_manager.clone();
+            let restart = *restart;
+
+            Task::future(async move {
+                let result = application_manager
+                    .shutdown()
+                    .await
+                    .map(|data| ServerGrpcApiResponseData::Shutdown { data });
+
+                let success = result.is_ok();
+
+                responder.respond(result);
+
+                if success {
+                    // give grpc server time to send response before runtime is stopped
+                    tokio::time::sleep(Duration::from_millis(200)).await;
+
+                    AppMsg::Exit { restart }
+                } else {
+                    AppMsg::Noop
+                }
+            })
+        }
+        ServerGrpcApiRequestData::Diagnostics {} => {
+            let result = state
+                .application_manager
+                .diagnostics()
+                .map(|data| ServerGrpcApiResponseData::Diagnostics { data });
+
+            responder.respond(result);
+
+            Task::none()
+        }
+        ServerGrpcApiRequestData::ExportFrecency {} => {
+            let result = state
+                .application_manager
+                .export_frecency()
+                .map(|data| ServerGrpcApiResponseData::ExportFrecency { data });
+
+            responder.respond(result);
+
+            Task::none()
+        }
+        ServerGrpcApiRequestData::ImportFrecency { data } => {
+            let result = state
+                .application_manager
+                .import_frecency(data.clone())
+                .map(|data| ServerGrpcApiResponseData::ImportFrecency { data });
+
+            responder.respond(result);
+
+            Task::none()
+        }
+        ServerGrpcApiRequestData::RunHistory {} => {
+            let result = state
+                .application_manager
+                .run_history()
+                .map(|data| ServerGrpcApiResponseData::RunHistory { data });
+
+            responder.respond(result);
+
+            Task::none()
+        }
+        ServerGrpcApiRequestData::SyncPlugins { dry_run } => {
+            let result = state
+                .application_manager
+                .sync_plugins_with_config(&state.global_hotkey_manager, *dry_run);
+
+            let installing = match &result {
+                Ok(report) => report.installing.clone(),
+                Err(_) => vec![],
+            };
+
+            responder.respond(result.map(|data| ServerGrpcApiResponseData::SyncPlugins { data }));
+
+            let application_manager = state.application_manager.clone();
+
+            // cli waits for installation by polling download status
+            Task::future(async move { application_manager.install_plugins_from_config(installing).await }).discard()
+        }
     }
 }
@@ -5,6 +5,7 @@ use std::time::Duration;
 use gauntlet_common::model::DownloadStatus;
 use gauntlet_common::model::EntrypointId;
 use gauntlet_common::model::PluginId;
+use gauntlet_common::model::PluginSource;
 use gauntlet_common_ui::padding;
 use gauntlet_server::global_hotkey::GlobalHotKeyManager;
 use gauntlet_server::plugins::ApplicationManager;
@@ -103,7 +104,8 @@ pub enum SettingsMsg {
     DownloadStatus { plugins: HashMap<PluginId, DownloadStatus> },
     HandleBackendError(RequestError),
     CheckDownloadStatus,
-    DownloadPlugin { plugin_id: PluginId },
+    DownloadPlugin { source: PluginSource },
+    UpdatePlugin { plugin_id: PluginId },
     Noop,
     ToggleDownloadInfo,
 }
@@ -184,29 +186,7 @@ pub fn update_settings(
                 }
             }
 
-            let application_manager = state.application_manager.clone();
-
-            Task::perform(
-                async move {
-                    let plugins = application_manager.plugins()?;
-                    let global_entrypoint_shortcuts = application_manager.get_global_entrypoint_shortcuts()?;
-                    let entrypoint_search_aliases = application_manager.get_entrypoint_search_aliases()?;
-
-                    Ok((plugins, global_entrypoint_shortcuts, entrypoint_search_aliases))
-                },
-                |result| {
-                    handle_backend_error(
-                        result,
-                        |(plugins, global_entrypoint_shortcuts, entrypoint_search_aliases)| {
-                            SettingsMsg::Plugin(SettingsPluginMsgIn::PluginsReloaded(
-                                plugins,
-                                global_entrypoint_shortcuts,
-                                entrypoint_search_aliases,
-                            ))
-                        },
-                    )
-                },
-            )
+            Task::done(SettingsMsg::Plugin(SettingsPluginMsgIn::FetchPlugins))
         }
         SettingsMsg::CheckDownloadStatus => {
             if state.downloads_info.is_empty() {
@@ -217,20 +197,42 @@ pub fn update_settings(
                 Task::done(SettingsMsg::DownloadStatus { plugins })
             }
         }
-        SettingsMsg::DownloadPlugin { plugin_id } => {
+        SettingsMsg::DownloadPlugin { source } => {
             let backend_client = state.application_manager.clone();
 
             let already_downloading = state
                 .downloads_info
-                .insert(plugin_id.clone(), DownloadInfo::InProgress)
+                .insert(source.plugin_id.clone(), DownloadInfo::InProgress)
                 .is_some();
 
             if already_downloading {
                 Task::none()
             } else {
-                backend_client.download_plugin(plugin_id);
+                backend_client.download_plugin(source);
+
+                Task::none()
+            }
+        }
+        SettingsMsg::UpdatePlugin { plugin_id } => {
+            let application_manager = state.application_manager.clone();
+
+            let already_downloading = matches!(
+                state.downloads_info.insert(plugin_id.clone(), DownloadInfo::InProgress),
+                Some(DownloadInfo::InProgress)
+            );
 
+            if already_downloading {
                 Task::none()
+            } else {
+                Task::perform(
+                    async move {
+                        application_manager.update_plugin(plugin_id).await?;
+
+                        Ok(())
+                    },
+                    // installed commit and update availability have changed
+                    |result| handle_backend_error(result, |()| SettingsMsg::Plugin(SettingsPluginMsgIn::FetchPlugins)),
+                )
             }
         }
         SettingsMsg::Noop => Task::none(),
@@ -251,12 +253,20 @@ pub fn update_settings(
                 let theme = state.application_manager.get_theme()?;
 
                 let window_position_mode = state.application_manager.get_window_position_mode()?;
+                let fuzzy_search = state.application_manager.get_fuzzy_search()?;
+                let frecency_half_life_days = state.application_manager.get_frecency_half_life_days()?;
+                let run_history_retention_days = state.application_manager.get_run_history_retention_days()?;
+                let recent_search_results_limit = state.application_manager.get_recent_search_results_limit()?;
                 let wayland_global_shortcuts_enabled = state.application_manager.config()?.wayland_use_legacy_x11_api;
 
                 Ok(Task::batch([
                     Task::done(SettingsMsg::General(SettingsGeneralMsgIn::InitSetting {
                         theme,
                         window_position_mode,
+                        fuzzy_search,
+                        frecency_half_life_days,
+                        run_history_retention_days,
+                        recent_search_results_limit,
                         shortcut: global_shortcut,
                         shortcut_error: global_shortcut_error,
                         global_shortcuts_unsupported: state.wayland && !wayland_global_shortcuts_enabled,
@@ -1,3 +1,5 @@
+use std::fmt::Display;
+use std::fmt::Formatter;
 use std::sync::Arc;
 
 use gauntlet_common::model::PhysicalShortcut;
@@ -15,6 +17,8 @@ use iced::alignment;
 use iced::alignment::Horizontal;
 use iced::font::Style;
 use iced::widget::Space;
+use iced::widget::button;
+use iced::widget::checkbox;
 use iced::widget::column;
 use iced::widget::container;
 use iced::widget::pick_list;
@@ -26,6 +30,7 @@ use crate::ui::settings::components::shortcut_selector::ShortcutData;
 use crate::ui::settings::components::shortcut_selector::render_shortcut_error;
 use crate::ui::settings::components::shortcut_selector::shortcut_selector;
 use crate::ui::settings::theme::Element;
+use crate::ui::settings::theme::button::ButtonStyle;
 use crate::ui::settings::theme::container::ContainerStyle;
 use crate::ui::settings::ui::SettingsMsg;
 
@@ -33,6 +38,10 @@ pub struct SettingsGeneralState {
     application_manager: Arc<ApplicationManager>,
     theme: SettingsTheme,
     window_position_mode: WindowPositionMode,
+    fuzzy_search: bool,
+    frecency_half_life: FrecencyHalfLife,
+    run_history_retention: RunHistoryRetention,
+    recent_search_results_limit: RecentSearchResultsLimit,
     current_shortcut: ShortcutData,
     global_shortcuts_unsupported: bool,
 }
@@ -42,6 +51,11 @@ pub enum SettingsGeneralMsgIn {
     ShortcutCaptured(Option<PhysicalShortcut>),
     ThemeChanged(SettingsTheme),
     WindowPositionModeChanged(WindowPositionMode),
+    FuzzySearchChanged(bool),
+    FrecencyHalfLifeChanged(FrecencyHalfLife),
+    RunHistoryRetentionChanged(RunHistoryRetention),
+    RecentSearchResultsLimitChanged(RecentSearchResultsLimit),
+    ClearRunHistory,
     HandleShortcutResponse {
         shortcut: Option<PhysicalShortcut>,
         shortcut_error: Option<String>,
@@ -49,12 +63,52 @@ pub enum SettingsGeneralMsgIn {
     InitSetting {
         theme: SettingsTheme,
         window_position_mode: WindowPositionMode,
+        fuzzy_search: bool,
+        frecency_half_life_days: u32,
+        run_history_retention_days: u32,
+        recent_search_results_limit: u32,
         shortcut: Option<PhysicalShortcut>,
         shortcut_error: Option<String>,
         global_shortcuts_unsupported: bool,
     },
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FrecencyHalfLife(u32);
+
+impl Display for FrecencyHalfLife {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.0 {
+            1 => write!(f, "1 day"),
+            days => write!(f, "{} days", days),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RunHistoryRetention(u32);
+
+impl Display for RunHistoryRetention {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.0 {
+            1 => write!(f, "1 day"),
+            days => write!(f, "{} days", days),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RecentSearchResultsLimit(u32);
+
+impl Display for RecentSearchResultsLimit {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.0 {
+            1 => write!(f, "1 entrypoint"),
+            limit => write!(f, "{} entrypoints", limit),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum SettingsGeneralMsgOut {
     Inner(SettingsGeneralMsgIn),
@@ -67,6 +121,10 @@ impl SettingsGeneralState {
             application_manager,
             theme: SettingsTheme::AutoDetect,
             window_position_mode: WindowPositionMode::Static,
+            fuzzy_search: false,
+            frecency_half_life: FrecencyHalfLife(3),
+            run_history_retention: RunHistoryRetention(30),
+            recent_search_results_limit: RecentSearchResultsLimit(5),
             current_shortcut: ShortcutData {
                 shortcut: None,
                 error: None,
@@ -100,12 +158,20 @@ impl SettingsGeneralState {
             SettingsGeneralMsgIn::InitSetting {
                 theme,
                 window_position_mode,
+                fuzzy_search,
+                frecency_half_life_days,
+                run_history_retention_days,
+                recent_search_results_limit,
                 shortcut,
                 shortcut_error,
                 global_shortcuts_unsupported,
             } => {
                 self.theme = theme;
                 self.window_position_mode = window_position_mode;
+                self.fuzzy_search = fuzzy_search;
+                self.frecency_half_life = FrecencyHalfLife(frecency_half_life_days);
+                self.run_history_retention = RunHistoryRetention(run_history_retention_days);
+                self.recent_search_results_limit = RecentSearchResultsLimit(recent_search_results_limit);
                 self.current_shortcut = ShortcutData {
                     shortcut,
                     error: shortcut_error,
@@ -142,6 +208,74 @@ impl SettingsGeneralState {
                     |result| handle_backend_error(result, |()| SettingsGeneralMsgOut::Outer(SettingsMsg::Noop)),
                 )
             }
+            SettingsGeneralMsgIn::FuzzySearchChanged(enabled) => {
+                self.fuzzy_search = enabled;
+
+                let application_manager = self.application_manager.clone();
+
+                Task::perform(
+                    async move {
+                        application_manager.set_fuzzy_search(enabled)?;
+
+                        Ok(())
+                    },
+                    |result| handle_backend_error(result, |()| SettingsGeneralMsgOut::Outer(SettingsMsg::Noop)),
+                )
+            }
+            SettingsGeneralMsgIn::FrecencyHalfLifeChanged(half_life) => {
+                self.frecency_half_life = half_life;
+
+                let application_manager = self.application_manager.clone();
+
+                Task::perform(
+                    async move {
+                        application_manager.set_frecency_half_life_days(half_life.0)?;
+
+                        Ok(())
+                    },
+                    |result| handle_backend_error(result, |()| SettingsGeneralMsgOut::Outer(SettingsMsg::Noop)),
+                )
+            }
+            SettingsGeneralMsgIn::RunHistoryRetentionChanged(retention) => {
+                self.run_history_retention = retention;
+
+                let application_manager = self.application_manager.clone();
+
+                Task::perform(
+                    async move {
+                        application_manager.set_run_history_retention_days(retention.0)?;
+
+                        Ok(())
+                    },
+                    |result| handle_backend_error(result, |()| SettingsGeneralMsgOut::Outer(SettingsMsg::Noop)),
+                )
+            }
+            SettingsGeneralMsgIn::RecentSearchResultsLimitChanged(limit) => {
+                self.recent_search_results_limit = limit;
+
+                let application_manager = self.application_manager.clone();
+
+                Task::perform(
+                    async move {
+                        application_manager.set_recent_search_results_limit(limit.0).await?;
+
+                        Ok(())
+                    },
+                    |result| handle_backend_error(result, |()| SettingsGeneralMsgOut::Outer(SettingsMsg::Noop)),
+                )
+            }
+            SettingsGeneralMsgIn::ClearRunHistory => {
+                let application_manager = self.application_manager.clone();
+
+                Task::perform(
+                    async move {
+                        application_manager.clear_run_history().await?;
+
+                        Ok(())
+                    },
+                    |result| handle_backend_error(result, |()| SettingsGeneralMsgOut::Outer(SettingsMsg::Noop)),
+                )
+            }
             SettingsGeneralMsgIn::HandleShortcutResponse {
                 shortcut,
                 shortcut_error,
@@ -192,8 +326,23 @@ impl SettingsGeneralState {
 
         let theme_field = self.theme_field();
 
+        let fuzzy_search_field = self.fuzzy_search_field();
+
+        let frecency_half_life_field = self.frecency_half_life_field();
+
+        let run_history_retention_field = self.run_history_retention_field();
+
+        let recent_search_results_limit_field = self.recent_search_results_limit_field();
+
         #[allow(unused_mut)]
-        let mut content = vec![global_shortcut_field, theme_field];
+        let mut content = vec![
+            global_shortcut_field,
+            theme_field,
+            fuzzy_search_field,
+            frecency_half_life_field,
+            run_history_retention_field,
+            recent_search_results_limit_field,
+        ];
 
         #[cfg(target_os = "macos")]
         {
@@ -251,6 +400,81 @@ impl SettingsGeneralState {
         theme_field
     }
 
+    fn fuzzy_search_field(&self) -> Element<SettingsGeneralMsgIn> {
+        let field: Element<_> = checkbox("Tolerate typos in search query", self.fuzzy_search)
+            .on_toggle(SettingsGeneralMsgIn::FuzzySearchChanged)
+            .into();
+
+        let field: Element<_> = container(field).width(Length::Fill).into();
+
+        let field = self.view_field("Fuzzy Search", field, None);
+
+        field
+    }
+
+    fn frecency_half_life_field(&self) -> Element<SettingsGeneralMsgIn> {
+        let items = [1, 3, 7, 14, 30].map(FrecencyHalfLife);
+
+        let field: Element<_> = pick_list(items, Some(self.frecency_half_life), move |item| {
+            SettingsGeneralMsgIn::FrecencyHalfLifeChanged(item)
+        })
+        .into();
+
+        let field: Element<_> = container(field).width(Length::Fill).into();
+
+        let field = self.view_field("Usage History Half Life", field, None);
+
+        field
+    }
+
+    fn run_history_retention_field(&self) -> Element<SettingsGeneralMsgIn> {
+        let items = [1, 7, 30, 90, 365].map(RunHistoryRetention);
+
+        let field: Element<_> = pick_list(items, Some(self.run_history_retention), move |item| {
+            SettingsGeneralMsgIn::RunHistoryRetentionChanged(item)
+        })
+        .into();
+
+        let field: Element<_> = container(field).width(Length::Fill).into();
+
+        let clear_text: Element<_> = text("Clear History").into();
+
+        let clear_text: Element<_> = container(clear_text)
+            .width(Length::Fill)
+            .align_y(Alignment::Center)
+            .align_x(Alignment::Center)
+            .into();
+
+        let clear_button: Element<_> = button(clear_text)
+            .class(ButtonStyle::Primary)
+            .on_press(SettingsGeneralMsgIn::ClearRunHistory)
+            .into();
+
+        let clear_button: Element<_> = container(clear_button)
+            .width(Length::FillPortion(3))
+            .padding(Padding::from([0.0, 8.0]))
+            .into();
+
+        let field = self.view_field("Keep Run History For", field, Some(clear_button));
+
+        field
+    }
+
+    fn recent_search_results_limit_field(&self) -> Element<SettingsGeneralMsgIn> {
+        let items = [1, 3, 5, 10, 20].map(RecentSearchResultsLimit);
+
+        let field: Element<_> = pick_list(items, Some(self.recent_search_results_limit), move |item| {
+            SettingsGeneralMsgIn::RecentSearchResultsLimitChanged(item)
+        })
+        .into();
+
+        let field: Element<_> = container(field).width(Length::Fill).into();
+
+        let field = self.view_field("Show Recent", field, None);
+
+        field
+    }
+
     #[allow(unused)]
     fn window_position_mode_field(&self) -> Element<SettingsGeneralMsgIn> {
         let items = [WindowPositionMode::Static, WindowPositionMode::ActiveMonitor];
@@ -4,11 +4,14 @@ use std::rc::Rc;
 use std::sync::Arc;
 
 use gauntlet_common::model::EntrypointId;
+use gauntlet_common::model::EntrypointSearchAlias;
 use gauntlet_common::model::PhysicalShortcut;
 use gauntlet_common::model::PluginId;
 use gauntlet_common::model::PluginPreferenceUserData;
+use gauntlet_common::model::PluginSource;
 use gauntlet_common::model::SettingsEntrypointType;
 use gauntlet_common::model::SettingsPlugin;
+use gauntlet_common::model::SettingsPluginSignature;
 use gauntlet_server::global_hotkey::GlobalHotKeyManager;
 use gauntlet_server::plugins::ApplicationManager;
 use gauntlet_utils::channel::RequestResult;
@@ -54,7 +57,8 @@ pub enum SettingsPluginMsgIn {
     PluginsReloaded(
         HashMap<PluginId, SettingsPlugin>,
         HashMap<(PluginId, EntrypointId), (PhysicalShortcut, Option<String>)>,
-        HashMap<(PluginId, EntrypointId), String>,
+        HashMap<(PluginId, EntrypointId), Vec<EntrypointSearchAlias>>,
+        Vec<(PluginId, EntrypointId)>,
     ),
     RemovePlugin {
         plugin_id: PluginId,
@@ -67,8 +71,22 @@ pub enum SettingsPluginMsgIn {
         entrypoint_id: EntrypointId,
     },
     DownloadPlugin {
+        source: PluginSource,
+    },
+    UpdatePlugin {
+        plugin_id: PluginId,
+    },
+    CheckForUpdates {
+        plugin_id: PluginId,
+    },
+    ConfirmEnablePlugin {
         plugin_id: PluginId,
     },
+    CancelEnablePlugin,
+    ResetFrecency {
+        plugin_id: PluginId,
+        entrypoint_id: Option<EntrypointId>,
+    },
     SelectItem(SelectedItem),
 }
 
@@ -84,7 +102,10 @@ pub struct SettingsPluginsState {
     preference_user_data: HashMap<(PluginId, Option<EntrypointId>, String), PluginPreferenceUserDataState>,
     selected_item: SelectedItem,
     global_entrypoint_shortcuts: HashMap<(PluginId, EntrypointId), (PhysicalShortcut, Option<String>)>,
-    entrypoint_search_aliases: HashMap<(PluginId, EntrypointId), String>,
+    entrypoint_search_aliases: HashMap<(PluginId, EntrypointId), Vec<EntrypointSearchAlias>>,
+    pinned_entrypoints: Vec<(PluginId, EntrypointId)>,
+    // plugin without trusted signature which user tried to enable, it is enabled only after confirmation
+    enable_confirmation: Option<PluginId>,
 }
 
 impl SettingsPluginsState {
@@ -97,6 +118,8 @@ impl SettingsPluginsState {
             table_state: PluginTableState::new(),
             global_entrypoint_shortcuts: HashMap::new(),
             entrypoint_search_aliases: HashMap::new(),
+            pinned_entrypoints: vec![],
+            enable_confirmation: None,
         }
     }
 
@@ -120,30 +143,32 @@ impl SettingsPluginsState {
                 let application_manager = application_manager.clone();
                 match self.table_state.update(message) {
                     PluginTableMsgOut::SetPluginState { enabled, plugin_id } => {
+                        let requires_confirmation = self
+                            .plugin_data
+                            .borrow()
+                            .plugins
+                            .get(&plugin_id)
+                            .is_some_and(|plugin| plugin.signature.requires_confirmation());
+
+                        if enabled && requires_confirmation {
+                            self.enable_confirmation = Some(plugin_id.clone());
+                            self.selected_item = SelectedItem::Plugin { plugin_id };
+
+                            return Task::none();
+                        }
+
                         let application_manager = application_manager.clone();
 
                         Task::perform(
                             async move {
-                                application_manager.set_plugin_state(plugin_id, enabled)?;
-
-                                let plugins = application_manager.plugins()?;
-                                let global_entrypoint_shortcuts =
-                                    application_manager.get_global_entrypoint_shortcuts()?;
-                                let entrypoint_aliases = application_manager.get_entrypoint_search_aliases()?;
+                                application_manager.set_plugin_state(plugin_id, enabled, false)?;
 
-                                Ok((plugins, global_entrypoint_shortcuts, entrypoint_aliases))
+                                Ok(())
                             },
                             |result| {
-                                handle_backend_error(
-                                    result,
-                                    |(plugins, global_entrypoint_shortcuts, entrypoint_aliases)| {
-                                        SettingsPluginMsgOut::Inner(SettingsPluginMsgIn::PluginsReloaded(
-                                            plugins,
-                                            global_entrypoint_shortcuts,
-                                            entrypoint_aliases,
-                                        ))
-                                    },
-                                )
+                                handle_backend_error(result, |()| {
+                                    SettingsPluginMsgOut::Inner(SettingsPluginMsgIn::FetchPlugins)
+                                })
                             },
                         )
                     }
@@ -158,27 +183,16 @@ impl SettingsPluginsState {
                             async move {
                                 application_manager.set_entrypoint_state(plugin_id, entrypoint_id, enabled)?;
 
-                                let plugins = application_manager.plugins()?;
-                                let global_entrypoint_shortcuts =
-                                    application_manager.get_global_entrypoint_shortcuts()?;
-                                let entrypoint_aliases = application_manager.get_entrypoint_search_aliases()?;
-
-                                Ok((plugins, global_entrypoint_shortcuts, entrypoint_aliases))
+                                Ok(())
                             },
                             |result| {
-                                handle_backend_error(
-                                    result,
-                                    |(plugins, global_entrypoint_shortcuts, entrypoint_aliases)| {
-                                        SettingsPluginMsgOut::Inner(SettingsPluginMsgIn::PluginsReloaded(
-                                            plugins,
-                                            global_entrypoint_shortcuts,
-                                            entrypoint_aliases,
-                                        ))
-                                    },
-                                )
+                                handle_backend_error(result, |()| {
+                                    SettingsPluginMsgOut::Inner(SettingsPluginMsgIn::FetchPlugins)
+                                })
                             },
                         )
                     }
+                    PluginTableMsgOut::Noop => Task::none(),
                     PluginTableMsgOut::SelectItem(selected_item) => {
                         Task::done(SettingsPluginMsgOut::Inner(SettingsPluginMsgIn::SelectItem(
                             selected_item,
@@ -219,15 +233,7 @@ impl SettingsPluginsState {
                                 shortcut,
                             )?;
 
-                            let plugins = application_manager.plugins()?;
-                            let global_entrypoint_shortcuts = application_manager.get_global_entrypoint_shortcuts()?;
-                            let entrypoint_aliases = application_manager.get_entrypoint_search_aliases()?;
-
-                            Ok(SettingsPluginMsgOut::Inner(SettingsPluginMsgIn::PluginsReloaded(
-                                plugins,
-                                global_entrypoint_shortcuts,
-                                entrypoint_aliases,
-                            )))
+                            Ok(SettingsPluginMsgOut::Inner(SettingsPluginMsgIn::FetchPlugins))
                         }
 
                         let msg_out = run(
@@ -241,31 +247,71 @@ impl SettingsPluginsState {
 
                         Task::done(msg_out)
                     }
-                    PluginTableMsgOut::AliasChanged(plugin_id, entrypoint_id, shortcut) => {
+                    PluginTableMsgOut::AliasesChanged(plugin_id, entrypoint_id, aliases) => {
+                        let application_manager = application_manager.clone();
+
+                        Task::perform(
+                            async move {
+                                application_manager.set_entrypoint_search_aliases(plugin_id, entrypoint_id, aliases)?;
+
+                                Ok(())
+                            },
+                            |result| {
+                                handle_backend_error(result, |()| {
+                                    SettingsPluginMsgOut::Inner(SettingsPluginMsgIn::FetchPlugins)
+                                })
+                            },
+                        )
+                    }
+                    PluginTableMsgOut::SearchScopeChanged(plugin_id, keyword) => {
+                        let application_manager = application_manager.clone();
+
+                        Task::perform(
+                            async move {
+                                application_manager.set_plugin_search_scope(plugin_id, keyword)?;
+
+                                Ok(())
+                            },
+                            |result| {
+                                handle_backend_error(result, |()| {
+                                    SettingsPluginMsgOut::Inner(SettingsPluginMsgIn::FetchPlugins)
+                                })
+                            },
+                        )
+                    }
+                    PluginTableMsgOut::PinnedPositionChanged(plugin_id, entrypoint_id, position) => {
                         let application_manager = application_manager.clone();
 
                         Task::perform(
                             async move {
-                                application_manager.set_entrypoint_search_alias(plugin_id, entrypoint_id, shortcut)?;
+                                application_manager.set_pinned_entrypoint_position(
+                                    plugin_id,
+                                    entrypoint_id,
+                                    position,
+                                )?;
+
+                                Ok(())
+                            },
+                            |result| {
+                                handle_backend_error(result, |()| {
+                                    SettingsPluginMsgOut::Inner(SettingsPluginMsgIn::FetchPlugins)
+                                })
+                            },
+                        )
+                    }
+                    PluginTableMsgOut::Unpin(plugin_id, entrypoint_id) => {
+                        let application_manager = application_manager.clone();
 
-                                let plugins = application_manager.plugins()?;
-                                let global_entrypoint_shortcuts =
-                                    application_manager.get_global_entrypoint_shortcuts()?;
-                                let entrypoint_aliases = application_manager.get_entrypoint_search_aliases()?;
+                        Task::perform(
+                            async move {
+                                application_manager.set_entrypoint_pinned(plugin_id, entrypoint_id, false)?;
 
-                                Ok((plugins, global_entrypoint_shortcuts, entrypoint_aliases))
+                                Ok(())
                             },
                             |result| {
-                                handle_backend_error(
-                                    result,
-                                    |(plugins, global_entrypoint_shortcuts, entrypoint_aliases)| {
-                                        SettingsPluginMsgOut::Inner(SettingsPluginMsgIn::PluginsReloaded(
-                                            plugins,
-                                            global_entrypoint_shortcuts,
-                                            entrypoint_aliases,
-                                        ))
-                                    },
-                                )
+                                handle_backend_error(result, |()| {
+                                    SettingsPluginMsgOut::Inner(SettingsPluginMsgIn::FetchPlugins)
+                                })
                             },
                         )
                     }
@@ -284,6 +330,7 @@ impl SettingsPluginsState {
                     plugins,
                     self.global_entrypoint_shortcuts.clone(),
                     self.entrypoint_search_aliases.clone(),
+                    self.pinned_entrypoints.clone(),
                 );
 
                 Task::none()
@@ -309,6 +356,7 @@ impl SettingsPluginsState {
                     plugins,
                     self.global_entrypoint_shortcuts.clone(),
                     self.entrypoint_search_aliases.clone(),
+                    self.pinned_entrypoints.clone(),
                 );
 
                 Task::none()
@@ -344,64 +392,93 @@ impl SettingsPluginsState {
                     }
                 }
             }
-            SettingsPluginMsgIn::FetchPlugins => {
-                let application_manager = self.application_manager.clone();
+            SettingsPluginMsgIn::FetchPlugins => reload_plugins_task(application_manager),
+            SettingsPluginMsgIn::PluginsReloaded(plugins, shortcuts, entrypoint_aliases, pinned_entrypoints) => {
+                self.apply_plugin_fetch(plugins, shortcuts, entrypoint_aliases, pinned_entrypoints);
+
+                Task::none()
+            }
+            SettingsPluginMsgIn::RemovePlugin { plugin_id } => {
+                self.selected_item = SelectedItem::None;
+
+                let application_manager = application_manager.clone();
 
                 Task::perform(
                     async move {
-                        let plugins = application_manager.plugins()?;
-                        let global_entrypoint_shortcuts = application_manager.get_global_entrypoint_shortcuts()?;
-                        let entrypoint_aliases = application_manager.get_entrypoint_search_aliases()?;
+                        application_manager.remove_plugin(plugin_id)?;
 
-                        Ok((plugins, global_entrypoint_shortcuts, entrypoint_aliases))
+                        Ok(())
                     },
                     |result| {
-                        handle_backend_error(result, |(plugins, global_entrypoint_shortcuts, entrypoint_aliases)| {
-                            SettingsPluginMsgOut::Inner(SettingsPluginMsgIn::PluginsReloaded(
-                                plugins,
-                                global_entrypoint_shortcuts,
-                                entrypoint_aliases,
-                            ))
+                        handle_backend_error(result, |()| {
+                            SettingsPluginMsgOut::Inner(SettingsPluginMsgIn::FetchPlugins)
                         })
                     },
                 )
             }
-            SettingsPluginMsgIn::PluginsReloaded(plugins, shortcuts, entrypoint_aliases) => {
-                self.apply_plugin_fetch(plugins, shortcuts, entrypoint_aliases);
-
-                Task::none()
+            SettingsPluginMsgIn::DownloadPlugin { source } => {
+                Task::done(SettingsPluginMsgOut::Outer(SettingsMsg::DownloadPlugin { source }))
             }
-            SettingsPluginMsgIn::RemovePlugin { plugin_id } => {
-                self.selected_item = SelectedItem::None;
+            SettingsPluginMsgIn::UpdatePlugin { plugin_id } => {
+                Task::done(SettingsPluginMsgOut::Outer(SettingsMsg::UpdatePlugin { plugin_id }))
+            }
+            SettingsPluginMsgIn::ConfirmEnablePlugin { plugin_id } => {
+                self.enable_confirmation = None;
 
                 let application_manager = application_manager.clone();
 
                 Task::perform(
                     async move {
-                        application_manager.remove_plugin(plugin_id)?;
+                        application_manager.set_plugin_state(plugin_id, true, true)?;
 
-                        let plugins = application_manager.plugins()?;
-                        let global_entrypoint_shortcuts = application_manager.get_global_entrypoint_shortcuts()?;
-                        let entrypoint_aliases = application_manager.get_entrypoint_search_aliases()?;
+                        Ok(())
+                    },
+                    |result| {
+                        handle_backend_error(result, |()| {
+                            SettingsPluginMsgOut::Inner(SettingsPluginMsgIn::FetchPlugins)
+                        })
+                    },
+                )
+            }
+            SettingsPluginMsgIn::CancelEnablePlugin => {
+                self.enable_confirmation = None;
+
+                Task::none()
+            }
+            SettingsPluginMsgIn::CheckForUpdates { plugin_id } => {
+                let application_manager = application_manager.clone();
 
-                        Ok((plugins, global_entrypoint_shortcuts, entrypoint_aliases))
+                Task::perform(
+                    async move {
+                        application_manager.check_plugin_update(plugin_id).await?;
+
+                        Ok(())
                     },
                     |result| {
-                        handle_backend_error(result, |(plugins, global_entrypoint_shortcuts, entrypoint_aliases)| {
-                            SettingsPluginMsgOut::Inner(SettingsPluginMsgIn::PluginsReloaded(
-                                plugins,
-                                global_entrypoint_shortcuts,
-                                entrypoint_aliases,
-                            ))
+                        handle_backend_error(result, |()| {
+                            SettingsPluginMsgOut::Inner(SettingsPluginMsgIn::FetchPlugins)
                         })
                     },
                 )
             }
-            SettingsPluginMsgIn::DownloadPlugin { plugin_id } => {
-                Task::done(SettingsPluginMsgOut::Outer(SettingsMsg::DownloadPlugin { plugin_id }))
+            SettingsPluginMsgIn::ResetFrecency {
+                plugin_id,
+                entrypoint_id,
+            } => {
+                let application_manager = application_manager.clone();
+
+                Task::perform(
+                    async move {
+                        application_manager.reset_frecency(plugin_id, entrypoint_id)?;
+
+                        Ok(())
+                    },
+                    |result| handle_backend_error(result, |()| SettingsPluginMsgOut::Outer(SettingsMsg::Noop)),
+                )
             }
             SettingsPluginMsgIn::SelectItem(selected_item) => {
                 self.selected_item = selected_item;
+                self.enable_confirmation = None;
 
                 Task::none()
             }
@@ -412,9 +489,12 @@ impl SettingsPluginsState {
         &mut self,
         plugins: HashMap<PluginId, SettingsPlugin>,
         global_entrypoint_shortcuts: HashMap<(PluginId, EntrypointId), (PhysicalShortcut, Option<String>)>,
-        entrypoint_search_aliases: HashMap<(PluginId, EntrypointId), String>,
+        entrypoint_search_aliases: HashMap<(PluginId, EntrypointId), Vec<EntrypointSearchAlias>>,
+        pinned_entrypoints: Vec<(PluginId, EntrypointId)>,
     ) {
         self.global_entrypoint_shortcuts = global_entrypoint_shortcuts.clone();
+        self.entrypoint_search_aliases = entrypoint_search_aliases.clone();
+        self.pinned_entrypoints = pinned_entrypoints.clone();
 
         self.preference_user_data = plugins
             .iter()
@@ -495,6 +575,7 @@ impl SettingsPluginsState {
             plugin_refs,
             global_entrypoint_shortcuts,
             entrypoint_search_aliases,
+            pinned_entrypoints,
         )
     }
 
@@ -551,6 +632,57 @@ impl SettingsPluginsState {
 
                         let mut column_content = vec![name, id];
 
+                        if plugin.update_available {
+                            let update_available: Element<_> =
+                                text("Update available").size(14).class(TextStyle::Subtitle).into();
+
+                            let update_available = container(update_available).padding(padding::all(8.0).top(0)).into();
+
+                            column_content.push(update_available);
+                        }
+
+                        if let Some(installed_commit) = &plugin.installed_commit {
+                            let commit_label: Element<_> =
+                                text("Installed Commit").size(14).class(TextStyle::Subtitle).into();
+
+                            let commit_label = container(commit_label).padding(padding::all(8.0).top(0)).into();
+
+                            let commit = match &plugin.git_ref {
+                                None => installed_commit.to_string(),
+                                Some(git_ref) => format!("{} ({})", installed_commit, git_ref),
+                            };
+
+                            let commit = text(commit).shaping(Shaping::Advanced);
+
+                            let commit = container(commit).padding(Padding::new(8.0)).into();
+
+                            let content: Element<_> = column(vec![commit_label, commit]).into();
+
+                            column_content.push(content);
+                        }
+
+                        let signature = match plugin.signature {
+                            SettingsPluginSignature::NotChecked => None,
+                            SettingsPluginSignature::Trusted => Some("Signed by trusted publisher"),
+                            SettingsPluginSignature::Untrusted => Some("Signed, but not by any of trusted publishers"),
+                            SettingsPluginSignature::Unsigned => Some("Not signed"),
+                        };
+
+                        if let Some(signature) = signature {
+                            let signature_label: Element<_> =
+                                text("Signature").size(14).class(TextStyle::Subtitle).into();
+
+                            let signature_label = container(signature_label).padding(padding::all(8.0).top(0)).into();
+
+                            let signature = text(signature).shaping(Shaping::Advanced);
+
+                            let signature = container(signature).padding(Padding::new(8.0)).into();
+
+                            let content: Element<_> = column(vec![signature_label, signature]).into();
+
+                            column_content.push(content);
+                        }
+
                         if !plugin.plugin_description.is_empty() {
                             let description_label: Element<_> =
                                 text("Description").size(14).class(TextStyle::Subtitle).into();
@@ -578,24 +710,49 @@ impl SettingsPluginsState {
 
                         let mut column_content = vec![content];
 
-                        if !plugin.plugin_id.to_string().starts_with("bundled://") {
-                            let check_for_updates_text: Element<_> = text("Check for updates").into();
+                        if self.enable_confirmation.as_ref() == Some(&plugin.plugin_id) {
+                            column_content.push(enable_confirmation(plugin.plugin_id.clone()));
+                        }
 
-                            let check_for_updates_text_container: Element<_> = container(check_for_updates_text)
-                                .width(Length::Fill)
-                                .align_y(Alignment::Center)
-                                .align_x(Alignment::Center)
-                                .into();
+                        column_content.push(reset_frecency_button(plugin.plugin_id.clone(), None));
 
-                            let check_for_updates_button: Element<_> = button(check_for_updates_text_container)
-                                .width(Length::Fill)
-                                .class(ButtonStyle::Primary)
-                                .on_press(SettingsPluginMsgIn::DownloadPlugin {
-                                    plugin_id: plugin.plugin_id.clone(),
-                                })
-                                .into();
-
-                            column_content.push(check_for_updates_button);
+                        if !plugin.plugin_id.to_string().starts_with("bundled://") {
+                            // only plugins downloaded from git repository have versions to compare,
+                            // if installed commit is unknown it is not possible to check, but it is still possible to update
+                            if plugin.plugin_id.try_to_git_url().is_ok() {
+                                let (update_text, update_msg) =
+                                    if plugin.update_available || plugin.installed_commit.is_none() {
+                                        (
+                                            "Update",
+                                            SettingsPluginMsgIn::UpdatePlugin {
+                                                plugin_id: plugin.plugin_id.clone(),
+                                            },
+                                        )
+                                    } else {
+                                        (
+                                            "Check for updates",
+                                            SettingsPluginMsgIn::CheckForUpdates {
+                                                plugin_id: plugin.plugin_id.clone(),
+                                            },
+                                        )
+                                    };
+
+                                let update_text: Element<_> = text(update_text).into();
+
+                                let update_text_container: Element<_> = container(update_text)
+                                    .width(Length::Fill)
+                                    .align_y(Alignment::Center)
+                                    .align_x(Alignment::Center)
+                                    .into();
+
+                                let update_button: Element<_> = button(update_text_container)
+                                    .width(Length::Fill)
+                                    .class(ButtonStyle::Primary)
+                                    .on_press(update_msg)
+                                    .into();
+
+                                column_content.push(update_button);
+                            }
 
                             let remove_text: Element<_> = text("Remove plugin").into();
 
@@ -678,11 +835,16 @@ impl SettingsPluginsState {
                             .map(|msg| SettingsPluginMsgIn::PluginPreferenceMsg(msg)),
                         );
 
-                        let column: Element<_> = column(column_content).spacing(12).into();
+                        let content: Element<_> = column(column_content).spacing(12).into();
 
-                        let column: Element<_> = scrollable(column).width(Length::Fill).into();
+                        let content: Element<_> = scrollable(content).height(Length::Fill).width(Length::Fill).into();
+
+                        let reset_frecency_button =
+                            reset_frecency_button(plugin_id.clone(), Some(entrypoint_id.clone()));
 
-                        container(column)
+                        let content: Element<_> = column(vec![content, reset_frecency_button]).spacing(8.0).into();
+
+                        container(content)
                             .width(Length::Fill)
                             .height(Length::Fill)
                             .padding(Padding::from([4.0, 0.0]))
@@ -691,12 +853,12 @@ impl SettingsPluginsState {
                 }
             }
             SelectedItem::NewPlugin { repository_url } => {
-                let url_input: Element<_> = text_input("Enter Git Repository URL", &repository_url)
+                let url_input: Element<_> = text_input("Enter Git Repository or Plugin Archive URL", &repository_url)
                     .on_input(|value| {
                         SettingsPluginMsgIn::SelectItem(SelectedItem::NewPlugin { repository_url: value })
                     })
                     .on_submit(SettingsPluginMsgIn::DownloadPlugin {
-                        plugin_id: PluginId::from_string(repository_url),
+                        source: PluginSource::parse(&repository_url),
                     })
                     .into();
 
@@ -704,6 +866,10 @@ impl SettingsPluginsState {
                     url_input,
                     text("Supported protocols:").into(),
                     text("http(s), ssh, git").into(),
+                    text("Branch, tag or commit can be selected by appending #<ref> to the URL").into(),
+                    text("Built plugin can also be installed from .tar.gz or .zip archive using http(s) or file URL")
+                        .into(),
+                    text("Expected archive hash is specified by appending #sha256=<hash>, required for http(s)").into(),
                 ])
                 .into();
 
@@ -745,9 +911,14 @@ impl SettingsPluginsState {
                             .shaping(Shaping::Advanced)
                             .into();
 
-                        let name: Element<_> = container(name).padding(padding::all(8.0)).into();
+                        let name: Element<_> = container(name).padding(padding::all(8.0)).height(Length::Fill).into();
+
+                        let reset_frecency_button =
+                            reset_frecency_button(plugin_id.clone(), Some(generated_entrypoint_id.clone()));
 
-                        container(name)
+                        let content: Element<_> = column(vec![name, reset_frecency_button]).spacing(8.0).into();
+
+                        container(content)
                             .padding(Padding::from([4.0, 0.0]))
                             .width(Length::Fill)
                             .height(Length::Fill)
@@ -782,7 +953,7 @@ impl SettingsPluginsState {
         let top_button_action = match plugin_url {
             Some(plugin_url) => {
                 SettingsPluginMsgIn::DownloadPlugin {
-                    plugin_id: PluginId::from_string(plugin_url),
+                    source: PluginSource::parse(&plugin_url),
                 }
             }
             None => {
@@ -818,6 +989,64 @@ impl SettingsPluginsState {
     }
 }
 
+fn reset_frecency_button<'a>(
+    plugin_id: PluginId,
+    entrypoint_id: Option<EntrypointId>,
+) -> Element<'a, SettingsPluginMsgIn> {
+    let reset_text: Element<_> = text("Reset usage history").into();
+
+    let reset_text_container: Element<_> = container(reset_text)
+        .width(Length::Fill)
+        .align_y(Alignment::Center)
+        .align_x(Alignment::Center)
+        .into();
+
+    button(reset_text_container)
+        .width(Length::Fill)
+        .class(ButtonStyle::Primary)
+        .on_press(SettingsPluginMsgIn::ResetFrecency {
+            plugin_id,
+            entrypoint_id,
+        })
+        .into()
+}
+
+fn enable_confirmation<'a>(plugin_id: PluginId) -> Element<'a, SettingsPluginMsgIn> {
+    let warning: Element<_> = text(
+        "This plugin is not signed by any of trusted publishers. \
+        Plugin can run with permissions listed in its manifest, enable it only if you trust its source",
+    )
+    .shaping(Shaping::Advanced)
+    .into();
+
+    let warning = container(warning).padding(Padding::new(8.0)).into();
+
+    let button_content = |label: &'static str| {
+        let label: Element<_> = text(label).into();
+
+        container(label)
+            .width(Length::Fill)
+            .align_y(Alignment::Center)
+            .align_x(Alignment::Center)
+    };
+
+    let enable_button: Element<_> = button(button_content("Enable Anyway"))
+        .width(Length::Fill)
+        .class(ButtonStyle::Destructive)
+        .on_press(SettingsPluginMsgIn::ConfirmEnablePlugin { plugin_id })
+        .into();
+
+    let cancel_button: Element<_> = button(button_content("Cancel"))
+        .width(Length::Fill)
+        .class(ButtonStyle::Primary)
+        .on_press(SettingsPluginMsgIn::CancelEnablePlugin)
+        .into();
+
+    let buttons: Element<_> = row(vec![enable_button, cancel_button]).spacing(8.0).into();
+
+    column(vec![warning, buttons]).spacing(8.0).into()
+}
+
 #[derive(Debug, Clone)]
 struct PluginDataContainer {
     plugins: HashMap<PluginId, SettingsPlugin>,
@@ -934,6 +1163,38 @@ impl PluginPreferenceUserDataState {
     }
 }
 
+// everything shown in plugin table, changes made in settings are shown only after reload
+fn reload_plugins_task(application_manager: Arc<ApplicationManager>) -> Task<SettingsPluginMsgOut> {
+    Task::perform(
+        async move {
+            let plugins = application_manager.plugins()?;
+            let global_entrypoint_shortcuts = application_manager.get_global_entrypoint_shortcuts()?;
+            let entrypoint_aliases = application_manager.get_entrypoint_search_aliases()?;
+            let pinned_entrypoints = application_manager.get_pinned_entrypoints()?;
+
+            Ok((
+                plugins,
+                global_entrypoint_shortcuts,
+                entrypoint_aliases,
+                pinned_entrypoints,
+            ))
+        },
+        |result| {
+            handle_backend_error(
+                result,
+                |(plugins, global_entrypoint_shortcuts, entrypoint_aliases, pinned_entrypoints)| {
+                    SettingsPluginMsgOut::Inner(SettingsPluginMsgIn::PluginsReloaded(
+                        plugins,
+                        global_entrypoint_shortcuts,
+                        entrypoint_aliases,
+                        pinned_entrypoints,
+                    ))
+                },
+            )
+        },
+    )
+}
+
 pub fn handle_backend_error<T>(
     result: RequestResult<T>,
     convert: impl FnOnce(T) -> SettingsPluginMsgOut,
@@ -3,6 +3,7 @@ use std::collections::HashMap;
 use std::rc::Rc;
 
 use gauntlet_common::model::EntrypointId;
+use gauntlet_common::model::EntrypointSearchAlias;
 use gauntlet_common::model::PhysicalShortcut;
 use gauntlet_common::model::PluginId;
 use gauntlet_common::model::SettingsEntrypointType;
@@ -21,8 +22,11 @@ use iced::widget::row;
 use iced::widget::scrollable;
 use iced::widget::text;
 use iced::widget::text_input;
+use iced_fonts::bootstrap::arrow_down;
+use iced_fonts::bootstrap::arrow_up;
 use iced_fonts::bootstrap::caret_down;
 use iced_fonts::bootstrap::caret_right;
+use iced_fonts::bootstrap::x;
 
 use crate::ui::settings::components::shortcut_selector::ShortcutData;
 use crate::ui::settings::components::shortcut_selector::shortcut_selector;
@@ -46,7 +50,12 @@ pub enum PluginTableMsgIn {
         entrypoint_id: EntrypointId,
     },
     ShortcutCaptured(PluginId, EntrypointId, Option<PhysicalShortcut>),
-    AliasChanged(PluginId, EntrypointId, String),
+    NewAliasChanged(PluginId, EntrypointId, String),
+    AddAlias(PluginId, EntrypointId, Vec<EntrypointSearchAlias>),
+    AliasesChanged(PluginId, EntrypointId, Vec<EntrypointSearchAlias>),
+    SearchScopeChanged(PluginId, String),
+    PinnedPositionChanged(PluginId, EntrypointId, usize),
+    Unpin(PluginId, EntrypointId),
 }
 
 pub enum PluginTableMsgOut {
@@ -68,11 +77,18 @@ pub enum PluginTableMsgOut {
         entrypoint_id: EntrypointId,
     },
     ShortcutCaptured(PluginId, EntrypointId, Option<PhysicalShortcut>),
-    AliasChanged(PluginId, EntrypointId, Option<String>),
+    AliasesChanged(PluginId, EntrypointId, Vec<EntrypointSearchAlias>),
+    SearchScopeChanged(PluginId, Option<String>),
+    PinnedPositionChanged(PluginId, EntrypointId, usize),
+    Unpin(PluginId, EntrypointId),
+    Noop,
 }
 
 pub struct PluginTableState {
     rows: Vec<Row>,
+    pinned_count: usize,
+    // text of "Add Alias" inputs, alias is saved only when it is submitted
+    new_aliases: HashMap<(PluginId, EntrypointId), String>,
     pub show_global_shortcuts: bool,
 }
 
@@ -80,6 +96,8 @@ impl PluginTableState {
     pub fn new() -> Self {
         Self {
             rows: vec![],
+            pinned_count: 0,
+            new_aliases: HashMap::new(),
             show_global_shortcuts: false,
         }
     }
@@ -120,12 +138,57 @@ impl PluginTableState {
             PluginTableMsgIn::ShortcutCaptured(plugin_id, entrypoint_id, shortcut) => {
                 PluginTableMsgOut::ShortcutCaptured(plugin_id, entrypoint_id, shortcut)
             }
-            PluginTableMsgIn::AliasChanged(plugin_id, entrypoint_id, alias) => {
-                let alias = alias.trim().to_owned();
-                let alias = if alias.is_empty() { None } else { Some(alias) };
+            PluginTableMsgIn::NewAliasChanged(plugin_id, entrypoint_id, alias) => {
+                self.new_aliases.insert((plugin_id, entrypoint_id), alias);
 
-                PluginTableMsgOut::AliasChanged(plugin_id, entrypoint_id, alias)
+                PluginTableMsgOut::Noop
             }
+            PluginTableMsgIn::AddAlias(plugin_id, entrypoint_id, mut aliases) => {
+                let Some(alias) = self.new_aliases.remove(&(plugin_id.clone(), entrypoint_id.clone())) else {
+                    return PluginTableMsgOut::Noop;
+                };
+
+                let alias = alias.trim();
+
+                // "gh <text>" is a keyword alias which passes rest of the query to entrypoint
+                let (alias, keyword) = match alias.strip_suffix("<text>") {
+                    Some(alias) => (alias.trim_end(), true),
+                    None => (alias, false),
+                };
+
+                // keyword alias has to be a first word of the query
+                if alias.is_empty() || (keyword && alias.contains(char::is_whitespace)) {
+                    return PluginTableMsgOut::Noop;
+                }
+
+                if aliases.iter().any(|existing| existing.alias == alias) {
+                    return PluginTableMsgOut::Noop;
+                }
+
+                aliases.push(EntrypointSearchAlias {
+                    alias: alias.to_owned(),
+                    keyword,
+                });
+
+                PluginTableMsgOut::AliasesChanged(plugin_id, entrypoint_id, aliases)
+            }
+            PluginTableMsgIn::AliasesChanged(plugin_id, entrypoint_id, aliases) => {
+                PluginTableMsgOut::AliasesChanged(plugin_id, entrypoint_id, aliases)
+            }
+            PluginTableMsgIn::SearchScopeChanged(plugin_id, keyword) => {
+                // keyword is followed by colon or space in search query, so it cannot contain them
+                let keyword: String = keyword
+                    .chars()
+                    .filter(|char| !char.is_whitespace() && *char != ':')
+                    .collect();
+                let keyword = if keyword.is_empty() { None } else { Some(keyword) };
+
+                PluginTableMsgOut::SearchScopeChanged(plugin_id, keyword)
+            }
+            PluginTableMsgIn::PinnedPositionChanged(plugin_id, entrypoint_id, position) => {
+                PluginTableMsgOut::PinnedPositionChanged(plugin_id, entrypoint_id, position)
+            }
+            PluginTableMsgIn::Unpin(plugin_id, entrypoint_id) => PluginTableMsgOut::Unpin(plugin_id, entrypoint_id),
         }
     }
 
@@ -134,8 +197,19 @@ impl PluginTableState {
         plugin_data: Rc<RefCell<PluginDataContainer>>,
         plugin_refs: Vec<(&SettingsPlugin, &SettingsPluginData)>,
         global_entrypoint_shortcuts: HashMap<(PluginId, EntrypointId), (PhysicalShortcut, Option<String>)>,
-        entrypoint_search_aliases: HashMap<(PluginId, EntrypointId), String>,
+        entrypoint_search_aliases: HashMap<(PluginId, EntrypointId), Vec<EntrypointSearchAlias>>,
+        pinned_entrypoints: Vec<(PluginId, EntrypointId)>,
     ) {
+        let pinned_position = |plugin_id: &PluginId, entrypoint_id: &EntrypointId| {
+            pinned_entrypoints
+                .iter()
+                .position(|(pinned_plugin_id, pinned_entrypoint_id)| {
+                    pinned_plugin_id == plugin_id && pinned_entrypoint_id == entrypoint_id
+                })
+        };
+
+        self.pinned_count = pinned_entrypoints.len();
+
         self.rows = plugin_refs
             .iter()
             .flat_map(|(plugin, plugin_state)| {
@@ -157,16 +231,18 @@ impl PluginTableState {
                         let shortcut = global_entrypoint_shortcut.map(|(shortcut, _)| shortcut).cloned();
                         let error = global_entrypoint_shortcut.map(|(_, error)| error).cloned().flatten();
 
-                        let search_alias = entrypoint_search_aliases
+                        let search_aliases = entrypoint_search_aliases
                             .get(&(plugin.plugin_id.clone(), entrypoint.entrypoint_id.clone()))
-                            .cloned();
+                            .cloned()
+                            .unwrap_or_default();
 
                         let entrypoint_row = Row::Entrypoint {
                             plugin_data: plugin_data.clone(),
                             plugin_id: plugin.plugin_id.clone(),
                             entrypoint_id: entrypoint.entrypoint_id.clone(),
                             shortcut_data: ShortcutData { shortcut, error },
-                            search_alias,
+                            search_aliases,
+                            pinned_position: pinned_position(&plugin.plugin_id, &entrypoint.entrypoint_id),
                         };
 
                         result.push(entrypoint_row);
@@ -192,9 +268,10 @@ impl PluginTableState {
                                 let shortcut = global_entrypoint_shortcut.map(|(shortcut, _)| shortcut).cloned();
                                 let error = global_entrypoint_shortcut.map(|(_, error)| error).cloned().flatten();
 
-                                let search_alias = entrypoint_search_aliases
+                                let search_aliases = entrypoint_search_aliases
                                     .get(&(plugin.plugin_id.clone(), data.entrypoint_id.clone()))
-                                    .cloned();
+                                    .cloned()
+                                    .unwrap_or_default();
 
                                 let generated_entrypoint_row = Row::GeneratedEntrypoint {
                                     plugin_data: plugin_data.clone(),
@@ -202,7 +279,8 @@ impl PluginTableState {
                                     generator_entrypoint_id: entrypoint.entrypoint_id.clone(),
                                     generated_entrypoint_id: data.entrypoint_id.clone(),
                                     shortcut_data: ShortcutData { shortcut, error },
-                                    search_alias,
+                                    search_aliases,
+                                    pinned_position: pinned_position(&plugin.plugin_id, &data.entrypoint_id),
                                 };
 
                                 result.push(generated_entrypoint_row);
@@ -237,7 +315,8 @@ impl PluginTableState {
                     } else {
                         None
                     },
-                    alias_cell(row_item),
+                    alias_cell(row_item, &self.new_aliases),
+                    pinned_cell(row_item, self.pinned_count),
                     enable_cell(row_item),
                 )
             })
@@ -253,6 +332,7 @@ impl PluginTableState {
                 None
             },
             header_cell("Alias", false),
+            header_cell("Pinned", false),
             header_cell("Enabled", false),
         );
 
@@ -287,7 +367,8 @@ enum Row {
         plugin_id: PluginId,
         entrypoint_id: EntrypointId,
         shortcut_data: ShortcutData,
-        search_alias: Option<String>,
+        search_aliases: Vec<EntrypointSearchAlias>,
+        pinned_position: Option<usize>,
     },
     GeneratedEntrypoint {
         plugin_data: Rc<RefCell<PluginDataContainer>>,
@@ -295,7 +376,8 @@ enum Row {
         generator_entrypoint_id: EntrypointId,
         generated_entrypoint_id: EntrypointId,
         shortcut_data: ShortcutData,
-        search_alias: Option<String>,
+        search_aliases: Vec<EntrypointSearchAlias>,
+        pinned_position: Option<usize>,
     },
 }
 
@@ -305,6 +387,7 @@ fn table_row<'a>(
     type_cell: Element<'a, PluginTableMsgIn>,
     shortcut_cell: Option<Element<'a, PluginTableMsgIn>>,
     alias_cell: Element<'a, PluginTableMsgIn>,
+    pinned_cell: Element<'a, PluginTableMsgIn>,
     enable_cell: Element<'a, PluginTableMsgIn>,
 ) -> Element<'a, PluginTableMsgIn> {
     let content = row([])
@@ -312,6 +395,7 @@ fn table_row<'a>(
         .push(container(type_cell).width(Length::FillPortion(12)))
         .push_maybe(shortcut_cell.map(|item| container(item).width(Length::FillPortion(24))))
         .push(container(alias_cell).width(Length::FillPortion(15)))
+        .push(container(pinned_cell).width(Length::FillPortion(14)))
         .push(container(enable_cell).width(Length::FillPortion(10)));
 
     container(content).class(style).into()
@@ -627,14 +711,32 @@ fn shortcut_cell<'a>(row_entry: &Row) -> Element<'a, PluginTableMsgIn> {
     }
 }
 
-fn alias_cell<'a>(row_entry: &Row) -> Element<'a, PluginTableMsgIn> {
+fn alias_cell<'a>(
+    row_entry: &Row,
+    new_aliases: &HashMap<(PluginId, EntrypointId), String>,
+) -> Element<'a, PluginTableMsgIn> {
     match &row_entry {
-        Row::Plugin { .. } => horizontal_space().into(),
+        Row::Plugin { plugin_data, plugin_id } => {
+            let plugin_data = plugin_data.borrow();
+            let plugin = plugin_data.plugins.get(&plugin_id).unwrap();
+
+            let input = text_input("Add Scope", plugin.search_scope.as_deref().unwrap_or(""))
+                .class(TextInputStyle::EntrypointAlias)
+                .padding(padding::all(12.0).left(7.0))
+                .size(14)
+                .on_input({
+                    let plugin_id = plugin_id.clone();
+
+                    move |keyword| PluginTableMsgIn::SearchScopeChanged(plugin_id.clone(), keyword)
+                });
+
+            container(input).height(Length::Fixed(40.0)).width(Length::Fill).into()
+        }
         Row::Entrypoint {
             plugin_data,
             plugin_id,
             entrypoint_id,
-            search_alias,
+            search_aliases,
             ..
         } => {
             let plugin_data = plugin_data.borrow();
@@ -642,18 +744,7 @@ fn alias_cell<'a>(row_entry: &Row) -> Element<'a, PluginTableMsgIn> {
             let entrypoint = plugin.entrypoints.get(&entrypoint_id).unwrap();
 
             if let SettingsEntrypointType::View | SettingsEntrypointType::Command = entrypoint.entrypoint_type {
-                let input = text_input("Add Alias", search_alias.as_deref().unwrap_or(""))
-                    .class(TextInputStyle::EntrypointAlias)
-                    .padding(padding::all(12.0).left(7.0))
-                    .size(14)
-                    .on_input({
-                        let plugin_id = plugin_id.clone();
-                        let entrypoint_id = entrypoint_id.clone();
-
-                        move |alias| PluginTableMsgIn::AliasChanged(plugin_id.clone(), entrypoint_id.clone(), alias)
-                    });
-
-                container(input).height(Length::Fixed(40.0)).width(Length::Fill).into()
+                aliases_input(plugin_id, entrypoint_id, search_aliases, new_aliases)
             } else {
                 horizontal_space().into()
             }
@@ -661,25 +752,141 @@ fn alias_cell<'a>(row_entry: &Row) -> Element<'a, PluginTableMsgIn> {
         Row::GeneratedEntrypoint {
             plugin_id,
             generated_entrypoint_id,
-            search_alias,
+            search_aliases,
             ..
-        } => {
-            let input = text_input("Add Alias", search_alias.as_deref().unwrap_or(""))
-                .class(TextInputStyle::EntrypointAlias)
-                .padding(padding::all(12.0).left(7.0))
+        } => aliases_input(plugin_id, generated_entrypoint_id, search_aliases, new_aliases),
+    }
+}
+
+fn aliases_input<'a>(
+    plugin_id: &PluginId,
+    entrypoint_id: &EntrypointId,
+    search_aliases: &[EntrypointSearchAlias],
+    new_aliases: &HashMap<(PluginId, EntrypointId), String>,
+) -> Element<'a, PluginTableMsgIn> {
+    let mut content: Vec<Element<_>> = search_aliases
+        .iter()
+        .enumerate()
+        .map(|(index, alias)| {
+            let label = if alias.keyword {
+                format!("{} <text>", alias.alias)
+            } else {
+                alias.alias.clone()
+            };
+
+            let label: Element<_> = text(label)
+                .shaping(Shaping::Advanced)
                 .size(14)
-                .on_input({
-                    let plugin_id = plugin_id.clone();
-                    let generated_entrypoint_id = generated_entrypoint_id.clone();
+                .width(Length::Fill)
+                .into();
 
-                    move |alias| {
-                        PluginTableMsgIn::AliasChanged(plugin_id.clone(), generated_entrypoint_id.clone(), alias)
-                    }
-                });
+            let mut remaining_aliases = search_aliases.to_vec();
+            remaining_aliases.remove(index);
 
-            container(input).height(Length::Fixed(40.0)).width(Length::Fill).into()
-        }
-    }
+            let remove: Element<_> = button(x())
+                .class(ButtonStyle::TableRow)
+                .padding(4.0)
+                .on_press(PluginTableMsgIn::AliasesChanged(
+                    plugin_id.clone(),
+                    entrypoint_id.clone(),
+                    remaining_aliases,
+                ))
+                .into();
+
+            let alias: Element<_> = row(vec![label, remove])
+                .align_y(Alignment::Center)
+                .padding(padding::left(7.0))
+                .into();
+
+            alias
+        })
+        .collect();
+
+    let new_alias = new_aliases
+        .get(&(plugin_id.clone(), entrypoint_id.clone()))
+        .map(|alias| alias.as_str())
+        .unwrap_or("");
+
+    let input: Element<_> = text_input("Add Alias", new_alias)
+        .class(TextInputStyle::EntrypointAlias)
+        .padding(padding::all(12.0).left(7.0))
+        .size(14)
+        .on_input({
+            let plugin_id = plugin_id.clone();
+            let entrypoint_id = entrypoint_id.clone();
+
+            move |alias| PluginTableMsgIn::NewAliasChanged(plugin_id.clone(), entrypoint_id.clone(), alias)
+        })
+        .on_submit(PluginTableMsgIn::AddAlias(
+            plugin_id.clone(),
+            entrypoint_id.clone(),
+            search_aliases.to_vec(),
+        ))
+        .into();
+
+    let input: Element<_> = container(input).height(Length::Fixed(40.0)).width(Length::Fill).into();
+
+    content.push(input);
+
+    column(content).width(Length::Fill).into()
+}
+
+fn pinned_cell<'a>(row_entry: &Row, pinned_count: usize) -> Element<'a, PluginTableMsgIn> {
+    let (plugin_id, entrypoint_id, position) = match row_entry {
+        Row::Plugin { .. } => return horizontal_space().into(),
+        Row::Entrypoint {
+            plugin_id,
+            entrypoint_id,
+            pinned_position,
+            ..
+        } => (plugin_id, entrypoint_id, pinned_position),
+        Row::GeneratedEntrypoint {
+            plugin_id,
+            generated_entrypoint_id,
+            pinned_position,
+            ..
+        } => (plugin_id, generated_entrypoint_id, pinned_position),
+    };
+
+    let Some(position) = *position else {
+        return horizontal_space().into();
+    };
+
+    let position_text: Element<_> = container(text((position + 1).to_string()).size(14))
+        .padding(padding::right(4.0))
+        .into();
+
+    let move_up = button(arrow_up())
+        .class(ButtonStyle::TableRow)
+        .padding(8.0)
+        .on_press_maybe(position.checked_sub(1).map(|new_position| {
+            PluginTableMsgIn::PinnedPositionChanged(plugin_id.clone(), entrypoint_id.clone(), new_position)
+        }))
+        .into();
+
+    let move_down =
+        button(arrow_down())
+            .class(ButtonStyle::TableRow)
+            .padding(8.0)
+            .on_press_maybe((position + 1 < pinned_count).then(|| {
+                PluginTableMsgIn::PinnedPositionChanged(plugin_id.clone(), entrypoint_id.clone(), position + 1)
+            }))
+            .into();
+
+    let unpin = button(x())
+        .class(ButtonStyle::TableRow)
+        .padding(8.0)
+        .on_press(PluginTableMsgIn::Unpin(plugin_id.clone(), entrypoint_id.clone()))
+        .into();
+
+    let content: Element<_> = row(vec![position_text, move_up, move_down, unpin])
+        .align_y(Alignment::Center)
+        .into();
+
+    container(content)
+        .height(Length::Fixed(40.0))
+        .align_y(Alignment::Center)
+        .into()
 }
 
 fn enable_cell<'a>(row: &Row) -> Element<'a, PluginTableMsgIn> {
@@ -1,6 +1,5 @@
 use gauntlet_common::model::PhysicalShortcut;
 use gauntlet_common::model::SearchResult;
-use gauntlet_common::model::SearchResultEntrypointAction;
 use gauntlet_common::model::SearchResultEntrypointType;
 use gauntlet_common::model::UiWidgetId;
 
@@ -16,7 +15,7 @@ pub enum MainViewState {
     None,
     SearchResultActionPanel {
         search_result: SearchResult,
-        entrypoint_actions: ScrollContent<SearchResultEntrypointAction>,
+        actions: ScrollContent<UiWidgetId>,
         scroll_handle: ScrollHandle,
     },
     InlineViewActionPanel {
@@ -41,16 +40,25 @@ impl MainViewState {
             None
         };
 
-        let items = search_result
-            .entrypoint_actions
-            .iter()
-            .enumerate()
-            .map(|(index, action)| (action_item_container_id(index), action.clone()))
+        let items = search_result_action_panel(&search_result)
+            .map(|action_panel| action_panel.items)
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|item| {
+                match item {
+                    ActionPanelItem::Action {
+                        container_id,
+                        widget_id,
+                        ..
+                    } => Some((container_id, widget_id)),
+                    ActionPanelItem::ActionSection { .. } => None,
+                }
+            })
             .collect();
 
         *prev_state = Self::SearchResultActionPanel {
             search_result: search_result.clone(),
-            entrypoint_actions: ScrollContent::new_with_ids(items),
+            actions: ScrollContent::new_with_ids(items),
             scroll_handle: ScrollHandle::new(first_action_item),
         }
     }
@@ -97,31 +105,29 @@ pub fn search_result_action_panel(search_item: &SearchResult) -> Option<ActionPa
 
         let primary_action_widget_id = 0;
 
-        if actions.is_empty() {
-            None
-        } else {
-            let label = label.to_string();
+        let label = label.to_string();
 
-            let primary_action = ActionPanelItem::Action {
-                label: label.clone(),
-                container_id: action_item_container_id(0),
-                widget_id: primary_action_widget_id,
-                physical_shortcut: Some(primary_shortcut()),
-            };
+        let primary_action = ActionPanelItem::Action {
+            label: label.clone(),
+            container_id: action_item_container_id(0),
+            widget_id: primary_action_widget_id,
+            physical_shortcut: Some(primary_shortcut()),
+        };
 
-            actions.insert(0, primary_action);
+        actions.insert(0, primary_action);
 
-            let action_panel = ActionPanel {
-                title: Some(search_item.entrypoint_name.clone()),
-                items: actions,
-            };
+        actions.push(pin_action(search_item));
 
-            Some(action_panel)
-        }
+        let action_panel = ActionPanel {
+            title: Some(search_item.entrypoint_name.clone()),
+            items: actions,
+        };
+
+        Some(action_panel)
     }
 
     fn create_generated(search_item: &SearchResult) -> Option<ActionPanel> {
-        let actions: Vec<_> = search_item
+        let mut actions: Vec<_> = search_item
             .entrypoint_actions
             .iter()
             .enumerate()
@@ -141,6 +147,8 @@ pub fn search_result_action_panel(search_item: &SearchResult) -> Option<ActionPa
             })
             .collect();
 
+        actions.push(pin_action(search_item));
+
         let action_panel = ActionPanel {
             title: Some(search_item.entrypoint_name.clone()),
             items: actions,
@@ -149,6 +157,23 @@ pub fn search_result_action_panel(search_item: &SearchResult) -> Option<ActionPa
         Some(action_panel)
     }
 
+    fn pin_action(search_item: &SearchResult) -> ActionPanelItem {
+        let widget_id = pin_action_widget_id(search_item);
+
+        let label = if search_item.entrypoint_pinned {
+            "Unpin"
+        } else {
+            "Pin to Top"
+        };
+
+        ActionPanelItem::Action {
+            label: label.to_string(),
+            container_id: action_item_container_id(widget_id),
+            widget_id,
+            physical_shortcut: None,
+        }
+    }
+
     match search_item.entrypoint_type {
         SearchResultEntrypointType::Command => create_static(search_item, "Run Command"),
         SearchResultEntrypointType::View => create_static(search_item, "Open View"),
@@ -156,6 +181,16 @@ pub fn search_result_action_panel(search_item: &SearchResult) -> Option<ActionPa
     }
 }
 
+// pin action always goes after all other actions, static entrypoints also have primary action in front of them
+pub fn pin_action_widget_id(search_item: &SearchResult) -> UiWidgetId {
+    match search_item.entrypoint_type {
+        SearchResultEntrypointType::Command | SearchResultEntrypointType::View => {
+            search_item.entrypoint_actions.len() + 1
+        }
+        SearchResultEntrypointType::Generated => search_item.entrypoint_actions.len(),
+    }
+}
+
 pub fn search_result_bot_panel_right_info(search_item: &SearchResult) -> (String, usize, PhysicalShortcut) {
     fn create_static(search_item: &SearchResult, label: &str) -> (String, usize, PhysicalShortcut) {
         let primary_action_widget_id = 0;
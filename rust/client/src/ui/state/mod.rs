@@ -206,16 +206,16 @@ impl Focus<SearchResult> for GlobalState {
                     }
                     MainViewState::SearchResultActionPanel {
                         search_result,
-                        entrypoint_actions,
+                        actions,
                         scroll_handle,
                     } => {
-                        let Some(index) = scroll_handle.get_index(entrypoint_actions) else {
+                        let Some(widget_id) = scroll_handle.get(actions) else {
                             return Task::none();
                         };
 
                         Task::done(AppMsg::OnAnyActionMainViewSearchResultPanelKeyboardWithFocus {
                             search_result: search_result.clone(),
-                            index,
+                            index: *widget_id,
                         })
                     }
                     MainViewState::InlineViewActionPanel { scroll_handle, actions } => {
@@ -408,11 +408,9 @@ impl Focus<SearchResult> for GlobalState {
                         task.unwrap_or(Task::none())
                     }
                     MainViewState::SearchResultActionPanel {
-                        entrypoint_actions,
-                        scroll_handle,
-                        ..
+                        actions, scroll_handle, ..
                     } => {
-                        let (_, task) = scroll_handle.list_focus_up(entrypoint_actions.ids());
+                        let (_, task) = scroll_handle.list_focus_up(actions.ids());
 
                         task.unwrap_or(Task::none())
                     }
@@ -461,11 +459,9 @@ impl Focus<SearchResult> for GlobalState {
                         task.unwrap_or(Task::none())
                     }
                     MainViewState::SearchResultActionPanel {
-                        entrypoint_actions,
-                        scroll_handle,
-                        ..
+                        actions, scroll_handle, ..
                     } => {
-                        let (_, task) = scroll_handle.list_focus_down(entrypoint_actions.ids());
+                        let (_, task) = scroll_handle.list_focus_down(actions.ids());
 
                         task.unwrap_or(Task::none())
                     }
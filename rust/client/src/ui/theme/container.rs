@@ -41,6 +41,7 @@ pub enum ContainerStyle {
     MainListItemSubText,
     MainListItemText,
     MainSearchBar,
+    MainSearchBarScope,
     MetadataInner,
     MetadataItemValue,
     MetadataItemValueInList,
@@ -366,6 +367,10 @@ impl<'a, Message: 'a> ThemableWidget<'a, Message> for Container<'a, Message, Gau
             ContainerStyle::MainList => self.padding(theme.main_list.padding.to_iced()),
             ContainerStyle::MainListInner => self.padding(theme.main_list_inner.padding.to_iced()),
             ContainerStyle::MainSearchBar => self.padding(theme.main_search_bar.padding.to_iced()),
+            ContainerStyle::MainSearchBarScope => {
+                self.padding(theme.main_list_item_alias.padding.to_iced())
+                    .class(ContainerStyleInner::MainListItemAlias)
+            }
             ContainerStyle::Root => self.class(ContainerStyleInner::Root),
             ContainerStyle::PluginErrorViewTitle => self.padding(theme.plugin_error_view_title.padding.to_iced()),
             ContainerStyle::PluginErrorViewDescription => {
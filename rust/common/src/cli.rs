@@ -1,10 +1,34 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::path::PathBuf;
+use std::time::Duration;
+use std::time::SystemTime;
+
+use anyhow::anyhow;
 use gauntlet_utils::channel::RequestError;
+use serde_json::Value;
+use serde_json::json;
+use tokio_stream::StreamExt;
 
+use crate::dirs::Dirs;
+use crate::model::CliDiagnostics;
+use crate::model::CliEntrypointAction;
+use crate::model::DownloadStatus;
 use crate::model::EntrypointId;
+use crate::model::PhysicalShortcut;
 use crate::model::PluginId;
+use crate::model::PluginSource;
+use crate::model::SearchResult;
+use crate::model::SearchResultAccessory;
+use crate::model::SearchResultEntrypointActionType;
+use crate::model::SearchResultEntrypointType;
+use crate::model::ServerEvent;
+use crate::model::SettingsEntrypointType;
 use crate::rpc::backend_api::BackendForCliApi;
 use crate::rpc::backend_api::BackendForCliApiProxy;
 use crate::rpc::backend_api::GrpcBackendApi;
+use crate::rpc::transport::BackendTransport;
+use crate::time::format_duration;
 
 pub fn is_server_running() -> bool {
     tokio::runtime::Builder::new_current_thread()
@@ -72,7 +96,13 @@ pub fn open_settings_window() {
         })
 }
 
-pub fn run_action(plugin_id: String, entrypoint_id: String, action_id: String) {
+pub fn run_action(
+    plugin_id: String,
+    entrypoint_id: String,
+    action_id: String,
+    arguments: HashMap<String, String>,
+    query: Option<String>,
+) {
     tokio::runtime::Builder::new_current_thread()
         .enable_all()
         .build()
@@ -87,7 +117,10 @@ pub fn run_action(plugin_id: String, entrypoint_id: String, action_id: String) {
                     let plugin_id = PluginId::from_string(plugin_id);
                     let entrypoint_id = EntrypointId::from_string(entrypoint_id);
 
-                    if let Err(err) = backend_api.run_action(plugin_id, entrypoint_id, action_id).await {
+                    if let Err(err) = backend_api
+                        .run_action(plugin_id, entrypoint_id, action_id, arguments, query)
+                        .await
+                    {
                         match err {
                             RequestError::Timeout => {
                                 tracing::error!("Timeout occurred when handling command");
@@ -107,3 +140,796 @@ pub fn run_action(plugin_id: String, entrypoint_id: String, action_id: String) {
             }
         })
 }
+
+pub fn search(query: String, json: bool) -> anyhow::Result<()> {
+    let results = block_on_backend(|backend_api| async move { Ok(backend_api.search(query).await?) })?;
+
+    if json {
+        let results: Vec<_> = results.iter().map(search_result_to_json).collect();
+
+        println!("{}", serde_json::to_string_pretty(&results)?);
+    } else {
+        for item in results {
+            println!(
+                "{}\t{}\t{}\t{}\t{}",
+                item.entrypoint_name,
+                entrypoint_type_label(&item.entrypoint_type),
+                item.plugin_name,
+                item.plugin_id,
+                item.entrypoint_id
+            );
+        }
+    }
+
+    Ok(())
+}
+
+pub fn list_plugins(json: bool) -> anyhow::Result<()> {
+    let plugins = block_on_backend(|backend_api| async move { Ok(backend_api.plugins().await?) })?;
+
+    let mut plugins: Vec<_> = plugins.into_values().collect();
+    plugins.sort_by(|a, b| a.plugin_id.to_string().cmp(&b.plugin_id.to_string()));
+
+    if json {
+        let plugins: Vec<_> = plugins
+            .iter()
+            .map(|plugin| {
+                json!({
+                    "plugin_id": plugin.plugin_id.to_string(),
+                    "plugin_name": plugin.plugin_name,
+                    "plugin_description": plugin.plugin_description,
+                    "enabled": plugin.enabled,
+                })
+            })
+            .collect();
+
+        println!("{}", serde_json::to_string_pretty(&plugins)?);
+    } else {
+        let rows = plugins
+            .iter()
+            .map(|plugin| {
+                vec![
+                    plugin.plugin_id.to_string(),
+                    plugin.plugin_name.clone(),
+                    plugin.enabled.to_string(),
+                    plugin.entrypoints.len().to_string(),
+                ]
+            })
+            .collect();
+
+        print_table(&["ID", "NAME", "ENABLED", "ENTRYPOINTS"], rows);
+    }
+
+    Ok(())
+}
+
+pub fn list_entrypoints(plugin_id: String, json: bool) -> anyhow::Result<()> {
+    let plugin_id = PluginId::from_string(plugin_id);
+
+    let mut plugins = block_on_backend(|backend_api| async move { Ok(backend_api.plugins().await?) })?;
+
+    let plugin = plugins
+        .remove(&plugin_id)
+        .ok_or_else(|| anyhow!("Unable to find plugin with id: {}", plugin_id))?;
+
+    let mut entrypoints: Vec<_> = plugin.entrypoints.into_values().collect();
+    entrypoints.sort_by(|a, b| a.entrypoint_id.to_string().cmp(&b.entrypoint_id.to_string()));
+
+    // generated entrypoints are listed right after their generator
+    let mut items = vec![];
+    for entrypoint in entrypoints {
+        let mut generated: Vec<_> = entrypoint.generated_entrypoints.values().cloned().collect();
+        generated.sort_by(|a, b| a.entrypoint_id.to_string().cmp(&b.entrypoint_id.to_string()));
+
+        items.push((
+            entrypoint.entrypoint_id.to_string(),
+            entrypoint.entrypoint_name.clone(),
+            entrypoint.entrypoint_description.clone(),
+            settings_entrypoint_type_label(&entrypoint.entrypoint_type),
+            entrypoint.enabled,
+            None,
+        ));
+
+        for generated_entrypoint in generated {
+            items.push((
+                generated_entrypoint.entrypoint_id.to_string(),
+                generated_entrypoint.entrypoint_name,
+                String::new(),
+                "generated",
+                entrypoint.enabled,
+                Some(entrypoint.entrypoint_id.to_string()),
+            ));
+        }
+    }
+
+    if json {
+        let items: Vec<_> = items
+            .into_iter()
+            .map(|(id, name, description, entrypoint_type, enabled, generator_id)| {
+                json!({
+                    "entrypoint_id": id,
+                    "entrypoint_name": name,
+                    "entrypoint_description": description,
+                    "entrypoint_type": entrypoint_type,
+                    "enabled": enabled,
+                    "generator_entrypoint_id": generator_id,
+                })
+            })
+            .collect();
+
+        println!("{}", serde_json::to_string_pretty(&items)?);
+    } else {
+        let rows = items
+            .into_iter()
+            .map(|(id, name, _, entrypoint_type, enabled, generator_id)| {
+                vec![
+                    id,
+                    name,
+                    entrypoint_type.to_string(),
+                    enabled.to_string(),
+                    generator_id.unwrap_or_else(|| "-".to_string()),
+                ]
+            })
+            .collect();
+
+        print_table(&["ID", "NAME", "TYPE", "ENABLED", "GENERATOR"], rows);
+    }
+
+    Ok(())
+}
+
+pub fn list_actions(plugin_id: String, entrypoint_id: String, json: bool) -> anyhow::Result<()> {
+    let plugin_id = PluginId::from_string(plugin_id);
+    let entrypoint_id = EntrypointId::from_string(entrypoint_id);
+
+    let actions =
+        block_on_backend(
+            |backend_api| async move { Ok(backend_api.entrypoint_actions(plugin_id, entrypoint_id).await?) },
+        )?;
+
+    if json {
+        let actions: Vec<_> = actions.iter().map(entrypoint_action_to_json).collect();
+
+        println!("{}", serde_json::to_string_pretty(&actions)?);
+    } else {
+        let rows = actions
+            .iter()
+            .map(|action| {
+                vec![
+                    action.action_id.clone().unwrap_or_else(|| "-".to_string()),
+                    action.label.clone(),
+                    action_type_label(&action.action_type).to_string(),
+                ]
+            })
+            .collect();
+
+        print_table(&["ID", "LABEL", "TYPE"], rows);
+    }
+
+    Ok(())
+}
+
+pub fn install_plugin(plugin_id: String) -> anyhow::Result<()> {
+    let source = PluginSource::parse(&plugin_id);
+
+    block_on_backend(|backend_api| {
+        async move {
+            let plugin_id = source.plugin_id.clone();
+
+            backend_api.download_plugin(source).await?;
+
+            wait_for_download(&backend_api, &plugin_id).await
+        }
+    })
+}
+
+async fn wait_for_download(backend_api: &BackendForCliApiProxy, plugin_id: &PluginId) -> anyhow::Result<()> {
+    loop {
+        tokio::time::sleep(Duration::from_millis(500)).await;
+
+        let download_status = backend_api.download_status().await?;
+
+        match download_status.get(plugin_id) {
+            Some(DownloadStatus::InProgress) => continue,
+            // finished download status is removed after some time
+            Some(DownloadStatus::Done) | None => return Ok(()),
+            Some(DownloadStatus::Failed { message }) => {
+                return Err(anyhow!("Unable to install plugin {}: {}", plugin_id, message));
+            }
+        }
+    }
+}
+
+pub fn sync_plugins(dry_run: bool) -> anyhow::Result<()> {
+    block_on_backend(|backend_api| {
+        async move {
+            let report = backend_api.sync_plugins(dry_run).await?;
+
+            for warning in &report.warnings {
+                eprintln!("warning: {}", warning);
+            }
+
+            if report.changes.is_empty() {
+                println!("Nothing to change");
+            }
+
+            for change in &report.changes {
+                println!("{}", change);
+            }
+
+            let mut failed = false;
+
+            for plugin_id in &report.installing {
+                match wait_for_download(&backend_api, plugin_id).await {
+                    Ok(()) => println!("Installed plugin {}", plugin_id),
+                    Err(err) => {
+                        eprintln!("{:#}", err);
+                        failed = true;
+                    }
+                }
+            }
+
+            if failed {
+                Err(anyhow!("Some of the plugins were not installed"))
+            } else {
+                Ok(())
+            }
+        }
+    })
+}
+
+pub fn remove_plugin(plugin_id: String) -> anyhow::Result<()> {
+    let plugin_id = PluginId::from_string(plugin_id);
+
+    block_on_backend(|backend_api| async move { Ok(backend_api.remove_plugin(plugin_id).await?) })
+}
+
+pub fn set_plugin_state(
+    plugin_id: String,
+    entrypoint_id: Option<String>,
+    enabled: bool,
+    allow_unsigned: bool,
+) -> anyhow::Result<()> {
+    let plugin_id = PluginId::from_string(plugin_id);
+
+    block_on_backend(|backend_api| {
+        async move {
+            match entrypoint_id {
+                None => backend_api.set_plugin_state(plugin_id, enabled, allow_unsigned).await?,
+                Some(entrypoint_id) => {
+                    let entrypoint_id = EntrypointId::from_string(entrypoint_id);
+
+                    backend_api
+                        .set_entrypoint_state(plugin_id, entrypoint_id, enabled)
+                        .await?
+                }
+            }
+
+            Ok(())
+        }
+    })
+}
+
+pub fn reload_plugins() -> anyhow::Result<()> {
+    block_on_backend(|backend_api| async move { Ok(backend_api.reload_all_plugins().await?) })
+}
+
+pub fn events(json: bool) -> anyhow::Result<()> {
+    tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .expect("unable to start server tokio runtime")
+        .block_on(async {
+            let backend_api = connect_backend().await?;
+
+            let stream = backend_api.subscribe().await?;
+            let mut stream = std::pin::pin!(stream);
+
+            // stream ends when server is stopped
+            while let Some(event) = stream.next().await {
+                let event = event?;
+
+                if json {
+                    println!("{}", serde_json::to_string(&server_event_to_json(&event))?);
+                } else {
+                    println!("{}", server_event_to_text(&event));
+                }
+            }
+
+            Ok(())
+        })
+}
+
+pub fn quit() -> anyhow::Result<()> {
+    block_on_backend(|backend_api| {
+        async move {
+            backend_api.shutdown(false).await?;
+
+            // wait until server stops accepting connections, so that it can be started again right away
+            for _ in 0..50 {
+                let Ok(backend_api) = GrpcBackendApi::new().await else {
+                    return Ok(());
+                };
+
+                if BackendForCliApiProxy::new(backend_api).ping().await.is_err() {
+                    return Ok(());
+                }
+
+                tokio::time::sleep(Duration::from_millis(200)).await;
+            }
+
+            Err(anyhow!("Server is still running after shutdown was requested"))
+        }
+    })
+}
+
+pub fn restart() -> anyhow::Result<()> {
+    block_on_backend(|backend_api| async move { Ok(backend_api.shutdown(true).await?) })
+}
+
+pub fn export_frecency(output: Option<PathBuf>) -> anyhow::Result<()> {
+    let data = block_on_backend(|backend_api| async move { Ok(backend_api.export_frecency().await?) })?;
+
+    match output {
+        None => println!("{}", data),
+        Some(output) => {
+            std::fs::write(&output, data)
+                .map_err(|err| anyhow!("Unable to write frecency data to {}: {}", output.display(), err))?
+        }
+    }
+
+    Ok(())
+}
+
+pub fn import_frecency(input: PathBuf) -> anyhow::Result<()> {
+    let data = std::fs::read_to_string(&input)
+        .map_err(|err| anyhow!("Unable to read frecency data from {}: {}", input.display(), err))?;
+
+    block_on_backend(|backend_api| async move { Ok(backend_api.import_frecency(data).await?) })
+}
+
+pub fn run_history(limit: Option<usize>, json: bool) -> anyhow::Result<()> {
+    let mut items = block_on_backend(|backend_api| async move { Ok(backend_api.run_history().await?) })?;
+
+    if let Some(limit) = limit {
+        items.truncate(limit);
+    }
+
+    if json {
+        let items: Vec<_> = items
+            .iter()
+            .map(|item| {
+                json!({
+                    "plugin_id": item.plugin_id.to_string(),
+                    "plugin_name": item.plugin_name,
+                    "entrypoint_id": item.entrypoint_id.to_string(),
+                    "entrypoint_name": item.entrypoint_name,
+                    "last_run": item.last_run.duration_since(SystemTime::UNIX_EPOCH).unwrap_or_default().as_secs(),
+                })
+            })
+            .collect();
+
+        println!("{}", serde_json::to_string_pretty(&items)?);
+    } else {
+        let rows = items
+            .into_iter()
+            .map(|item| {
+                let last_run = SystemTime::now().duration_since(item.last_run).unwrap_or_default();
+
+                vec![
+                    item.entrypoint_name,
+                    item.plugin_name,
+                    format!("{} ago", format_duration(last_run)),
+                    item.plugin_id.to_string(),
+                    item.entrypoint_id.to_string(),
+                ]
+            })
+            .collect();
+
+        print_table(&["NAME", "PLUGIN", "LAST RUN", "PLUGIN ID", "ENTRYPOINT ID"], rows);
+    }
+
+    Ok(())
+}
+
+/// Returns `false` if any problem was found
+pub fn doctor(json: bool) -> anyhow::Result<bool> {
+    let dirs = Dirs::new();
+    let transport = BackendTransport::read(&dirs);
+
+    let server_running = is_server_running();
+
+    let diagnostics = if server_running {
+        Some(block_on_backend(|backend_api| {
+            async move { Ok(backend_api.diagnostics().await?) }
+        })?)
+    } else {
+        None
+    };
+
+    let last_crash_log = last_crash_log(&dirs);
+
+    let healthy = match &diagnostics {
+        None => false,
+        Some(diagnostics) => {
+            diagnostics.global_shortcut_error.is_none()
+                && diagnostics.global_entrypoint_shortcut_errors.is_empty()
+                && diagnostics.not_running_plugins.is_empty()
+        }
+    };
+
+    let dir_paths = vec![
+        ("config_file", dirs.config_file()),
+        ("data_dir", dirs.data_dir()?),
+        ("cache_dir", dirs.cache_dir()),
+        ("state_dir", dirs.state_dir()),
+        ("logs_dir", dirs.logs_dir()),
+    ];
+
+    if json {
+        let dir_paths: serde_json::Map<_, _> = dir_paths
+            .into_iter()
+            .map(|(name, path)| (name.to_string(), json!(path.display().to_string())))
+            .collect();
+
+        let result = json!({
+            "healthy": healthy,
+            "server_running": server_running,
+            "control_transport": transport_label(&transport),
+            "dirs": dir_paths,
+            "config": diagnostics.as_ref().map(|diagnostics| {
+                json!({
+                    "close_on_unfocus": diagnostics.close_on_unfocus,
+                    "layer_shell": diagnostics.layer_shell,
+                    "wayland_use_legacy_x11_api": diagnostics.wayland_use_legacy_x11_api,
+                    "linux_native_hud": diagnostics.linux_native_hud,
+                })
+            }),
+            "global_shortcut_error": diagnostics.as_ref().and_then(|diagnostics| diagnostics.global_shortcut_error.clone()),
+            "global_entrypoint_shortcut_errors": diagnostics.as_ref().map(|diagnostics| {
+                diagnostics
+                    .global_entrypoint_shortcut_errors
+                    .iter()
+                    .map(|(plugin_id, entrypoint_id, error)| {
+                        json!({
+                            "plugin_id": plugin_id.to_string(),
+                            "entrypoint_id": entrypoint_id.to_string(),
+                            "error": error,
+                        })
+                    })
+                    .collect::<Vec<_>>()
+            }),
+            "not_running_plugins": diagnostics.as_ref().map(|diagnostics| {
+                diagnostics
+                    .not_running_plugins
+                    .iter()
+                    .map(|(plugin_id, plugin_name)| {
+                        json!({
+                            "plugin_id": plugin_id.to_string(),
+                            "plugin_name": plugin_name,
+                        })
+                    })
+                    .collect::<Vec<_>>()
+            }),
+            "last_crash_log": last_crash_log.as_ref().map(|(path, modified, content)| {
+                json!({
+                    "path": path.display().to_string(),
+                    "modified_seconds_ago": modified.as_secs(),
+                    "content": content,
+                })
+            }),
+        });
+
+        println!("{}", serde_json::to_string_pretty(&result)?);
+    } else {
+        println!(
+            "Server running: {}",
+            if server_running { "yes" } else { "no (problem)" }
+        );
+        println!("Control transport: {}", transport_label(&transport));
+
+        println!();
+        println!("Directories:");
+        print_table(
+            &["NAME", "PATH"],
+            dir_paths
+                .into_iter()
+                .map(|(name, path)| vec![name.to_string(), path.display().to_string()])
+                .collect(),
+        );
+
+        println!();
+        match &diagnostics {
+            None => println!("Config, shortcuts and plugins: unavailable, server is not running"),
+            Some(diagnostics) => print_diagnostics(diagnostics),
+        }
+
+        println!();
+        match &last_crash_log {
+            None => println!("Most recent crash log: none"),
+            Some((path, modified, content)) => {
+                println!(
+                    "Most recent crash log: {} (modified {} ago)",
+                    path.display(),
+                    format_duration(*modified)
+                );
+
+                for line in content.lines() {
+                    println!("    {}", line);
+                }
+            }
+        }
+
+        println!();
+        println!("{}", if healthy { "No problems found" } else { "Problems found" });
+    }
+
+    Ok(healthy)
+}
+
+fn print_diagnostics(diagnostics: &CliDiagnostics) {
+    println!("Config:");
+    print_table(
+        &["NAME", "VALUE"],
+        vec![
+            vec!["close_on_unfocus".to_string(), diagnostics.close_on_unfocus.to_string()],
+            vec!["layer_shell".to_string(), diagnostics.layer_shell.to_string()],
+            vec![
+                "wayland_use_legacy_x11_api".to_string(),
+                diagnostics.wayland_use_legacy_x11_api.to_string(),
+            ],
+            vec!["linux_native_hud".to_string(), diagnostics.linux_native_hud.to_string()],
+        ],
+    );
+
+    println!();
+    match &diagnostics.global_shortcut_error {
+        None => println!("Global shortcut: ok"),
+        Some(error) => println!("Global shortcut: registration failed (problem): {}", error),
+    }
+
+    for (plugin_id, entrypoint_id, error) in &diagnostics.global_entrypoint_shortcut_errors {
+        println!(
+            "Entrypoint global shortcut of {} {}: registration failed (problem): {}",
+            plugin_id, entrypoint_id, error
+        );
+    }
+
+    println!();
+    if diagnostics.not_running_plugins.is_empty() {
+        println!("Enabled plugins which are not running: none");
+    } else {
+        println!("Enabled plugins which are not running (problem):");
+        print_table(
+            &["ID", "NAME"],
+            diagnostics
+                .not_running_plugins
+                .iter()
+                .map(|(plugin_id, plugin_name)| vec![plugin_id.to_string(), plugin_name.clone()])
+                .collect(),
+        );
+    }
+}
+
+/// Server and plugin runtime crash logs are located in the same directory,
+/// crash log is removed when server or plugin runtime is started again
+fn last_crash_log(dirs: &Dirs) -> Option<(PathBuf, Duration, String)> {
+    let entries = std::fs::read_dir(dirs.logs_dir()).ok()?;
+
+    let (path, modified) = entries
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| {
+            let file_name = entry.file_name();
+            let file_name = file_name.to_string_lossy();
+
+            file_name.starts_with("crash") && file_name.ends_with(".txt")
+        })
+        .filter_map(|entry| {
+            let modified = entry.metadata().ok()?.modified().ok()?;
+
+            Some((entry.path(), modified))
+        })
+        .max_by_key(|(_, modified)| *modified)?;
+
+    let content = std::fs::read_to_string(&path).ok()?;
+
+    let modified = SystemTime::now().duration_since(modified).unwrap_or_default();
+
+    Some((path, modified, content))
+}
+
+fn transport_label(transport: &BackendTransport) -> String {
+    match transport {
+        #[cfg(unix)]
+        BackendTransport::UnixSocket { path } => format!("unix socket {}", path.display()),
+        BackendTransport::Tcp { address, token_file } => {
+            format!("tcp {} (token file {})", address, token_file.display())
+        }
+    }
+}
+
+fn print_table(headers: &[&str], rows: Vec<Vec<String>>) {
+    let mut widths: Vec<_> = headers.iter().map(|header| header.chars().count()).collect();
+
+    for row in &rows {
+        for (index, cell) in row.iter().enumerate() {
+            widths[index] = widths[index].max(cell.chars().count());
+        }
+    }
+
+    let format_row = |cells: Vec<String>| {
+        cells
+            .into_iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{:width$}", cell, width = width))
+            .collect::<Vec<_>>()
+            .join("  ")
+            .trim_end()
+            .to_string()
+    };
+
+    println!(
+        "{}",
+        format_row(headers.iter().map(|header| header.to_string()).collect())
+    );
+
+    for row in rows {
+        println!("{}", format_row(row));
+    }
+}
+
+fn block_on_backend<T, F, Fut>(func: F) -> anyhow::Result<T>
+where
+    F: FnOnce(BackendForCliApiProxy) -> Fut,
+    Fut: Future<Output = anyhow::Result<T>>,
+{
+    tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .expect("unable to start server tokio runtime")
+        .block_on(async {
+            let backend_api = connect_backend().await?;
+
+            func(BackendForCliApiProxy::new(backend_api)).await
+        })
+}
+
+async fn connect_backend() -> anyhow::Result<GrpcBackendApi> {
+    GrpcBackendApi::new()
+        .await
+        .map_err(|_| anyhow!("Unable to connect to server. Please check if you have Gauntlet running on your PC"))
+}
+
+fn server_event_to_json(event: &ServerEvent) -> Value {
+    match event {
+        ServerEvent::WindowShown => json!({ "event": "window_shown" }),
+        ServerEvent::WindowHidden => json!({ "event": "window_hidden" }),
+        ServerEvent::SearchPerformed { query } => json!({ "event": "search_performed", "query": query }),
+        ServerEvent::EntrypointRun {
+            plugin_id,
+            entrypoint_id,
+        } => {
+            json!({
+                "event": "entrypoint_run",
+                "plugin_id": plugin_id.to_string(),
+                "entrypoint_id": entrypoint_id.to_string(),
+            })
+        }
+        ServerEvent::PluginStarted { plugin_id } => {
+            json!({ "event": "plugin_started", "plugin_id": plugin_id.to_string() })
+        }
+        ServerEvent::PluginStopped { plugin_id, crashed } => {
+            json!({ "event": "plugin_stopped", "plugin_id": plugin_id.to_string(), "crashed": crashed })
+        }
+        ServerEvent::DownloadFinished { plugin_id, error } => {
+            json!({ "event": "download_finished", "plugin_id": plugin_id.to_string(), "error": error })
+        }
+        ServerEvent::ThemeChanged { theme } => json!({ "event": "theme_changed", "theme": theme.to_string() }),
+    }
+}
+
+fn server_event_to_text(event: &ServerEvent) -> String {
+    match event {
+        ServerEvent::WindowShown => "window_shown".to_string(),
+        ServerEvent::WindowHidden => "window_hidden".to_string(),
+        ServerEvent::SearchPerformed { query } => format!("search_performed\t{}", query),
+        ServerEvent::EntrypointRun {
+            plugin_id,
+            entrypoint_id,
+        } => format!("entrypoint_run\t{}\t{}", plugin_id, entrypoint_id),
+        ServerEvent::PluginStarted { plugin_id } => format!("plugin_started\t{}", plugin_id),
+        ServerEvent::PluginStopped { plugin_id, crashed } => {
+            let status = if *crashed { "crashed" } else { "stopped" };
+
+            format!("plugin_stopped\t{}\t{}", plugin_id, status)
+        }
+        ServerEvent::DownloadFinished { plugin_id, error } => {
+            match error {
+                None => format!("download_finished\t{}\tsuccess", plugin_id),
+                Some(error) => format!("download_finished\t{}\tfailed: {}", plugin_id, error),
+            }
+        }
+        ServerEvent::ThemeChanged { theme } => format!("theme_changed\t{}", theme),
+    }
+}
+
+fn search_result_to_json(item: &SearchResult) -> Value {
+    let actions: Vec<_> = item
+        .entrypoint_actions
+        .iter()
+        .map(|action| {
+            json!({
+                "id": action.id,
+                "label": action.label,
+                "type": action_type_label(&action.action_type),
+                "shortcut": action.shortcut.as_ref().map(PhysicalShortcut::label),
+            })
+        })
+        .collect();
+
+    let accessories: Vec<_> = item
+        .entrypoint_accessories
+        .iter()
+        .map(|accessory| {
+            match accessory {
+                SearchResultAccessory::TextAccessory { text, icon, tooltip } => {
+                    json!({
+                        "text": text,
+                        "icon": icon,
+                        "tooltip": tooltip,
+                    })
+                }
+                SearchResultAccessory::IconAccessory { icon, tooltip } => {
+                    json!({
+                        "icon": icon,
+                        "tooltip": tooltip,
+                    })
+                }
+            }
+        })
+        .collect();
+
+    json!({
+        "plugin_id": item.plugin_id.to_string(),
+        "plugin_name": item.plugin_name,
+        "entrypoint_id": item.entrypoint_id.to_string(),
+        "entrypoint_name": item.entrypoint_name,
+        "entrypoint_generator_name": item.entrypoint_generator_name,
+        "entrypoint_type": entrypoint_type_label(&item.entrypoint_type),
+        "entrypoint_aliases": item.entrypoint_aliases,
+        "actions": actions,
+        "accessories": accessories,
+    })
+}
+
+fn entrypoint_action_to_json(action: &CliEntrypointAction) -> Value {
+    json!({
+        "action_id": action.action_id,
+        "label": action.label,
+        "type": action_type_label(&action.action_type),
+    })
+}
+
+fn action_type_label(action_type: &SearchResultEntrypointActionType) -> &'static str {
+    match action_type {
+        SearchResultEntrypointActionType::Command => "command",
+        SearchResultEntrypointActionType::View => "view",
+    }
+}
+
+fn settings_entrypoint_type_label(entrypoint_type: &SettingsEntrypointType) -> &'static str {
+    match entrypoint_type {
+        SettingsEntrypointType::Command => "command",
+        SettingsEntrypointType::View => "view",
+        SettingsEntrypointType::InlineView => "inline-view",
+        SettingsEntrypointType::EntrypointGenerator => "entrypoint-generator",
+    }
+}
+
+fn entrypoint_type_label(entrypoint_type: &SearchResultEntrypointType) -> &'static str {
+    match entrypoint_type {
+        SearchResultEntrypointType::Command => "command",
+        SearchResultEntrypointType::View => "view",
+        SearchResultEntrypointType::Generated => "generated",
+    }
+}
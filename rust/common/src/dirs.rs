@@ -7,12 +7,24 @@ use directories::ProjectDirs;
 #[derive(Clone)]
 pub struct Dirs {
     inner: ProjectDirs,
+    // overrides data, config, cache and state directories
+    root: Option<PathBuf>,
 }
 
 impl Dirs {
     pub fn new() -> Self {
         Self {
             inner: ProjectDirs::from("dev", "project-gauntlet", "Gauntlet").unwrap(),
+            root: None,
+        }
+    }
+
+    /// Keeps all data, config, cache and state in subdirectories of `root`,
+    /// so that tests and benchmarks don't read or modify files of the user
+    pub fn with_root(root: impl Into<PathBuf>) -> Self {
+        Self {
+            root: Some(root.into()),
+            ..Self::new()
         }
     }
 
@@ -38,6 +50,10 @@ impl Dirs {
     }
 
     pub fn data_dir(&self) -> anyhow::Result<PathBuf> {
+        if let Some(root) = &self.root {
+            return Ok(root.join("data"));
+        }
+
         let data_dir = if cfg!(feature = "release") || cfg!(feature = "scenario_runner") {
             self.inner.data_dir().to_path_buf()
         } else {
@@ -56,6 +72,10 @@ impl Dirs {
     }
 
     pub fn config_dir(&self) -> PathBuf {
+        if let Some(root) = &self.root {
+            return root.join("config");
+        }
+
         let config_dir = if cfg!(feature = "release") || cfg!(feature = "scenario_runner") {
             self.inner.config_dir().to_path_buf()
         } else {
@@ -69,6 +89,10 @@ impl Dirs {
         self.cache_dir().join("icons")
     }
 
+    pub fn search_index_dir(&self) -> PathBuf {
+        self.cache_dir().join("search_index")
+    }
+
     pub fn plugin_cache(&self, plugin_uuid: &str) -> anyhow::Result<PathBuf> {
         let plugin_cache_dir = self.cache_dir().join("plugins").join(&plugin_uuid);
 
@@ -76,6 +100,10 @@ impl Dirs {
     }
 
     pub fn cache_dir(&self) -> PathBuf {
+        if let Some(root) = &self.root {
+            return root.join("cache");
+        }
+
         let cache_dir = if cfg!(feature = "release") || cfg!(feature = "scenario_runner") {
             self.inner.cache_dir().to_path_buf()
         } else {
@@ -111,6 +139,10 @@ impl Dirs {
     }
 
     pub fn state_dir(&self) -> PathBuf {
+        if let Some(root) = &self.root {
+            return root.join("state");
+        }
+
         let state_dir = if cfg!(feature = "release") || cfg!(feature = "scenario_runner") {
             let dir = match self.inner.state_dir() {
                 Some(dir) => dir,
@@ -138,6 +170,14 @@ impl Dirs {
         state_dir.join(format!("project-gauntlet-{}.sock", plugin_uuid))
     }
 
+    pub fn control_socket(&self) -> PathBuf {
+        self.state_dir().join("control.sock")
+    }
+
+    pub fn control_token_file(&self) -> PathBuf {
+        self.state_dir().join("control_token")
+    }
+
     pub fn window_position(&self) -> PathBuf {
         self.state_dir().join("window_position")
     }
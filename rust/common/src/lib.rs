@@ -3,3 +3,4 @@ pub mod detached_process;
 pub mod dirs;
 pub mod model;
 pub mod rpc;
+pub mod time;
@@ -3,6 +3,7 @@ use std::fmt::Display;
 use std::fmt::Formatter;
 use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::SystemTime;
 
 use anyhow::anyhow;
 use bincode::Decode;
@@ -21,13 +22,15 @@ impl PluginId {
     }
 
     fn try_to_url(&self) -> anyhow::Result<Url> {
-        let url = self.to_string();
-        let url: &str = url.as_ref();
-        let url = gix_url::parse(url.try_into()?)?;
+        let url = gix_url::parse(self.0.as_ref().into())?;
         Ok(url)
     }
 
     pub fn try_to_git_url(&self) -> anyhow::Result<String> {
+        if self.is_archive() {
+            return Err(anyhow!("plugin id points to an archive, not to a git repository"));
+        }
+
         let url = self.try_to_url()?;
 
         match url.scheme {
@@ -48,6 +51,33 @@ impl PluginId {
         let plugin_dir = PathBuf::from(plugin_dir);
         Ok(plugin_dir)
     }
+
+    /// Url of `.tar.gz` or `.zip` archive of built plugin, local or remote
+    pub fn try_to_archive_url(&self) -> anyhow::Result<String> {
+        if !self.is_archive() {
+            return Err(anyhow!("plugin id is expected to point to .tar.gz or .zip archive"));
+        }
+
+        let url = self.try_to_url()?;
+
+        match url.scheme {
+            Scheme::File | Scheme::Http | Scheme::Https => Ok(url.to_bstring().to_string()),
+            scheme => Err(anyhow!("'{}' schema is not supported for archives", scheme.as_str())),
+        }
+    }
+
+    pub fn is_archive(&self) -> bool {
+        let url = self.0.to_lowercase();
+
+        [".tar.gz", ".tgz", ".zip"]
+            .iter()
+            .any(|extension| url.ends_with(extension))
+    }
+
+    /// Plugin loaded from local directory during plugin development
+    pub fn is_development(&self) -> bool {
+        self.0.starts_with("file://") && !self.is_archive()
+    }
 }
 
 impl Display for PluginId {
@@ -56,6 +86,56 @@ impl Display for PluginId {
     }
 }
 
+/// Plugin id and what exactly should be downloaded, entered as url with optional fragment,
+/// e.g. `https://github.com/user/plugin.git#gauntlet/beta` or `https://example.com/plugin.tar.gz#sha256=<hash>`.
+/// Fragment is not a part of plugin id, so plugin keeps its settings and usage history when it changes
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub struct PluginSource {
+    pub plugin_id: PluginId,
+    // branch, tag or commit of git repository
+    pub git_ref: Option<String>,
+    // expected hash of archive
+    pub archive_sha256: Option<String>,
+}
+
+impl PluginSource {
+    pub fn new(plugin_id: PluginId) -> Self {
+        Self {
+            plugin_id,
+            git_ref: None,
+            archive_sha256: None,
+        }
+    }
+
+    pub fn parse(value: &str) -> Self {
+        let value = value.trim();
+
+        let (url, fragment) = match value.rsplit_once('#') {
+            Some((url, "")) => (url, None),
+            Some((url, fragment)) => (url, Some(fragment)),
+            None => (value, None),
+        };
+
+        let plugin_id = PluginId::from_string(url);
+
+        match fragment {
+            None => Self::new(plugin_id),
+            Some(fragment) if plugin_id.is_archive() => {
+                Self {
+                    archive_sha256: fragment.strip_prefix("sha256=").map(|hash| hash.to_owned()),
+                    ..Self::new(plugin_id)
+                }
+            }
+            Some(fragment) => {
+                Self {
+                    git_ref: Some(fragment.to_owned()),
+                    ..Self::new(plugin_id)
+                }
+            }
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Encode, Decode)]
 pub struct EntrypointId(Arc<str>);
 
@@ -84,7 +164,7 @@ pub enum UiRenderLocation {
     View,
 }
 
-#[derive(Debug, Clone, Encode, Decode)]
+#[derive(Debug, Clone, PartialEq, Encode, Decode)]
 pub struct PhysicalShortcut {
     pub physical_key: PhysicalKey,
     pub modifier_shift: bool,
@@ -93,27 +173,140 @@ pub struct PhysicalShortcut {
     pub modifier_meta: bool,
 }
 
+impl PhysicalShortcut {
+    /// e.g. "Control+Shift+KeyK", the same format is used for shortcuts in config
+    pub fn label(&self) -> String {
+        let mut parts = vec![];
+
+        if self.modifier_meta {
+            parts.push("Meta".to_string());
+        }
+        if self.modifier_control {
+            parts.push("Control".to_string());
+        }
+        if self.modifier_alt {
+            parts.push("Alt".to_string());
+        }
+        if self.modifier_shift {
+            parts.push("Shift".to_string());
+        }
+
+        parts.push(self.physical_key.to_value());
+
+        parts.join("+")
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct LocalSaveData {
     pub stdout_file_path: String,
     pub stderr_file_path: String,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Encode, Decode)]
 pub struct SearchResult {
     pub plugin_id: PluginId,
     pub plugin_name: String,
     pub entrypoint_id: EntrypointId,
     pub entrypoint_name: String,
     pub entrypoint_generator_name: Option<String>,
+    // bincode doesn't implement encoding for `Bytes`
+    #[bincode(with_serde)]
     pub entrypoint_icon: Option<bytes::Bytes>,
     pub entrypoint_type: SearchResultEntrypointType,
     pub entrypoint_actions: Vec<SearchResultEntrypointAction>,
     pub entrypoint_accessories: Vec<SearchResultAccessory>,
-    pub entrypoint_alias: Option<String>,
+    pub entrypoint_aliases: Vec<String>,
+    pub entrypoint_pinned: bool,
+    // entrypoint was found using its keyword alias
+    pub keyword_alias_match: bool,
+    // rest of the query if entrypoint was found using its keyword alias
+    pub keyword_alias_query: Option<String>,
+    // set only for entrypoints shown in "Recent" section when search query is empty
+    pub entrypoint_last_run: Option<SystemTime>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EntrypointSearchAlias {
+    pub alias: String,
+    // keyword alias takes rest of the query as an argument, e.g. "gh <text>"
+    pub keyword: bool,
 }
 
 #[derive(Debug, Clone)]
+pub struct SearchScope {
+    pub plugin_id: PluginId,
+    pub plugin_name: String,
+    pub keyword: String,
+}
+
+#[derive(Debug, Clone, Encode, Decode)]
+pub struct CliEntrypointAction {
+    pub action_id: Option<String>,
+    pub label: String,
+    pub action_type: SearchResultEntrypointActionType,
+}
+
+#[derive(Debug, Clone, Encode, Decode)]
+pub struct CliRunHistoryItem {
+    pub plugin_id: PluginId,
+    pub plugin_name: String,
+    pub entrypoint_id: EntrypointId,
+    pub entrypoint_name: String,
+    pub last_run: SystemTime,
+}
+
+#[derive(Debug, Clone, Encode, Decode)]
+pub struct CliPluginSyncReport {
+    // human-readable description of every change, in order of application
+    pub changes: Vec<String>,
+    pub warnings: Vec<String>,
+    // plugins which are being downloaded in the background, their settings are applied after download
+    pub installing: Vec<PluginId>,
+}
+
+#[derive(Debug, Clone, Encode, Decode)]
+pub struct CliDiagnostics {
+    pub close_on_unfocus: bool,
+    pub layer_shell: bool,
+    pub wayland_use_legacy_x11_api: bool,
+    pub linux_native_hud: bool,
+    pub global_shortcut_error: Option<String>,
+    pub global_entrypoint_shortcut_errors: Vec<(PluginId, EntrypointId, String)>,
+    // enabled plugins, plugin id and plugin name
+    pub not_running_plugins: Vec<(PluginId, String)>,
+}
+
+#[derive(Debug, Clone, Encode, Decode)]
+pub enum ServerEvent {
+    WindowShown,
+    WindowHidden,
+    /// Sent for queries typed in main window once user stops typing or runs an entrypoint,
+    /// not for every keystroke. Searches performed using CLI are not reported
+    SearchPerformed {
+        query: String,
+    },
+    EntrypointRun {
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+    },
+    PluginStarted {
+        plugin_id: PluginId,
+    },
+    PluginStopped {
+        plugin_id: PluginId,
+        crashed: bool,
+    },
+    DownloadFinished {
+        plugin_id: PluginId,
+        error: Option<String>,
+    },
+    ThemeChanged {
+        theme: SettingsTheme,
+    },
+}
+
+#[derive(Debug, Clone, Encode, Decode)]
 pub enum SearchResultAccessory {
     TextAccessory {
         text: String,
@@ -126,20 +319,21 @@ pub enum SearchResultAccessory {
     },
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Encode, Decode)]
 pub struct SearchResultEntrypointAction {
+    pub id: Option<String>,
     pub action_type: SearchResultEntrypointActionType,
     pub label: String,
     pub shortcut: Option<PhysicalShortcut>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Encode, Decode)]
 pub enum SearchResultEntrypointActionType {
     Command,
     View,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Encode, Decode)]
 pub enum SearchResultEntrypointType {
     Command,
     View,
@@ -513,11 +707,40 @@ pub struct SettingsPlugin {
     pub plugin_name: String,
     pub plugin_description: String,
     pub enabled: bool,
+    pub search_scope: Option<String>,
+    // branch or tag plugin was installed from has commits newer than the installed one
+    pub update_available: bool,
+    // commit plugin was downloaded from, none for local and bundled plugins
+    pub installed_commit: Option<String>,
+    // branch, tag or commit plugin was installed from, none if default release branch is used
+    pub git_ref: Option<String>,
+    pub signature: SettingsPluginSignature,
     pub entrypoints: HashMap<EntrypointId, SettingsEntrypoint>,
     pub preferences: HashMap<String, PluginPreference>,
     pub preferences_user_data: HashMap<String, PluginPreferenceUserData>,
 }
 
+#[derive(Debug, Clone, PartialEq, Decode, Encode)]
+pub enum SettingsPluginSignature {
+    // bundled plugins and plugins loaded from local directory during development are not checked
+    NotChecked,
+    // signed by one of trusted keys in config
+    Trusted,
+    // signed, but not by any of trusted keys in config
+    Untrusted,
+    Unsigned,
+}
+
+impl SettingsPluginSignature {
+    /// Plugin has to be explicitly confirmed by user before it is enabled
+    pub fn requires_confirmation(&self) -> bool {
+        matches!(
+            self,
+            SettingsPluginSignature::Untrusted | SettingsPluginSignature::Unsigned
+        )
+    }
+}
+
 #[derive(Debug, Clone, Encode, Decode)]
 pub enum SettingsEntrypointType {
     Command,
@@ -552,7 +775,7 @@ impl Display for SettingsTheme {
     }
 }
 
-#[derive(Debug, Clone, Decode, Encode)]
+#[derive(Debug, Clone, PartialEq, Decode, Encode)]
 pub enum PluginPreferenceUserData {
     Number { value: Option<f64> },
     String { value: Option<String> },
@@ -612,7 +835,7 @@ pub struct PreferenceEnumValue {
 }
 
 // copy of iced (currently fork) PhysicalKey but without modifiers
-#[derive(Debug, Clone, Decode, Encode)]
+#[derive(Debug, Clone, PartialEq, Decode, Encode)]
 pub enum PhysicalKey {
     Backquote,
     Backslash,
@@ -801,193 +1024,195 @@ pub enum PhysicalKey {
 
 impl PhysicalKey {
     pub fn from_value(key: String) -> PhysicalKey {
-        match key.as_str() {
-            "Backquote" => PhysicalKey::Backquote,
-            "Backslash" => PhysicalKey::Backslash,
-            "BracketLeft" => PhysicalKey::BracketLeft,
-            "BracketRight" => PhysicalKey::BracketRight,
-            "Comma" => PhysicalKey::Comma,
-            "Digit0" => PhysicalKey::Digit0,
-            "Digit1" => PhysicalKey::Digit1,
-            "Digit2" => PhysicalKey::Digit2,
-            "Digit3" => PhysicalKey::Digit3,
-            "Digit4" => PhysicalKey::Digit4,
-            "Digit5" => PhysicalKey::Digit5,
-            "Digit6" => PhysicalKey::Digit6,
-            "Digit7" => PhysicalKey::Digit7,
-            "Digit8" => PhysicalKey::Digit8,
-            "Digit9" => PhysicalKey::Digit9,
-            "Equal" => PhysicalKey::Equal,
-            "IntlBackslash" => PhysicalKey::IntlBackslash,
-            "IntlRo" => PhysicalKey::IntlRo,
-            "IntlYen" => PhysicalKey::IntlYen,
-            "KeyA" => PhysicalKey::KeyA,
-            "KeyB" => PhysicalKey::KeyB,
-            "KeyC" => PhysicalKey::KeyC,
-            "KeyD" => PhysicalKey::KeyD,
-            "KeyE" => PhysicalKey::KeyE,
-            "KeyF" => PhysicalKey::KeyF,
-            "KeyG" => PhysicalKey::KeyG,
-            "KeyH" => PhysicalKey::KeyH,
-            "KeyI" => PhysicalKey::KeyI,
-            "KeyJ" => PhysicalKey::KeyJ,
-            "KeyK" => PhysicalKey::KeyK,
-            "KeyL" => PhysicalKey::KeyL,
-            "KeyM" => PhysicalKey::KeyM,
-            "KeyN" => PhysicalKey::KeyN,
-            "KeyO" => PhysicalKey::KeyO,
-            "KeyP" => PhysicalKey::KeyP,
-            "KeyQ" => PhysicalKey::KeyQ,
-            "KeyR" => PhysicalKey::KeyR,
-            "KeyS" => PhysicalKey::KeyS,
-            "KeyT" => PhysicalKey::KeyT,
-            "KeyU" => PhysicalKey::KeyU,
-            "KeyV" => PhysicalKey::KeyV,
-            "KeyW" => PhysicalKey::KeyW,
-            "KeyX" => PhysicalKey::KeyX,
-            "KeyY" => PhysicalKey::KeyY,
-            "KeyZ" => PhysicalKey::KeyZ,
-            "Minus" => PhysicalKey::Minus,
-            "Period" => PhysicalKey::Period,
-            "Quote" => PhysicalKey::Quote,
-            "Semicolon" => PhysicalKey::Semicolon,
-            "Slash" => PhysicalKey::Slash,
-            "Backspace" => PhysicalKey::Backspace,
-            "CapsLock" => PhysicalKey::CapsLock,
-            "ContextMenu" => PhysicalKey::ContextMenu,
-            "Enter" => PhysicalKey::Enter,
-            "Space" => PhysicalKey::Space,
-            "Tab" => PhysicalKey::Tab,
-            "Convert" => PhysicalKey::Convert,
-            "KanaMode" => PhysicalKey::KanaMode,
-            "Lang1" => PhysicalKey::Lang1,
-            "Lang2" => PhysicalKey::Lang2,
-            "Lang3" => PhysicalKey::Lang3,
-            "Lang4" => PhysicalKey::Lang4,
-            "Lang5" => PhysicalKey::Lang5,
-            "NonConvert" => PhysicalKey::NonConvert,
-            "Delete" => PhysicalKey::Delete,
-            "End" => PhysicalKey::End,
-            "Help" => PhysicalKey::Help,
-            "Home" => PhysicalKey::Home,
-            "Insert" => PhysicalKey::Insert,
-            "PageDown" => PhysicalKey::PageDown,
-            "PageUp" => PhysicalKey::PageUp,
-            "ArrowDown" => PhysicalKey::ArrowDown,
-            "ArrowLeft" => PhysicalKey::ArrowLeft,
-            "ArrowRight" => PhysicalKey::ArrowRight,
-            "ArrowUp" => PhysicalKey::ArrowUp,
-            "NumLock" => PhysicalKey::NumLock,
-            "Numpad0" => PhysicalKey::Numpad0,
-            "Numpad1" => PhysicalKey::Numpad1,
-            "Numpad2" => PhysicalKey::Numpad2,
-            "Numpad3" => PhysicalKey::Numpad3,
-            "Numpad4" => PhysicalKey::Numpad4,
-            "Numpad5" => PhysicalKey::Numpad5,
-            "Numpad6" => PhysicalKey::Numpad6,
-            "Numpad7" => PhysicalKey::Numpad7,
-            "Numpad8" => PhysicalKey::Numpad8,
-            "Numpad9" => PhysicalKey::Numpad9,
-            "NumpadAdd" => PhysicalKey::NumpadAdd,
-            "NumpadBackspace" => PhysicalKey::NumpadBackspace,
-            "NumpadClear" => PhysicalKey::NumpadClear,
-            "NumpadClearEntry" => PhysicalKey::NumpadClearEntry,
-            "NumpadComma" => PhysicalKey::NumpadComma,
-            "NumpadDecimal" => PhysicalKey::NumpadDecimal,
-            "NumpadDivide" => PhysicalKey::NumpadDivide,
-            "NumpadEnter" => PhysicalKey::NumpadEnter,
-            "NumpadEqual" => PhysicalKey::NumpadEqual,
-            "NumpadHash" => PhysicalKey::NumpadHash,
-            "NumpadMemoryAdd" => PhysicalKey::NumpadMemoryAdd,
-            "NumpadMemoryClear" => PhysicalKey::NumpadMemoryClear,
-            "NumpadMemoryRecall" => PhysicalKey::NumpadMemoryRecall,
-            "NumpadMemoryStore" => PhysicalKey::NumpadMemoryStore,
-            "NumpadMemorySubtract" => PhysicalKey::NumpadMemorySubtract,
-            "NumpadMultiply" => PhysicalKey::NumpadMultiply,
-            "NumpadParenLeft" => PhysicalKey::NumpadParenLeft,
-            "NumpadParenRight" => PhysicalKey::NumpadParenRight,
-            "NumpadStar" => PhysicalKey::NumpadStar,
-            "NumpadSubtract" => PhysicalKey::NumpadSubtract,
-            "Escape" => PhysicalKey::Escape,
-            "Fn" => PhysicalKey::Fn,
-            "FnLock" => PhysicalKey::FnLock,
-            "PrintScreen" => PhysicalKey::PrintScreen,
-            "ScrollLock" => PhysicalKey::ScrollLock,
-            "Pause" => PhysicalKey::Pause,
-            "BrowserBack" => PhysicalKey::BrowserBack,
-            "BrowserFavorites" => PhysicalKey::BrowserFavorites,
-            "BrowserForward" => PhysicalKey::BrowserForward,
-            "BrowserHome" => PhysicalKey::BrowserHome,
-            "BrowserRefresh" => PhysicalKey::BrowserRefresh,
-            "BrowserSearch" => PhysicalKey::BrowserSearch,
-            "BrowserStop" => PhysicalKey::BrowserStop,
-            "Eject" => PhysicalKey::Eject,
-            "LaunchApp1" => PhysicalKey::LaunchApp1,
-            "LaunchApp2" => PhysicalKey::LaunchApp2,
-            "LaunchMail" => PhysicalKey::LaunchMail,
-            "MediaPlayPause" => PhysicalKey::MediaPlayPause,
-            "MediaSelect" => PhysicalKey::MediaSelect,
-            "MediaStop" => PhysicalKey::MediaStop,
-            "MediaTrackNext" => PhysicalKey::MediaTrackNext,
-            "MediaTrackPrevious" => PhysicalKey::MediaTrackPrevious,
-            "Power" => PhysicalKey::Power,
-            "Sleep" => PhysicalKey::Sleep,
-            "AudioVolumeDown" => PhysicalKey::AudioVolumeDown,
-            "AudioVolumeMute" => PhysicalKey::AudioVolumeMute,
-            "AudioVolumeUp" => PhysicalKey::AudioVolumeUp,
-            "WakeUp" => PhysicalKey::WakeUp,
-            "Abort" => PhysicalKey::Abort,
-            "Resume" => PhysicalKey::Resume,
-            "Suspend" => PhysicalKey::Suspend,
-            "Again" => PhysicalKey::Again,
-            "Copy" => PhysicalKey::Copy,
-            "Cut" => PhysicalKey::Cut,
-            "Find" => PhysicalKey::Find,
-            "Open" => PhysicalKey::Open,
-            "Paste" => PhysicalKey::Paste,
-            "Props" => PhysicalKey::Props,
-            "Select" => PhysicalKey::Select,
-            "Undo" => PhysicalKey::Undo,
-            "Hiragana" => PhysicalKey::Hiragana,
-            "Katakana" => PhysicalKey::Katakana,
-            "F1" => PhysicalKey::F1,
-            "F2" => PhysicalKey::F2,
-            "F3" => PhysicalKey::F3,
-            "F4" => PhysicalKey::F4,
-            "F5" => PhysicalKey::F5,
-            "F6" => PhysicalKey::F6,
-            "F7" => PhysicalKey::F7,
-            "F8" => PhysicalKey::F8,
-            "F9" => PhysicalKey::F9,
-            "F10" => PhysicalKey::F10,
-            "F11" => PhysicalKey::F11,
-            "F12" => PhysicalKey::F12,
-            "F13" => PhysicalKey::F13,
-            "F14" => PhysicalKey::F14,
-            "F15" => PhysicalKey::F15,
-            "F16" => PhysicalKey::F16,
-            "F17" => PhysicalKey::F17,
-            "F18" => PhysicalKey::F18,
-            "F19" => PhysicalKey::F19,
-            "F20" => PhysicalKey::F20,
-            "F21" => PhysicalKey::F21,
-            "F22" => PhysicalKey::F22,
-            "F23" => PhysicalKey::F23,
-            "F24" => PhysicalKey::F24,
-            "F25" => PhysicalKey::F25,
-            "F26" => PhysicalKey::F26,
-            "F27" => PhysicalKey::F27,
-            "F28" => PhysicalKey::F28,
-            "F29" => PhysicalKey::F29,
-            "F30" => PhysicalKey::F30,
-            "F31" => PhysicalKey::F31,
-            "F32" => PhysicalKey::F32,
-            "F33" => PhysicalKey::F33,
-            "F34" => PhysicalKey::F34,
-            "F35" => PhysicalKey::F35,
-            _ => {
-                panic!("unknown key: {}", key)
-            }
+        PhysicalKey::try_from_value(&key).unwrap_or_else(|| panic!("unknown key: {}", key))
+    }
+
+    pub fn try_from_value(key: &str) -> Option<PhysicalKey> {
+        match key {
+            "Backquote" => Some(PhysicalKey::Backquote),
+            "Backslash" => Some(PhysicalKey::Backslash),
+            "BracketLeft" => Some(PhysicalKey::BracketLeft),
+            "BracketRight" => Some(PhysicalKey::BracketRight),
+            "Comma" => Some(PhysicalKey::Comma),
+            "Digit0" => Some(PhysicalKey::Digit0),
+            "Digit1" => Some(PhysicalKey::Digit1),
+            "Digit2" => Some(PhysicalKey::Digit2),
+            "Digit3" => Some(PhysicalKey::Digit3),
+            "Digit4" => Some(PhysicalKey::Digit4),
+            "Digit5" => Some(PhysicalKey::Digit5),
+            "Digit6" => Some(PhysicalKey::Digit6),
+            "Digit7" => Some(PhysicalKey::Digit7),
+            "Digit8" => Some(PhysicalKey::Digit8),
+            "Digit9" => Some(PhysicalKey::Digit9),
+            "Equal" => Some(PhysicalKey::Equal),
+            "IntlBackslash" => Some(PhysicalKey::IntlBackslash),
+            "IntlRo" => Some(PhysicalKey::IntlRo),
+            "IntlYen" => Some(PhysicalKey::IntlYen),
+            "KeyA" => Some(PhysicalKey::KeyA),
+            "KeyB" => Some(PhysicalKey::KeyB),
+            "KeyC" => Some(PhysicalKey::KeyC),
+            "KeyD" => Some(PhysicalKey::KeyD),
+            "KeyE" => Some(PhysicalKey::KeyE),
+            "KeyF" => Some(PhysicalKey::KeyF),
+            "KeyG" => Some(PhysicalKey::KeyG),
+            "KeyH" => Some(PhysicalKey::KeyH),
+            "KeyI" => Some(PhysicalKey::KeyI),
+            "KeyJ" => Some(PhysicalKey::KeyJ),
+            "KeyK" => Some(PhysicalKey::KeyK),
+            "KeyL" => Some(PhysicalKey::KeyL),
+            "KeyM" => Some(PhysicalKey::KeyM),
+            "KeyN" => Some(PhysicalKey::KeyN),
+            "KeyO" => Some(PhysicalKey::KeyO),
+            "KeyP" => Some(PhysicalKey::KeyP),
+            "KeyQ" => Some(PhysicalKey::KeyQ),
+            "KeyR" => Some(PhysicalKey::KeyR),
+            "KeyS" => Some(PhysicalKey::KeyS),
+            "KeyT" => Some(PhysicalKey::KeyT),
+            "KeyU" => Some(PhysicalKey::KeyU),
+            "KeyV" => Some(PhysicalKey::KeyV),
+            "KeyW" => Some(PhysicalKey::KeyW),
+            "KeyX" => Some(PhysicalKey::KeyX),
+            "KeyY" => Some(PhysicalKey::KeyY),
+            "KeyZ" => Some(PhysicalKey::KeyZ),
+            "Minus" => Some(PhysicalKey::Minus),
+            "Period" => Some(PhysicalKey::Period),
+            "Quote" => Some(PhysicalKey::Quote),
+            "Semicolon" => Some(PhysicalKey::Semicolon),
+            "Slash" => Some(PhysicalKey::Slash),
+            "Backspace" => Some(PhysicalKey::Backspace),
+            "CapsLock" => Some(PhysicalKey::CapsLock),
+            "ContextMenu" => Some(PhysicalKey::ContextMenu),
+            "Enter" => Some(PhysicalKey::Enter),
+            "Space" => Some(PhysicalKey::Space),
+            "Tab" => Some(PhysicalKey::Tab),
+            "Convert" => Some(PhysicalKey::Convert),
+            "KanaMode" => Some(PhysicalKey::KanaMode),
+            "Lang1" => Some(PhysicalKey::Lang1),
+            "Lang2" => Some(PhysicalKey::Lang2),
+            "Lang3" => Some(PhysicalKey::Lang3),
+            "Lang4" => Some(PhysicalKey::Lang4),
+            "Lang5" => Some(PhysicalKey::Lang5),
+            "NonConvert" => Some(PhysicalKey::NonConvert),
+            "Delete" => Some(PhysicalKey::Delete),
+            "End" => Some(PhysicalKey::End),
+            "Help" => Some(PhysicalKey::Help),
+            "Home" => Some(PhysicalKey::Home),
+            "Insert" => Some(PhysicalKey::Insert),
+            "PageDown" => Some(PhysicalKey::PageDown),
+            "PageUp" => Some(PhysicalKey::PageUp),
+            "ArrowDown" => Some(PhysicalKey::ArrowDown),
+            "ArrowLeft" => Some(PhysicalKey::ArrowLeft),
+            "ArrowRight" => Some(PhysicalKey::ArrowRight),
+            "ArrowUp" => Some(PhysicalKey::ArrowUp),
+            "NumLock" => Some(PhysicalKey::NumLock),
+            "Numpad0" => Some(PhysicalKey::Numpad0),
+            "Numpad1" => Some(PhysicalKey::Numpad1),
+            "Numpad2" => Some(PhysicalKey::Numpad2),
+            "Numpad3" => Some(PhysicalKey::Numpad3),
+            "Numpad4" => Some(PhysicalKey::Numpad4),
+            "Numpad5" => Some(PhysicalKey::Numpad5),
+            "Numpad6" => Some(PhysicalKey::Numpad6),
+            "Numpad7" => Some(PhysicalKey::Numpad7),
+            "Numpad8" => Some(PhysicalKey::Numpad8),
+            "Numpad9" => Some(PhysicalKey::Numpad9),
+            "NumpadAdd" => Some(PhysicalKey::NumpadAdd),
+            "NumpadBackspace" => Some(PhysicalKey::NumpadBackspace),
+            "NumpadClear" => Some(PhysicalKey::NumpadClear),
+            "NumpadClearEntry" => Some(PhysicalKey::NumpadClearEntry),
+            "NumpadComma" => Some(PhysicalKey::NumpadComma),
+            "NumpadDecimal" => Some(PhysicalKey::NumpadDecimal),
+            "NumpadDivide" => Some(PhysicalKey::NumpadDivide),
+            "NumpadEnter" => Some(PhysicalKey::NumpadEnter),
+            "NumpadEqual" => Some(PhysicalKey::NumpadEqual),
+            "NumpadHash" => Some(PhysicalKey::NumpadHash),
+            "NumpadMemoryAdd" => Some(PhysicalKey::NumpadMemoryAdd),
+            "NumpadMemoryClear" => Some(PhysicalKey::NumpadMemoryClear),
+            "NumpadMemoryRecall" => Some(PhysicalKey::NumpadMemoryRecall),
+            "NumpadMemoryStore" => Some(PhysicalKey::NumpadMemoryStore),
+            "NumpadMemorySubtract" => Some(PhysicalKey::NumpadMemorySubtract),
+            "NumpadMultiply" => Some(PhysicalKey::NumpadMultiply),
+            "NumpadParenLeft" => Some(PhysicalKey::NumpadParenLeft),
+            "NumpadParenRight" => Some(PhysicalKey::NumpadParenRight),
+            "NumpadStar" => Some(PhysicalKey::NumpadStar),
+            "NumpadSubtract" => Some(PhysicalKey::NumpadSubtract),
+            "Escape" => Some(PhysicalKey::Escape),
+            "Fn" => Some(PhysicalKey::Fn),
+            "FnLock" => Some(PhysicalKey::FnLock),
+            "PrintScreen" => Some(PhysicalKey::PrintScreen),
+            "ScrollLock" => Some(PhysicalKey::ScrollLock),
+            "Pause" => Some(PhysicalKey::Pause),
+            "BrowserBack" => Some(PhysicalKey::BrowserBack),
+            "BrowserFavorites" => Some(PhysicalKey::BrowserFavorites),
+            "BrowserForward" => Some(PhysicalKey::BrowserForward),
+            "BrowserHome" => Some(PhysicalKey::BrowserHome),
+            "BrowserRefresh" => Some(PhysicalKey::BrowserRefresh),
+            "BrowserSearch" => Some(PhysicalKey::BrowserSearch),
+            "BrowserStop" => Some(PhysicalKey::BrowserStop),
+            "Eject" => Some(PhysicalKey::Eject),
+            "LaunchApp1" => Some(PhysicalKey::LaunchApp1),
+            "LaunchApp2" => Some(PhysicalKey::LaunchApp2),
+            "LaunchMail" => Some(PhysicalKey::LaunchMail),
+            "MediaPlayPause" => Some(PhysicalKey::MediaPlayPause),
+            "MediaSelect" => Some(PhysicalKey::MediaSelect),
+            "MediaStop" => Some(PhysicalKey::MediaStop),
+            "MediaTrackNext" => Some(PhysicalKey::MediaTrackNext),
+            "MediaTrackPrevious" => Some(PhysicalKey::MediaTrackPrevious),
+            "Power" => Some(PhysicalKey::Power),
+            "Sleep" => Some(PhysicalKey::Sleep),
+            "AudioVolumeDown" => Some(PhysicalKey::AudioVolumeDown),
+            "AudioVolumeMute" => Some(PhysicalKey::AudioVolumeMute),
+            "AudioVolumeUp" => Some(PhysicalKey::AudioVolumeUp),
+            "WakeUp" => Some(PhysicalKey::WakeUp),
+            "Abort" => Some(PhysicalKey::Abort),
+            "Resume" => Some(PhysicalKey::Resume),
+            "Suspend" => Some(PhysicalKey::Suspend),
+            "Again" => Some(PhysicalKey::Again),
+            "Copy" => Some(PhysicalKey::Copy),
+            "Cut" => Some(PhysicalKey::Cut),
+            "Find" => Some(PhysicalKey::Find),
+            "Open" => Some(PhysicalKey::Open),
+            "Paste" => Some(PhysicalKey::Paste),
+            "Props" => Some(PhysicalKey::Props),
+            "Select" => Some(PhysicalKey::Select),
+            "Undo" => Some(PhysicalKey::Undo),
+            "Hiragana" => Some(PhysicalKey::Hiragana),
+            "Katakana" => Some(PhysicalKey::Katakana),
+            "F1" => Some(PhysicalKey::F1),
+            "F2" => Some(PhysicalKey::F2),
+            "F3" => Some(PhysicalKey::F3),
+            "F4" => Some(PhysicalKey::F4),
+            "F5" => Some(PhysicalKey::F5),
+            "F6" => Some(PhysicalKey::F6),
+            "F7" => Some(PhysicalKey::F7),
+            "F8" => Some(PhysicalKey::F8),
+            "F9" => Some(PhysicalKey::F9),
+            "F10" => Some(PhysicalKey::F10),
+            "F11" => Some(PhysicalKey::F11),
+            "F12" => Some(PhysicalKey::F12),
+            "F13" => Some(PhysicalKey::F13),
+            "F14" => Some(PhysicalKey::F14),
+            "F15" => Some(PhysicalKey::F15),
+            "F16" => Some(PhysicalKey::F16),
+            "F17" => Some(PhysicalKey::F17),
+            "F18" => Some(PhysicalKey::F18),
+            "F19" => Some(PhysicalKey::F19),
+            "F20" => Some(PhysicalKey::F20),
+            "F21" => Some(PhysicalKey::F21),
+            "F22" => Some(PhysicalKey::F22),
+            "F23" => Some(PhysicalKey::F23),
+            "F24" => Some(PhysicalKey::F24),
+            "F25" => Some(PhysicalKey::F25),
+            "F26" => Some(PhysicalKey::F26),
+            "F27" => Some(PhysicalKey::F27),
+            "F28" => Some(PhysicalKey::F28),
+            "F29" => Some(PhysicalKey::F29),
+            "F30" => Some(PhysicalKey::F30),
+            "F31" => Some(PhysicalKey::F31),
+            "F32" => Some(PhysicalKey::F32),
+            "F33" => Some(PhysicalKey::F33),
+            "F34" => Some(PhysicalKey::F34),
+            "F35" => Some(PhysicalKey::F35),
+            _ => None,
         }
     }
 
@@ -1,17 +1,35 @@
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use gauntlet_utils::channel::RequestResult;
 use gauntlet_utils_macros::boundary_gen;
 use tokio::sync::Mutex;
+use tokio_stream::Stream;
+use tokio_stream::StreamExt;
 use tonic::Request;
+use tonic::service::interceptor::InterceptedService;
 use tonic::transport::Channel;
-
+use tonic::transport::Endpoint;
+
+use crate::dirs::Dirs;
+use crate::model::CliDiagnostics;
+use crate::model::CliEntrypointAction;
+use crate::model::CliPluginSyncReport;
+use crate::model::CliRunHistoryItem;
+use crate::model::DownloadStatus;
 use crate::model::EntrypointId;
 use crate::model::LocalSaveData;
 use crate::model::PluginId;
+use crate::model::PluginSource;
+use crate::model::SearchResult;
+use crate::model::ServerEvent;
+use crate::model::SettingsPlugin;
 use crate::rpc::grpc::RpcBincode;
 use crate::rpc::grpc::RpcSaveLocalPluginRequest;
+use crate::rpc::grpc::RpcSubscribeRequest;
 use crate::rpc::grpc::rpc_backend_client::RpcBackendClient;
+use crate::rpc::transport::BackendTransport;
+use crate::rpc::transport::ClientTokenInterceptor;
 
 #[boundary_gen(bincode, grpc)]
 #[tonic::async_trait]
@@ -27,7 +45,53 @@ pub trait BackendForCliApi {
         plugin_id: PluginId,
         entrypoint_id: EntrypointId,
         action_id: String,
+        arguments: HashMap<String, String>,
+        query: Option<String>,
+    ) -> RequestResult<()>;
+
+    async fn search(&self, text: String) -> RequestResult<Vec<SearchResult>>;
+
+    async fn plugins(&self) -> RequestResult<HashMap<PluginId, SettingsPlugin>>;
+
+    async fn entrypoint_actions(
+        &self,
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+    ) -> RequestResult<Vec<CliEntrypointAction>>;
+
+    async fn download_plugin(&self, source: PluginSource) -> RequestResult<()>;
+
+    async fn download_status(&self) -> RequestResult<HashMap<PluginId, DownloadStatus>>;
+
+    async fn remove_plugin(&self, plugin_id: PluginId) -> RequestResult<()>;
+
+    async fn set_plugin_state(
+        &self,
+        plugin_id: PluginId,
+        enabled: bool,
+        acknowledge_unsigned: bool,
+    ) -> RequestResult<()>;
+
+    async fn set_entrypoint_state(
+        &self,
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        enabled: bool,
     ) -> RequestResult<()>;
+
+    async fn reload_all_plugins(&self) -> RequestResult<()>;
+
+    async fn diagnostics(&self) -> RequestResult<CliDiagnostics>;
+
+    async fn shutdown(&self, restart: bool) -> RequestResult<()>;
+
+    async fn export_frecency(&self) -> RequestResult<String>;
+
+    async fn import_frecency(&self, data: String) -> RequestResult<()>;
+
+    async fn run_history(&self) -> RequestResult<Vec<CliRunHistoryItem>>;
+
+    async fn sync_plugins(&self, dry_run: bool) -> RequestResult<CliPluginSyncReport>;
 }
 
 #[tonic::async_trait]
@@ -37,13 +101,43 @@ pub trait BackendForToolsApi {
 
 #[derive(Debug, Clone)]
 pub struct GrpcBackendApi {
-    client: Arc<Mutex<RpcBackendClient<Channel>>>,
+    client: Arc<Mutex<RpcBackendClient<InterceptedService<Channel, ClientTokenInterceptor>>>>,
 }
 
 impl GrpcBackendApi {
     pub async fn new() -> anyhow::Result<Self> {
+        let transport = BackendTransport::read(&Dirs::new());
+
+        Self::connect(transport).await
+    }
+
+    pub async fn connect(transport: BackendTransport) -> anyhow::Result<Self> {
+        let token = transport.read_token()?;
+
+        let channel = match transport {
+            #[cfg(unix)]
+            BackendTransport::UnixSocket { path } => {
+                // uri is ignored by connector, but has to be valid
+                Endpoint::try_from("http://[::]:42320")?
+                    .connect_with_connector(tower::service_fn(move |_: tonic::transport::Uri| {
+                        let path = path.clone();
+                        async move {
+                            let stream = tokio::net::UnixStream::connect(path).await?;
+
+                            Ok::<_, std::io::Error>(hyper_util::rt::TokioIo::new(stream))
+                        }
+                    }))
+                    .await?
+            }
+            BackendTransport::Tcp { address, .. } => {
+                Endpoint::try_from(format!("http://{}", address))?.connect().await?
+            }
+        };
+
+        let client = RpcBackendClient::with_interceptor(channel, ClientTokenInterceptor::new(token)?);
+
         Ok(Self {
-            client: Arc::new(Mutex::new(RpcBackendClient::connect("http://127.0.0.1:42320").await?)),
+            client: Arc::new(Mutex::new(client)),
         })
     }
 
@@ -61,6 +155,25 @@ impl GrpcBackendApi {
         Ok(response)
     }
 
+    pub async fn subscribe(&self) -> anyhow::Result<impl Stream<Item = anyhow::Result<ServerEvent>>> {
+        let mut client = self.client.lock().await;
+
+        let stream = client
+            .subscribe(Request::new(RpcSubscribeRequest {}))
+            .await?
+            .into_inner();
+
+        let stream = stream.map(|item| -> anyhow::Result<ServerEvent> {
+            let data = item?.data;
+
+            let (event, _) = bincode::decode_from_slice(&data[..], bincode::config::standard())?;
+
+            Ok(event)
+        });
+
+        Ok(stream)
+    }
+
     pub async fn save_local_plugin(&self, path: String) -> RequestResult<LocalSaveData> {
         let request = RpcSaveLocalPluginRequest { path };
 
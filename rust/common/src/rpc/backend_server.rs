@@ -1,26 +1,47 @@
 use std::net::SocketAddr;
+#[cfg(unix)]
+use std::path::Path;
+use std::pin::Pin;
+use std::sync::Arc;
 use std::time::Duration;
 
-use tokio::net::TcpStream;
+#[cfg(unix)]
+use anyhow::anyhow;
+use tokio::sync::broadcast;
+use tokio_stream::Stream;
+use tokio_stream::StreamExt;
+use tokio_stream::wrappers::BroadcastStream;
+use tokio_stream::wrappers::errors::BroadcastStreamRecvError;
 use tonic::Request;
 use tonic::Response;
 use tonic::Status;
 use tonic::transport::Server;
 
+use crate::model::ServerEvent;
 use crate::rpc::backend_api::BackendForCliApi;
 use crate::rpc::backend_api::BackendForToolsApi;
 use crate::rpc::backend_api::handle_grpc_request_backend_for_cli_api;
 use crate::rpc::grpc::RpcBincode;
 use crate::rpc::grpc::RpcSaveLocalPluginRequest;
 use crate::rpc::grpc::RpcSaveLocalPluginResponse;
+use crate::rpc::grpc::RpcSubscribeRequest;
 use crate::rpc::grpc::rpc_backend_server::RpcBackend;
 use crate::rpc::grpc::rpc_backend_server::RpcBackendServer;
+use crate::rpc::transport::BackendTransport;
+use crate::rpc::transport::ServerTokenInterceptor;
 
-pub async fn wait_for_backend_server() {
+// address used by `@project-gauntlet/tools` dev server
+const TOOLS_ADDRESS: &str = "127.0.0.1:42320";
+
+pub async fn wait_for_backend_server(transport: &BackendTransport) {
     loop {
-        let addr: SocketAddr = "127.0.0.1:42320".parse().unwrap();
+        let connected = match transport {
+            #[cfg(unix)]
+            BackendTransport::UnixSocket { path } => tokio::net::UnixStream::connect(path).await.is_ok(),
+            BackendTransport::Tcp { address, .. } => tokio::net::TcpStream::connect(address).await.is_ok(),
+        };
 
-        if TcpStream::connect(addr).await.is_ok() {
+        if connected {
             return;
         }
 
@@ -29,31 +50,123 @@ pub async fn wait_for_backend_server() {
 }
 
 pub async fn start_backend_server(
+    transport: BackendTransport,
+    tools_server: bool,
+    events: broadcast::Sender<ServerEvent>,
     cli: Box<dyn BackendForCliApi + Sync + Send>,
     tools: Box<dyn BackendForToolsApi + Sync + Send>,
 ) {
-    let addr = "127.0.0.1:42320".parse().unwrap();
+    let token = transport.read_token().expect("unable to read control token");
 
-    Server::builder()
-        .add_service(RpcBackendServer::new(RpcBackendServerImpl::new(cli, tools)))
-        .serve(addr)
-        .await
-        .expect("unable to start backend server");
+    let tools: Arc<dyn BackendForToolsApi + Sync + Send> = Arc::from(tools);
+
+    if tools_server {
+        tokio::spawn(start_tools_server(tools.clone()));
+    }
+
+    let service = RpcBackendServer::with_interceptor(
+        RpcBackendServerImpl::new(events, cli, tools),
+        ServerTokenInterceptor::new(token),
+    );
+
+    match transport {
+        #[cfg(unix)]
+        BackendTransport::UnixSocket { path } => {
+            let listener = bind_control_socket(&path).expect("unable to bind control socket");
+
+            Server::builder()
+                .add_service(service)
+                .serve_with_incoming(tokio_stream::wrappers::UnixListenerStream::new(listener))
+                .await
+                .expect("unable to start backend server");
+        }
+        BackendTransport::Tcp { address, .. } => {
+            Server::builder()
+                .add_service(service)
+                .serve(address)
+                .await
+                .expect("unable to start backend server");
+        }
+    }
+}
+
+/// `@project-gauntlet/tools` dev server doesn't know about control transport and token,
+/// so requests to save local plugin are also accepted on its fixed address, but nothing else is.
+/// Anyone on the machine can connect to it, so it is only started if enabled in `[control]` section of config
+async fn start_tools_server(tools: Arc<dyn BackendForToolsApi + Sync + Send>) {
+    let address: SocketAddr = TOOLS_ADDRESS.parse().unwrap();
+
+    let result = Server::builder()
+        .add_service(RpcBackendServer::new(RpcToolsServerImpl { tools }))
+        .serve(address)
+        .await;
+
+    if let Err(err) = result {
+        tracing::error!("Unable to start dev tools server on {}: {:?}", address, err);
+    }
+}
+
+/// Socket is created in a directory only current user can access and moved to `path` after its permissions are
+/// restricted, so other users are not able to connect to it between bind and chmod
+#[cfg(unix)]
+fn bind_control_socket(path: &Path) -> anyhow::Result<tokio::net::UnixListener> {
+    use std::os::unix::fs::DirBuilderExt;
+    use std::os::unix::fs::PermissionsExt;
+
+    let parent = path
+        .parent()
+        .ok_or_else(|| anyhow!("control socket path {} has no parent directory", path.display()))?;
+
+    std::fs::create_dir_all(parent)?;
+
+    let file_name = path
+        .file_name()
+        .ok_or_else(|| anyhow!("control socket path {} has no file name", path.display()))?;
+
+    let staging_dir = parent.join(format!(".{}.{}", file_name.to_string_lossy(), std::process::id()));
+
+    let _ = std::fs::remove_dir_all(&staging_dir);
+
+    std::fs::DirBuilder::new().mode(0o700).create(&staging_dir)?;
+
+    let staging_path = staging_dir.join(file_name);
+
+    let result = (|| -> anyhow::Result<tokio::net::UnixListener> {
+        let listener = tokio::net::UnixListener::bind(&staging_path)?;
+
+        std::fs::set_permissions(&staging_path, std::fs::Permissions::from_mode(0o600))?;
+
+        // server running check is done before this point, so any existing socket is a leftover
+        std::fs::rename(&staging_path, path)?;
+
+        Ok(listener)
+    })();
+
+    let _ = std::fs::remove_dir_all(&staging_dir);
+
+    result
 }
 
 struct RpcBackendServerImpl {
+    events: broadcast::Sender<ServerEvent>,
     cli: Box<dyn BackendForCliApi + Sync + Send>,
-    tools: Box<dyn BackendForToolsApi + Sync + Send>,
+    tools: Arc<dyn BackendForToolsApi + Sync + Send>,
 }
 
 impl RpcBackendServerImpl {
-    pub fn new(cli: Box<dyn BackendForCliApi + Sync + Send>, tools: Box<dyn BackendForToolsApi + Sync + Send>) -> Self {
-        Self { cli, tools }
+    pub fn new(
+        events: broadcast::Sender<ServerEvent>,
+        cli: Box<dyn BackendForCliApi + Sync + Send>,
+        tools: Arc<dyn BackendForToolsApi + Sync + Send>,
+    ) -> Self {
+        Self { events, cli, tools }
     }
 }
 
 #[tonic::async_trait]
 impl RpcBackend for RpcBackendServerImpl {
+    type SubscribeStream = Pin<Box<dyn Stream<Item = Result<RpcBincode, Status>> + Send>>;
+
     async fn backend_for_cli_api(&self, request: Request<RpcBincode>) -> Result<Response<RpcBincode>, Status> {
         let data = request.into_inner().data;
 
@@ -62,22 +175,83 @@ impl RpcBackend for RpcBackendServerImpl {
         Ok(Response::new(RpcBincode { data: encoded }))
     }
 
+    async fn subscribe(
+        &self,
+        _request: Request<RpcSubscribeRequest>,
+    ) -> Result<Response<Self::SubscribeStream>, Status> {
+        let stream = BroadcastStream::new(self.events.subscribe()).filter_map(|event| {
+            match event {
+                Ok(event) => {
+                    let encoded = bincode::encode_to_vec(&event, bincode::config::standard())
+                        .map_err(|err| Status::internal(format!("Unable to serialize event: {:#}", err)));
+
+                    Some(encoded.map(|data| RpcBincode { data }))
+                }
+                Err(BroadcastStreamRecvError::Lagged(count)) => {
+                    // slow subscriber, skip missed events instead of closing the stream
+                    tracing::warn!("Event subscriber lagged behind, {} events were skipped", count);
+
+                    None
+                }
+            }
+        });
+
+        Ok(Response::new(Box::pin(stream)))
+    }
+
+    async fn save_local_plugin(
+        &self,
+        request: Request<RpcSaveLocalPluginRequest>,
+    ) -> Result<Response<RpcSaveLocalPluginResponse>, Status> {
+        save_local_plugin(self.tools.as_ref(), request).await
+    }
+}
+
+struct RpcToolsServerImpl {
+    tools: Arc<dyn BackendForToolsApi + Sync + Send>,
+}
+
+#[tonic::async_trait]
+impl RpcBackend for RpcToolsServerImpl {
+    type SubscribeStream = Pin<Box<dyn Stream<Item = Result<RpcBincode, Status>> + Send>>;
+
+    async fn backend_for_cli_api(&self, _request: Request<RpcBincode>) -> Result<Response<RpcBincode>, Status> {
+        Err(tools_only_status())
+    }
+
+    async fn subscribe(
+        &self,
+        _request: Request<RpcSubscribeRequest>,
+    ) -> Result<Response<Self::SubscribeStream>, Status> {
+        Err(tools_only_status())
+    }
+
     async fn save_local_plugin(
         &self,
         request: Request<RpcSaveLocalPluginRequest>,
     ) -> Result<Response<RpcSaveLocalPluginResponse>, Status> {
-        let request = request.into_inner();
-        let path = request.path;
-
-        let local_save_data = self
-            .tools
-            .save_local_plugin(path)
-            .await
-            .map_err(|err| Status::internal(format!("{:#}", err)))?;
-
-        Ok(Response::new(RpcSaveLocalPluginResponse {
-            stdout_file_path: local_save_data.stdout_file_path,
-            stderr_file_path: local_save_data.stderr_file_path,
-        }))
+        save_local_plugin(self.tools.as_ref(), request).await
     }
 }
+
+fn tools_only_status() -> Status {
+    Status::permission_denied("only dev tools requests are accepted on this address, use control transport instead")
+}
+
+async fn save_local_plugin(
+    tools: &(dyn BackendForToolsApi + Sync + Send),
+    request: Request<RpcSaveLocalPluginRequest>,
+) -> Result<Response<RpcSaveLocalPluginResponse>, Status> {
+    let request = request.into_inner();
+    let path = request.path;
+
+    let local_save_data = tools
+        .save_local_plugin(path)
+        .await
+        .map_err(|err| Status::internal(format!("{:#}", err)))?;
+
+    Ok(Response::new(RpcSaveLocalPluginResponse {
+        stdout_file_path: local_save_data.stdout_file_path,
+        stderr_file_path: local_save_data.stderr_file_path,
+    }))
+}
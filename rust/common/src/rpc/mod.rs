@@ -3,3 +3,4 @@ pub mod backend_server;
 pub mod frontend_api;
 mod grpc;
 pub mod server_grpc_api;
+pub mod transport;
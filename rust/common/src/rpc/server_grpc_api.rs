@@ -1,9 +1,19 @@
+use std::collections::HashMap;
+
 use gauntlet_utils::channel::RequestResult;
 use gauntlet_utils_macros::boundary_gen;
 
+use crate::model::CliDiagnostics;
+use crate::model::CliEntrypointAction;
+use crate::model::CliPluginSyncReport;
+use crate::model::CliRunHistoryItem;
+use crate::model::DownloadStatus;
 use crate::model::EntrypointId;
 use crate::model::LocalSaveData;
 use crate::model::PluginId;
+use crate::model::PluginSource;
+use crate::model::SearchResult;
+use crate::model::SettingsPlugin;
 
 #[allow(async_fn_in_trait)]
 #[boundary_gen(in_process)]
@@ -17,7 +27,53 @@ pub trait ServerGrpcApi {
         plugin_id: PluginId,
         entrypoint_id: EntrypointId,
         action_id: String,
+        arguments: HashMap<String, String>,
+        query: Option<String>,
     ) -> RequestResult<()>;
 
     async fn save_local_plugin(&self, path: String) -> RequestResult<LocalSaveData>;
+
+    async fn search(&self, text: String) -> RequestResult<Vec<SearchResult>>;
+
+    async fn plugins(&self) -> RequestResult<HashMap<PluginId, SettingsPlugin>>;
+
+    async fn entrypoint_actions(
+        &self,
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+    ) -> RequestResult<Vec<CliEntrypointAction>>;
+
+    async fn download_plugin(&self, source: PluginSource) -> RequestResult<()>;
+
+    async fn download_status(&self) -> RequestResult<HashMap<PluginId, DownloadStatus>>;
+
+    async fn remove_plugin(&self, plugin_id: PluginId) -> RequestResult<()>;
+
+    async fn set_plugin_state(
+        &self,
+        plugin_id: PluginId,
+        enabled: bool,
+        acknowledge_unsigned: bool,
+    ) -> RequestResult<()>;
+
+    async fn set_entrypoint_state(
+        &self,
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        enabled: bool,
+    ) -> RequestResult<()>;
+
+    async fn reload_all_plugins(&self) -> RequestResult<()>;
+
+    async fn diagnostics(&self) -> RequestResult<CliDiagnostics>;
+
+    async fn shutdown(&self, restart: bool) -> RequestResult<()>;
+
+    async fn export_frecency(&self) -> RequestResult<String>;
+
+    async fn import_frecency(&self, data: String) -> RequestResult<()>;
+
+    async fn run_history(&self) -> RequestResult<Vec<CliRunHistoryItem>>;
+
+    async fn sync_plugins(&self, dry_run: bool) -> RequestResult<CliPluginSyncReport>;
 }
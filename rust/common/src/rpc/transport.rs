@@ -0,0 +1,179 @@
+use std::net::SocketAddr;
+use std::path::PathBuf;
+
+use serde::Deserialize;
+use tonic::Request;
+use tonic::Status;
+use tonic::metadata::AsciiMetadataValue;
+use tonic::service::Interceptor;
+
+use crate::dirs::Dirs;
+
+// 42320 is used by dev tools endpoint
+const DEFAULT_TCP_ADDRESS: &str = "127.0.0.1:42321";
+const TOKEN_METADATA_KEY: &str = "x-gauntlet-token";
+
+#[derive(Deserialize, Debug, Default)]
+pub struct ControlConfig {
+    pub transport: Option<ControlTransportConfig>,
+    pub socket_path: Option<PathBuf>,
+    pub address: Option<SocketAddr>,
+    pub token_file: Option<PathBuf>,
+    pub tools_server: Option<bool>,
+}
+
+#[derive(Deserialize, Debug)]
+pub enum ControlTransportConfig {
+    #[serde(rename = "unix_socket")] // default on linux and macos
+    UnixSocket,
+    #[serde(rename = "tcp")] // default on windows
+    Tcp,
+}
+
+#[derive(Debug, Clone)]
+pub enum BackendTransport {
+    #[cfg(unix)]
+    UnixSocket {
+        path: PathBuf,
+    },
+    Tcp {
+        address: SocketAddr,
+        token_file: PathBuf,
+    },
+}
+
+impl BackendTransport {
+    pub fn from_config(dirs: &Dirs, config: Option<ControlConfig>) -> Self {
+        let config = config.unwrap_or_default();
+
+        let transport = match config.transport {
+            Some(transport) => transport,
+            None if cfg!(unix) => ControlTransportConfig::UnixSocket,
+            None => ControlTransportConfig::Tcp,
+        };
+
+        let tcp = || {
+            BackendTransport::Tcp {
+                address: config.address.unwrap_or_else(|| DEFAULT_TCP_ADDRESS.parse().unwrap()),
+                token_file: config.token_file.clone().unwrap_or_else(|| dirs.control_token_file()),
+            }
+        };
+
+        match transport {
+            #[cfg(unix)]
+            ControlTransportConfig::UnixSocket => {
+                BackendTransport::UnixSocket {
+                    path: config.socket_path.clone().unwrap_or_else(|| dirs.control_socket()),
+                }
+            }
+            #[cfg(not(unix))]
+            ControlTransportConfig::UnixSocket => {
+                tracing::warn!("Unix socket control transport is not supported on this platform, using tcp");
+
+                tcp()
+            }
+            ControlTransportConfig::Tcp => tcp(),
+        }
+    }
+
+    /// Reads only the `[control]` section of config file, used by cli which doesn't have access to the full config
+    pub fn read(dirs: &Dirs) -> Self {
+        #[derive(Deserialize, Default)]
+        struct PartialConfig {
+            control: Option<ControlConfig>,
+        }
+
+        let config = std::fs::read_to_string(dirs.config_file())
+            .ok()
+            .and_then(|content| {
+                toml::from_str::<PartialConfig>(&content)
+                    .inspect_err(|err| tracing::error!("Unable to parse config, error: {:#}", err))
+                    .ok()
+            })
+            .unwrap_or_default();
+
+        Self::from_config(dirs, config.control)
+    }
+
+    pub fn read_token(&self) -> anyhow::Result<Option<String>> {
+        match self {
+            #[cfg(unix)]
+            BackendTransport::UnixSocket { .. } => Ok(None),
+            BackendTransport::Tcp { token_file, .. } => {
+                let token = std::fs::read_to_string(token_file).map_err(|err| {
+                    anyhow::anyhow!("Unable to read control token file {}: {}", token_file.display(), err)
+                })?;
+
+                let token = token.trim().to_string();
+
+                if token.is_empty() {
+                    Err(anyhow::anyhow!("Control token file {} is empty", token_file.display()))?
+                }
+
+                Ok(Some(token))
+            }
+        }
+    }
+}
+
+/// Attaches control token to every request made by client, noop when transport doesn't require one
+#[derive(Clone)]
+pub struct ClientTokenInterceptor {
+    token: Option<AsciiMetadataValue>,
+}
+
+impl ClientTokenInterceptor {
+    pub fn new(token: Option<String>) -> anyhow::Result<Self> {
+        let token = token.map(|token| token.parse()).transpose()?;
+
+        Ok(Self { token })
+    }
+}
+
+impl Interceptor for ClientTokenInterceptor {
+    fn call(&mut self, mut request: Request<()>) -> Result<Request<()>, Status> {
+        if let Some(token) = &self.token {
+            request.metadata_mut().insert(TOKEN_METADATA_KEY, token.clone());
+        }
+
+        Ok(request)
+    }
+}
+
+/// Rejects requests which do not carry expected control token, noop when transport doesn't require one
+#[derive(Clone)]
+pub struct ServerTokenInterceptor {
+    token: Option<String>,
+}
+
+impl ServerTokenInterceptor {
+    pub fn new(token: Option<String>) -> Self {
+        Self { token }
+    }
+}
+
+impl Interceptor for ServerTokenInterceptor {
+    fn call(&mut self, request: Request<()>) -> Result<Request<()>, Status> {
+        let Some(expected) = &self.token else {
+            return Ok(request);
+        };
+
+        let actual = request
+            .metadata()
+            .get(TOKEN_METADATA_KEY)
+            .and_then(|value| value.to_str().ok());
+
+        match actual {
+            Some(actual) if constant_time_eq(actual.as_bytes(), expected.as_bytes()) => Ok(request),
+            _ => Err(Status::unauthenticated("invalid or missing control token")),
+        }
+    }
+}
+
+fn constant_time_eq(left: &[u8], right: &[u8]) -> bool {
+    if left.len() != right.len() {
+        return false;
+    }
+
+    left.iter().zip(right).fold(0u8, |acc, (l, r)| acc | (l ^ r)) == 0
+}
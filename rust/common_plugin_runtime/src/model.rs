@@ -19,6 +19,7 @@ pub enum JsEvent {
     OpenView {
         #[serde(rename = "entrypointId")]
         entrypoint_id: String,
+        query: Option<String>,
     },
     CloseView,
     PopView {
@@ -28,12 +29,18 @@ pub enum JsEvent {
     RunCommand {
         #[serde(rename = "entrypointId")]
         entrypoint_id: String,
+        #[serde(rename = "args")]
+        arguments: HashMap<String, String>,
+        query: Option<String>,
     },
     RunGeneratedEntrypoint {
         #[serde(rename = "entrypointId")]
         entrypoint_id: String,
         #[serde(rename = "actionIndex")]
         action_index: usize,
+        #[serde(rename = "args")]
+        arguments: HashMap<String, String>,
+        query: Option<String>,
     },
     ViewEvent {
         #[serde(rename = "widgetId")]
@@ -158,6 +165,7 @@ pub struct JsGeneratedSearchItem {
     pub entrypoint_icon: Option<Vec<u8>>,
     pub entrypoint_actions: Vec<JsGeneratedSearchItemAction>,
     pub entrypoint_accessories: Vec<JsGeneratedSearchItemAccessory>,
+    pub entrypoint_keywords: Vec<String>,
 }
 
 impl fmt::Debug for JsGeneratedSearchItem {
@@ -170,6 +178,7 @@ impl fmt::Debug for JsGeneratedSearchItem {
             .field("entrypoint_uuid", &self.entrypoint_uuid)
             .field("entrypoint_actions", &self.entrypoint_actions)
             .field("entrypoint_accessories", &self.entrypoint_accessories)
+            .field("entrypoint_keywords", &self.entrypoint_keywords)
             .finish()
     }
 }
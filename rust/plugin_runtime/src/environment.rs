@@ -14,7 +14,7 @@ pub fn environment_gauntlet_version() -> u16 {
 pub fn environment_is_development(state: &mut OpState) -> bool {
     let plugin_id = state.borrow::<PluginData>().plugin_id();
 
-    plugin_id.to_string().starts_with("file://")
+    plugin_id.is_development()
 }
 
 #[op2]
@@ -25,6 +25,7 @@ pub struct DenoInGeneratedSearchItem {
     pub entrypoint_icon: Option<JsBuffer>,
     pub entrypoint_actions: Vec<JsGeneratedSearchItemAction>,
     pub entrypoint_accessories: Vec<JsGeneratedSearchItemAccessory>,
+    pub entrypoint_keywords: Vec<String>,
 }
 
 #[derive(Serialize)]
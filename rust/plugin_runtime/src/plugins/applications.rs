@@ -43,6 +43,7 @@ pub struct DesktopApplication {
     desktop_file_path: String,
     icon: Option<ToJsBuffer>,
     startup_wm_class: Option<String>,
+    keywords: Vec<String>,
 }
 
 #[cfg(target_os = "macos")]
@@ -212,6 +212,18 @@ fn create_app_entry(desktop_file_path: &Path) -> Option<DesktopApplication> {
     let no_display = entry.attr("NoDisplay").map(|val| val == "true").unwrap_or(false);
     let hidden = entry.attr("Hidden").map(|val| val == "true").unwrap_or(false);
     let startup_wm_class = entry.attr("StartupWMClass").map(|s| s.to_string());
+    // e.g. "Web Browser" for Firefox, used only for search
+    let generic_name = entry.attr("GenericName").map(|s| s.to_string());
+    let keywords = entry
+        .attr("Keywords")
+        .map(|s| {
+            s.split(';')
+                .map(|keyword| keyword.trim())
+                .filter(|keyword| !keyword.is_empty())
+                .map(|keyword| keyword.to_string())
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
     // TODO NotShowIn, OnlyShowIn https://wiki.archlinux.org/title/desktop_entries
 
     if no_display || hidden {
@@ -274,5 +286,6 @@ fn create_app_entry(desktop_file_path: &Path) -> Option<DesktopApplication> {
         desktop_file_path: desktop_file_path_str,
         icon,
         startup_wm_class,
+        keywords: generic_name.into_iter().chain(keywords).collect(),
     })
 }
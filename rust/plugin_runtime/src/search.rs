@@ -35,6 +35,7 @@ pub async fn reload_search_index(
                 entrypoint_icon: item.entrypoint_icon.map(|buffer| buffer.to_vec()),
                 entrypoint_actions: item.entrypoint_actions,
                 entrypoint_accessories: item.entrypoint_accessories,
+                entrypoint_keywords: item.entrypoint_keywords,
             }
         })
         .collect();
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use gauntlet_common::model::EntrypointId;
 use gauntlet_common::model::KeyboardEventOrigin;
 use gauntlet_common::model::PhysicalKey;
@@ -8,6 +10,7 @@ use gauntlet_common::model::UiWidgetId;
 pub enum IntermediateUiEvent {
     OpenView {
         entrypoint_id: EntrypointId,
+        query: Option<String>,
     },
     CloseView,
     PopView {
@@ -15,10 +18,14 @@ pub enum IntermediateUiEvent {
     },
     RunCommand {
         entrypoint_id: String,
+        arguments: HashMap<String, String>,
+        query: Option<String>,
     },
     RunGeneratedEntrypoint {
         entrypoint_id: String,
         action_index: usize,
+        arguments: HashMap<String, String>,
+        query: Option<String>,
     },
     HandleViewEvent {
         widget_id: UiWidgetId,
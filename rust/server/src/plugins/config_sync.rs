@@ -0,0 +1,1134 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::fmt::Debug;
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use anyhow::anyhow;
+use gauntlet_common::model::EntrypointId;
+use gauntlet_common::model::EntrypointSearchAlias;
+use gauntlet_common::model::PhysicalKey;
+use gauntlet_common::model::PhysicalShortcut;
+use gauntlet_common::model::PluginId;
+use gauntlet_common::model::PluginPreferenceUserData;
+use gauntlet_common::model::PluginSource;
+use itertools::Itertools;
+
+use crate::plugins::data_db_repository::DbPluginPreference;
+use crate::plugins::data_db_repository::DbPluginPreferenceUserData;
+use crate::plugins::data_db_repository::DbPluginType;
+use crate::plugins::data_db_repository::DbPreferenceEnumValue;
+use crate::plugins::data_db_repository::DbReadPlugin;
+use crate::plugins::data_db_repository::DbReadPluginEntrypoint;
+use crate::plugins::data_db_repository::db_plugin_type_from_str;
+use crate::plugins::loader::PLUGIN_RELEASE_BRANCH;
+use crate::plugins::plugin_preference_user_data_from_db;
+use crate::plugins::settings::config::PluginConfig;
+use crate::plugins::settings::config::PluginEntrypointConfig;
+use crate::plugins::settings::config::PreferenceValueConfig;
+
+pub enum PluginSyncChange {
+    InstallPlugin {
+        plugin_id: PluginId,
+    },
+    RemovePlugin {
+        plugin_id: PluginId,
+    },
+    // plugin is downloaded again from different branch, tag or commit, keeping its settings and usage history
+    SwitchPluginRef {
+        plugin_id: PluginId,
+        git_ref: Option<String>,
+    },
+    // plugin is downloaded again from archive with different hash, keeping its settings and usage history
+    SwitchPluginArchive {
+        plugin_id: PluginId,
+        archive_sha256: Option<String>,
+    },
+    SetPluginEnabled {
+        plugin_id: PluginId,
+        enabled: bool,
+        acknowledge_unsigned: bool,
+    },
+    SetEntrypointEnabled {
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        enabled: bool,
+    },
+    SetPreference {
+        plugin_id: PluginId,
+        entrypoint_id: Option<EntrypointId>,
+        preference_id: String,
+        value: PluginPreferenceUserData,
+    },
+    SetSearchAliases {
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        aliases: Vec<EntrypointSearchAlias>,
+    },
+    SetGlobalShortcut {
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        shortcut: PhysicalShortcut,
+    },
+}
+
+impl Display for PluginSyncChange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PluginSyncChange::InstallPlugin { plugin_id } => {
+                write!(f, "+ install plugin {} and apply its settings", plugin_id)
+            }
+            PluginSyncChange::RemovePlugin { plugin_id } => write!(f, "- remove plugin {}", plugin_id),
+            PluginSyncChange::SwitchPluginRef { plugin_id, git_ref } => {
+                write!(
+                    f,
+                    "~ download plugin {} from {} and apply its settings",
+                    plugin_id,
+                    git_ref.as_deref().unwrap_or(PLUGIN_RELEASE_BRANCH)
+                )
+            }
+            PluginSyncChange::SwitchPluginArchive {
+                plugin_id,
+                archive_sha256,
+            } => {
+                write!(
+                    f,
+                    "~ download plugin {} with hash {} and apply its settings",
+                    plugin_id,
+                    archive_sha256.as_deref().unwrap_or("none")
+                )
+            }
+            PluginSyncChange::SetPluginEnabled { plugin_id, enabled, .. } => {
+                write!(f, "~ {} plugin {}", enabled_label(*enabled), plugin_id)
+            }
+            PluginSyncChange::SetEntrypointEnabled {
+                plugin_id,
+                entrypoint_id,
+                enabled,
+            } => {
+                write!(
+                    f,
+                    "~ {} entrypoint {} of plugin {}",
+                    enabled_label(*enabled),
+                    entrypoint_id,
+                    plugin_id
+                )
+            }
+            PluginSyncChange::SetPreference {
+                plugin_id,
+                entrypoint_id: None,
+                preference_id,
+                value,
+            } => {
+                write!(
+                    f,
+                    "~ set preference {} of plugin {} to {}",
+                    preference_id,
+                    plugin_id,
+                    preference_value_label(value)
+                )
+            }
+            PluginSyncChange::SetPreference {
+                plugin_id,
+                entrypoint_id: Some(entrypoint_id),
+                preference_id,
+                value,
+            } => {
+                write!(
+                    f,
+                    "~ set preference {} of entrypoint {} of plugin {} to {}",
+                    preference_id,
+                    entrypoint_id,
+                    plugin_id,
+                    preference_value_label(value)
+                )
+            }
+            PluginSyncChange::SetSearchAliases {
+                plugin_id,
+                entrypoint_id,
+                aliases,
+            } => {
+                let aliases = aliases
+                    .iter()
+                    .map(|alias| {
+                        if alias.keyword {
+                            format!("{} (keyword)", alias.alias)
+                        } else {
+                            alias.alias.clone()
+                        }
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                write!(
+                    f,
+                    "~ set aliases of entrypoint {} of plugin {} to [{}]",
+                    entrypoint_id, plugin_id, aliases
+                )
+            }
+            PluginSyncChange::SetGlobalShortcut {
+                plugin_id,
+                entrypoint_id,
+                shortcut,
+            } => {
+                write!(
+                    f,
+                    "~ set global shortcut of entrypoint {} of plugin {} to {}",
+                    entrypoint_id,
+                    plugin_id,
+                    shortcut.label()
+                )
+            }
+        }
+    }
+}
+
+pub struct PluginSyncPlan {
+    pub changes: Vec<PluginSyncChange>,
+    pub warnings: Vec<String>,
+}
+
+/// `ref` and `sha256` are not a part of plugin id, but can also be specified after `#` in `id`
+pub fn configured_plugin_source(plugin_config: &PluginConfig) -> PluginSource {
+    let source = PluginSource::parse(&plugin_config.id);
+
+    PluginSource {
+        git_ref: plugin_config.git_ref.clone().or(source.git_ref),
+        archive_sha256: plugin_config.sha256.clone().or(source.archive_sha256),
+        plugin_id: source.plugin_id,
+    }
+}
+
+pub fn configured_plugin_id(plugin_config: &PluginConfig) -> PluginId {
+    configured_plugin_source(plugin_config).plugin_id
+}
+
+// plugins which are installed from git repository or archive, as opposed to local and bundled plugins
+fn is_downloadable(plugin_id: &PluginId) -> bool {
+    plugin_id.try_to_git_url().is_ok() || plugin_id.try_to_archive_url().is_ok()
+}
+
+/// Lists changes needed to make installed plugins match the config.
+/// Only installation is planned for plugins which are not installed yet or have to be downloaded from different ref or archive,
+/// the rest of their settings has to be planned again after they are downloaded
+pub fn plan_plugin_sync(
+    plugins_config: &[PluginConfig],
+    remove_unlisted: bool,
+    installed_plugins: Vec<(DbReadPlugin, Vec<DbReadPluginEntrypoint>)>,
+    search_aliases: &HashMap<(PluginId, EntrypointId), Vec<EntrypointSearchAlias>>,
+    global_shortcuts: &HashMap<(PluginId, EntrypointId), PhysicalShortcut>,
+) -> PluginSyncPlan {
+    let mut plan = PluginSyncPlan {
+        changes: vec![],
+        warnings: vec![],
+    };
+
+    let mut installed_plugins: HashMap<_, _> = installed_plugins
+        .into_iter()
+        .map(|(plugin, entrypoints)| (PluginId::from_string(&plugin.id), (plugin, entrypoints)))
+        .collect();
+
+    let configured_plugin_ids: HashSet<_> = plugins_config.iter().map(configured_plugin_id).collect();
+
+    if remove_unlisted {
+        for (plugin_id, (plugin, _)) in &installed_plugins {
+            let downloaded = matches!(db_plugin_type_from_str(&plugin.plugin_type), DbPluginType::Normal)
+                && is_downloadable(plugin_id);
+
+            if downloaded && !configured_plugin_ids.contains(plugin_id) {
+                plan.changes.push(PluginSyncChange::RemovePlugin {
+                    plugin_id: plugin_id.clone(),
+                });
+            }
+        }
+    }
+
+    for plugin_config in plugins_config {
+        let source = configured_plugin_source(plugin_config);
+        let plugin_id = source.plugin_id.clone();
+
+        match installed_plugins.remove(&plugin_id) {
+            None => {
+                if !is_downloadable(&plugin_id) {
+                    plan.warnings.push(format!(
+                        "Plugin {} is not installed and cannot be downloaded, only git repositories and .tar.gz or .zip archives are supported",
+                        plugin_id
+                    ));
+                    continue;
+                }
+
+                plan.changes.push(PluginSyncChange::InstallPlugin {
+                    plugin_id: plugin_id.clone(),
+                });
+
+                // aliases and shortcuts are not stored with plugin, so they can be set before plugin is installed
+                plan_plugin_search(&mut plan, &plugin_id, plugin_config, search_aliases, global_shortcuts);
+            }
+            // plugins are matched by id, which doesn't include ref, so changing it keeps user data of the plugin
+            Some((plugin, _)) if !plugin_id.is_archive() && plugin.git_ref != source.git_ref => {
+                plan.changes.push(PluginSyncChange::SwitchPluginRef {
+                    plugin_id: plugin_id.clone(),
+                    git_ref: source.git_ref,
+                });
+
+                plan_plugin_search(&mut plan, &plugin_id, plugin_config, search_aliases, global_shortcuts);
+            }
+            // new version of archive is published with different hash, id stays the same
+            Some((plugin, _)) if plugin_id.is_archive() && plugin.archive_sha256 != source.archive_sha256 => {
+                plan.changes.push(PluginSyncChange::SwitchPluginArchive {
+                    plugin_id: plugin_id.clone(),
+                    archive_sha256: source.archive_sha256,
+                });
+
+                plan_plugin_search(&mut plan, &plugin_id, plugin_config, search_aliases, global_shortcuts);
+            }
+            Some((plugin, entrypoints)) => {
+                plan_installed_plugin(
+                    &mut plan,
+                    &plugin_id,
+                    plugin_config,
+                    plugin,
+                    entrypoints,
+                    search_aliases,
+                    global_shortcuts,
+                );
+            }
+        }
+    }
+
+    plan
+}
+
+fn plan_installed_plugin(
+    plan: &mut PluginSyncPlan,
+    plugin_id: &PluginId,
+    plugin_config: &PluginConfig,
+    plugin: DbReadPlugin,
+    entrypoints: Vec<DbReadPluginEntrypoint>,
+    search_aliases: &HashMap<(PluginId, EntrypointId), Vec<EntrypointSearchAlias>>,
+    global_shortcuts: &HashMap<(PluginId, EntrypointId), PhysicalShortcut>,
+) {
+    if let Some(enabled) = plugin_config.enabled {
+        if enabled != plugin.enabled {
+            plan.changes.push(PluginSyncChange::SetPluginEnabled {
+                plugin_id: plugin_id.clone(),
+                enabled,
+                acknowledge_unsigned: plugin_config.allow_unsigned.unwrap_or(false),
+            });
+        }
+    }
+
+    plan_preferences(
+        plan,
+        plugin_id,
+        None,
+        &plugin_config.preferences,
+        &plugin.preferences,
+        plugin.preferences_user_data,
+    );
+
+    let mut entrypoints: HashMap<_, _> = entrypoints
+        .into_iter()
+        .map(|entrypoint| (entrypoint.id.clone(), entrypoint))
+        .collect();
+
+    for (entrypoint_id, entrypoint_config) in &plugin_config.entrypoints {
+        let Some(entrypoint) = entrypoints.remove(entrypoint_id) else {
+            plan.warnings.push(format!(
+                "Entrypoint {} of plugin {} specified in config doesn't exist",
+                entrypoint_id, plugin_id
+            ));
+            continue;
+        };
+
+        let entrypoint_id = EntrypointId::from_string(entrypoint_id);
+
+        if let Some(enabled) = entrypoint_config.enabled {
+            if enabled != entrypoint.enabled {
+                plan.changes.push(PluginSyncChange::SetEntrypointEnabled {
+                    plugin_id: plugin_id.clone(),
+                    entrypoint_id: entrypoint_id.clone(),
+                    enabled,
+                });
+            }
+        }
+
+        plan_preferences(
+            plan,
+            plugin_id,
+            Some(&entrypoint_id),
+            &entrypoint_config.preferences,
+            &entrypoint.preferences,
+            entrypoint.preferences_user_data,
+        );
+
+        plan_entrypoint_search(
+            plan,
+            plugin_id,
+            &entrypoint_id,
+            entrypoint_config,
+            search_aliases,
+            global_shortcuts,
+        );
+    }
+}
+
+fn plan_preferences(
+    plan: &mut PluginSyncPlan,
+    plugin_id: &PluginId,
+    entrypoint_id: Option<&EntrypointId>,
+    preferences_config: &HashMap<String, PreferenceValueConfig>,
+    preferences: &HashMap<String, DbPluginPreference>,
+    mut preferences_user_data: HashMap<String, DbPluginPreferenceUserData>,
+) {
+    for (preference_id, value) in preferences_config {
+        let Some(preference) = preferences.get(preference_id) else {
+            plan.warnings.push(format!(
+                "Preference {} of plugin {} specified in config doesn't exist",
+                preference_id, plugin_id
+            ));
+            continue;
+        };
+
+        let value = match preference_value(preference, value) {
+            Ok(value) => value,
+            Err(err) => {
+                plan.warnings.push(format!(
+                    "Preference {} of plugin {} specified in config is invalid: {:#}",
+                    preference_id, plugin_id, err
+                ));
+                continue;
+            }
+        };
+
+        let current_value = preferences_user_data
+            .remove(preference_id)
+            .map(plugin_preference_user_data_from_db);
+
+        if current_value.as_ref() != Some(&value) {
+            plan.changes.push(PluginSyncChange::SetPreference {
+                plugin_id: plugin_id.clone(),
+                entrypoint_id: entrypoint_id.cloned(),
+                preference_id: preference_id.clone(),
+                value,
+            });
+        }
+    }
+}
+
+fn plan_plugin_search(
+    plan: &mut PluginSyncPlan,
+    plugin_id: &PluginId,
+    plugin_config: &PluginConfig,
+    search_aliases: &HashMap<(PluginId, EntrypointId), Vec<EntrypointSearchAlias>>,
+    global_shortcuts: &HashMap<(PluginId, EntrypointId), PhysicalShortcut>,
+) {
+    for (entrypoint_id, entrypoint_config) in &plugin_config.entrypoints {
+        plan_entrypoint_search(
+            plan,
+            plugin_id,
+            &EntrypointId::from_string(entrypoint_id),
+            entrypoint_config,
+            search_aliases,
+            global_shortcuts,
+        );
+    }
+}
+
+fn plan_entrypoint_search(
+    plan: &mut PluginSyncPlan,
+    plugin_id: &PluginId,
+    entrypoint_id: &EntrypointId,
+    entrypoint_config: &PluginEntrypointConfig,
+    search_aliases: &HashMap<(PluginId, EntrypointId), Vec<EntrypointSearchAlias>>,
+    global_shortcuts: &HashMap<(PluginId, EntrypointId), PhysicalShortcut>,
+) {
+    let key = (plugin_id.clone(), entrypoint_id.clone());
+
+    if entrypoint_config.aliases.is_some() || entrypoint_config.keyword_aliases.is_some() {
+        let current_aliases = search_aliases.get(&key).cloned().unwrap_or_default();
+
+        // kind of aliases which is not specified in config is kept
+        let aliases = |keyword: bool, config: &Option<Vec<String>>| {
+            match config {
+                None => {
+                    current_aliases
+                        .iter()
+                        .filter(|alias| alias.keyword == keyword)
+                        .cloned()
+                        .collect::<Vec<_>>()
+                }
+                Some(config) => {
+                    config
+                        .iter()
+                        .map(|alias| {
+                            EntrypointSearchAlias {
+                                alias: alias.clone(),
+                                keyword,
+                            }
+                        })
+                        .collect()
+                }
+            }
+        };
+
+        let mut new_aliases = aliases(false, &entrypoint_config.aliases);
+        new_aliases.extend(aliases(true, &entrypoint_config.keyword_aliases));
+
+        if !same_aliases(&current_aliases, &new_aliases) {
+            plan.changes.push(PluginSyncChange::SetSearchAliases {
+                plugin_id: plugin_id.clone(),
+                entrypoint_id: entrypoint_id.clone(),
+                aliases: new_aliases,
+            });
+        }
+    }
+
+    if let Some(shortcut) = &entrypoint_config.shortcut {
+        match parse_shortcut(shortcut) {
+            Ok(shortcut) => {
+                if global_shortcuts.get(&key) != Some(&shortcut) {
+                    plan.changes.push(PluginSyncChange::SetGlobalShortcut {
+                        plugin_id: plugin_id.clone(),
+                        entrypoint_id: entrypoint_id.clone(),
+                        shortcut,
+                    });
+                }
+            }
+            Err(err) => {
+                plan.warnings.push(format!(
+                    "Shortcut of entrypoint {} of plugin {} specified in config is invalid: {:#}",
+                    entrypoint_id, plugin_id, err
+                ));
+            }
+        }
+    }
+}
+
+fn same_aliases(left: &[EntrypointSearchAlias], right: &[EntrypointSearchAlias]) -> bool {
+    let sorted = |aliases: &[EntrypointSearchAlias]| {
+        aliases
+            .iter()
+            .map(|alias| (alias.keyword, alias.alias.clone()))
+            .sorted()
+            .collect::<Vec<_>>()
+    };
+
+    sorted(left) == sorted(right)
+}
+
+fn preference_value(
+    preference: &DbPluginPreference,
+    value: &PreferenceValueConfig,
+) -> anyhow::Result<PluginPreferenceUserData> {
+    let value = match (preference, value) {
+        (DbPluginPreference::Number { .. }, PreferenceValueConfig::Number(value)) => {
+            PluginPreferenceUserData::Number { value: Some(*value) }
+        }
+        (DbPluginPreference::String { .. }, PreferenceValueConfig::String(value)) => {
+            PluginPreferenceUserData::String {
+                value: Some(value.clone()),
+            }
+        }
+        (DbPluginPreference::Enum { enum_values, .. }, PreferenceValueConfig::String(value)) => {
+            check_enum_value(enum_values, value)?;
+
+            PluginPreferenceUserData::Enum {
+                value: Some(value.clone()),
+            }
+        }
+        (DbPluginPreference::Bool { .. }, PreferenceValueConfig::Bool(value)) => {
+            PluginPreferenceUserData::Bool { value: Some(*value) }
+        }
+        (DbPluginPreference::ListOfStrings { .. }, PreferenceValueConfig::ListOfStrings(value)) => {
+            PluginPreferenceUserData::ListOfStrings {
+                value: Some(value.clone()),
+            }
+        }
+        (DbPluginPreference::ListOfNumbers { .. }, PreferenceValueConfig::ListOfNumbers(value)) => {
+            PluginPreferenceUserData::ListOfNumbers {
+                value: Some(value.clone()),
+            }
+        }
+        (DbPluginPreference::ListOfEnums { enum_values, .. }, PreferenceValueConfig::ListOfStrings(value)) => {
+            for value in value {
+                check_enum_value(enum_values, value)?;
+            }
+
+            PluginPreferenceUserData::ListOfEnums {
+                value: Some(value.clone()),
+            }
+        }
+        // empty list is parsed as list of numbers
+        (DbPluginPreference::ListOfStrings { .. }, PreferenceValueConfig::ListOfNumbers(value)) if value.is_empty() => {
+            PluginPreferenceUserData::ListOfStrings { value: Some(vec![]) }
+        }
+        (DbPluginPreference::ListOfEnums { .. }, PreferenceValueConfig::ListOfNumbers(value)) if value.is_empty() => {
+            PluginPreferenceUserData::ListOfEnums { value: Some(vec![]) }
+        }
+        _ => return Err(anyhow!("value has a type different from the one declared by plugin")),
+    };
+
+    Ok(value)
+}
+
+fn check_enum_value(enum_values: &[DbPreferenceEnumValue], value: &str) -> anyhow::Result<()> {
+    if enum_values.iter().any(|enum_value| enum_value.value == value) {
+        Ok(())
+    } else {
+        let allowed = enum_values
+            .iter()
+            .map(|enum_value| enum_value.value.as_str())
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        Err(anyhow!("'{}' is not one of allowed values: {}", value, allowed))
+    }
+}
+
+/// Parses shortcut in format "Control+Shift+KeyK", key name is the same as in plugin manifest
+pub fn parse_shortcut(value: &str) -> anyhow::Result<PhysicalShortcut> {
+    let mut parts = value.split('+').map(|part| part.trim()).collect::<Vec<_>>();
+
+    let key = parts
+        .pop()
+        .filter(|key| !key.is_empty())
+        .ok_or_else(|| anyhow!("shortcut '{}' doesn't have a key", value))?;
+
+    let physical_key =
+        PhysicalKey::try_from_value(key).ok_or_else(|| anyhow!("unknown key '{}' in shortcut '{}'", key, value))?;
+
+    let mut shortcut = PhysicalShortcut {
+        physical_key,
+        modifier_shift: false,
+        modifier_control: false,
+        modifier_alt: false,
+        modifier_meta: false,
+    };
+
+    for modifier in parts {
+        match modifier.to_lowercase().as_str() {
+            "shift" => shortcut.modifier_shift = true,
+            "control" | "ctrl" => shortcut.modifier_control = true,
+            "alt" | "option" => shortcut.modifier_alt = true,
+            "meta" | "super" | "cmd" | "command" => shortcut.modifier_meta = true,
+            _ => return Err(anyhow!("unknown modifier '{}' in shortcut '{}'", modifier, value)),
+        }
+    }
+
+    Ok(shortcut)
+}
+
+fn preference_value_label(value: &PluginPreferenceUserData) -> String {
+    fn label(value: &Option<impl Debug>) -> String {
+        match value {
+            None => "none".to_string(),
+            Some(value) => format!("{:?}", value),
+        }
+    }
+
+    match value {
+        PluginPreferenceUserData::Number { value } => label(value),
+        PluginPreferenceUserData::String { value } => label(value),
+        PluginPreferenceUserData::Enum { value } => label(value),
+        PluginPreferenceUserData::Bool { value } => label(value),
+        PluginPreferenceUserData::ListOfStrings { value } => label(value),
+        PluginPreferenceUserData::ListOfNumbers { value } => label(value),
+        PluginPreferenceUserData::ListOfEnums { value } => label(value),
+    }
+}
+
+fn enabled_label(enabled: bool) -> &'static str {
+    if enabled { "enable" } else { "disable" }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::plugins::data_db_repository::DbCode;
+
+    const PLUGIN_ID: &str = "https://github.com/project-gauntlet/plugin-template";
+
+    fn plugin(enabled: bool, git_ref: Option<&str>) -> DbReadPlugin {
+        DbReadPlugin {
+            id: PLUGIN_ID.to_string(),
+            uuid: "plugin-uuid".to_string(),
+            name: "Plugin".to_string(),
+            description: "".to_string(),
+            enabled,
+            code: DbCode { js: HashMap::new() },
+            permissions: serde_json::from_value(serde_json::json!({})).unwrap(),
+            plugin_type: "normal".to_string(),
+            preferences: HashMap::from([(
+                "count".to_string(),
+                DbPluginPreference::Number {
+                    name: None,
+                    default: None,
+                    description: "".to_string(),
+                },
+            )]),
+            preferences_user_data: HashMap::from([(
+                "count".to_string(),
+                DbPluginPreferenceUserData::Number { value: Some(1.0) },
+            )]),
+            installed_commit: None,
+            git_ref: git_ref.map(|git_ref| git_ref.to_string()),
+            archive_sha256: None,
+            signature: None,
+        }
+    }
+
+    fn entrypoint(enabled: bool) -> DbReadPluginEntrypoint {
+        DbReadPluginEntrypoint {
+            id: "main".to_string(),
+            uuid: "entrypoint-uuid".to_string(),
+            plugin_id: PLUGIN_ID.to_string(),
+            name: "Main".to_string(),
+            description: "".to_string(),
+            enabled,
+            icon_path: None,
+            entrypoint_type: "command".to_string(),
+            preferences: HashMap::new(),
+            preferences_user_data: HashMap::new(),
+            actions: vec![],
+            actions_user_data: vec![],
+            keywords: vec![],
+        }
+    }
+
+    fn plan(
+        config: &str,
+        remove_unlisted: bool,
+        installed_plugins: Vec<(DbReadPlugin, Vec<DbReadPluginEntrypoint>)>,
+        search_aliases: &HashMap<(PluginId, EntrypointId), Vec<EntrypointSearchAlias>>,
+        global_shortcuts: &HashMap<(PluginId, EntrypointId), PhysicalShortcut>,
+    ) -> (Vec<String>, usize) {
+        #[derive(serde::Deserialize)]
+        struct Config {
+            #[serde(default)]
+            plugins: Vec<PluginConfig>,
+        }
+
+        let config: Config = toml::from_str(config).unwrap();
+
+        let plan = plan_plugin_sync(
+            &config.plugins,
+            remove_unlisted,
+            installed_plugins,
+            search_aliases,
+            global_shortcuts,
+        );
+
+        let changes = plan.changes.iter().map(|change| change.to_string()).collect();
+
+        (changes, plan.warnings.len())
+    }
+
+    fn key() -> (PluginId, EntrypointId) {
+        (PluginId::from_string(PLUGIN_ID), EntrypointId::from_string("main"))
+    }
+
+    #[test]
+    fn plan_plugin_sync_cases() {
+        let shortcut = parse_shortcut("Control+KeyK").unwrap();
+        let aliases = vec![
+            EntrypointSearchAlias {
+                alias: "old".to_string(),
+                keyword: false,
+            },
+            EntrypointSearchAlias {
+                alias: "kw".to_string(),
+                keyword: true,
+            },
+        ];
+
+        let cases: Vec<(&str, String, bool, bool, Vec<String>, usize)> = vec![
+            ("nothing configured", "".to_string(), false, true, vec![], 0),
+            (
+                "unlisted plugin is removed",
+                "".to_string(),
+                true,
+                true,
+                vec![format!("- remove plugin {}", PLUGIN_ID)],
+                0,
+            ),
+            (
+                "not installed plugin is installed",
+                format!("[[plugins]]\nid = \"{}\"", PLUGIN_ID),
+                false,
+                false,
+                vec![format!("+ install plugin {} and apply its settings", PLUGIN_ID)],
+                0,
+            ),
+            (
+                "local plugin which is not installed cannot be downloaded",
+                "[[plugins]]\nid = \"file:///home/user/plugin\"".to_string(),
+                false,
+                false,
+                vec![],
+                1,
+            ),
+            (
+                "installed plugin matching config has no changes",
+                format!(
+                    "[[plugins]]\nid = \"{}\"\nenabled = true\npreferences = {{ count = 1.0 }}",
+                    PLUGIN_ID
+                ),
+                true,
+                true,
+                vec![],
+                0,
+            ),
+            (
+                "enabled state and preference are changed",
+                format!(
+                    "[[plugins]]\nid = \"{}\"\nenabled = false\npreferences = {{ count = 2.0 }}",
+                    PLUGIN_ID
+                ),
+                false,
+                true,
+                vec![
+                    format!("~ disable plugin {}", PLUGIN_ID),
+                    format!("~ set preference count of plugin {} to 2.0", PLUGIN_ID),
+                ],
+                0,
+            ),
+            (
+                "unknown preference, entrypoint and invalid value are warnings",
+                format!(
+                    "[[plugins]]\nid = \"{}\"\npreferences = {{ count = \"two\", missing = 1 }}\n[plugins.entrypoints.missing]\nenabled = true",
+                    PLUGIN_ID
+                ),
+                false,
+                true,
+                vec![],
+                3,
+            ),
+            (
+                "changed ref switches plugin in place",
+                format!("[[plugins]]\nid = \"{}#v2\"", PLUGIN_ID),
+                false,
+                true,
+                vec![format!(
+                    "~ download plugin {} from v2 and apply its settings",
+                    PLUGIN_ID
+                )],
+                0,
+            ),
+            (
+                "ref can be specified in separate field",
+                format!("[[plugins]]\nid = \"{}\"\nref = \"v1\"", PLUGIN_ID),
+                false,
+                true,
+                vec![format!(
+                    "~ download plugin {} from v1 and apply its settings",
+                    PLUGIN_ID
+                )],
+                0,
+            ),
+            (
+                "entrypoint settings are changed",
+                format!(
+                    "[[plugins]]\nid = \"{}\"\n[plugins.entrypoints.main]\nenabled = false\naliases = [\"new\"]\nshortcut = \"Shift+KeyK\"",
+                    PLUGIN_ID
+                ),
+                false,
+                true,
+                vec![
+                    format!("~ disable entrypoint main of plugin {}", PLUGIN_ID),
+                    format!(
+                        "~ set aliases of entrypoint main of plugin {} to [new, kw (keyword)]",
+                        PLUGIN_ID
+                    ),
+                    format!(
+                        "~ set global shortcut of entrypoint main of plugin {} to Shift+KeyK",
+                        PLUGIN_ID
+                    ),
+                ],
+                0,
+            ),
+            (
+                "entrypoint settings matching config have no changes",
+                format!(
+                    "[[plugins]]\nid = \"{}\"\n[plugins.entrypoints.main]\nkeyword_aliases = [\"kw\"]\nshortcut = \"Ctrl+KeyK\"",
+                    PLUGIN_ID
+                ),
+                false,
+                true,
+                vec![],
+                0,
+            ),
+            (
+                "invalid shortcut is a warning",
+                format!(
+                    "[[plugins]]\nid = \"{}\"\n[plugins.entrypoints.main]\nshortcut = \"Control+Unknown\"",
+                    PLUGIN_ID
+                ),
+                false,
+                true,
+                vec![],
+                1,
+            ),
+        ];
+
+        let search_aliases = HashMap::from([(key(), aliases)]);
+        let global_shortcuts = HashMap::from([(key(), shortcut)]);
+
+        for (name, config, remove_unlisted, installed, expected_changes, expected_warnings) in cases {
+            let installed_plugins = if installed {
+                vec![(plugin(true, None), vec![entrypoint(true)])]
+            } else {
+                vec![]
+            };
+
+            let (changes, warnings) = plan(
+                &config,
+                remove_unlisted,
+                installed_plugins,
+                &search_aliases,
+                &global_shortcuts,
+            );
+
+            assert_eq!(changes, expected_changes, "{}", name);
+            assert_eq!(warnings, expected_warnings, "{}", name);
+        }
+    }
+
+    #[test]
+    fn plan_plugin_sync_keeps_installed_ref() {
+        let (changes, warnings) = plan(
+            &format!("[[plugins]]\nid = \"{}#v1\"", PLUGIN_ID),
+            false,
+            vec![(plugin(true, Some("v1")), vec![entrypoint(true)])],
+            &HashMap::new(),
+            &HashMap::new(),
+        );
+
+        assert_eq!(changes, Vec::<String>::new());
+        assert_eq!(warnings, 0);
+    }
+
+    #[test]
+    fn plan_plugin_sync_switches_archive_with_different_hash() {
+        let archive_id = "https://example.com/plugin.tar.gz";
+
+        let installed_plugins = || {
+            let mut plugin = plugin(true, None);
+            plugin.id = archive_id.to_string();
+            plugin.archive_sha256 = Some("aaaa".to_string());
+
+            vec![(plugin, vec![])]
+        };
+
+        let (changes, _) = plan(
+            &format!("[[plugins]]\nid = \"{}#sha256=bbbb\"", archive_id),
+            true,
+            installed_plugins(),
+            &HashMap::new(),
+            &HashMap::new(),
+        );
+
+        assert_eq!(
+            changes,
+            vec![format!(
+                "~ download plugin {} with hash bbbb and apply its settings",
+                archive_id
+            )]
+        );
+
+        let (changes, _) = plan(
+            &format!("[[plugins]]\nid = \"{}\"\nsha256 = \"aaaa\"", archive_id),
+            true,
+            installed_plugins(),
+            &HashMap::new(),
+            &HashMap::new(),
+        );
+
+        assert_eq!(changes, Vec::<String>::new());
+    }
+
+    #[test]
+    fn parse_shortcut_cases() {
+        let shortcut = |physical_key, modifier_shift, modifier_control, modifier_alt, modifier_meta| {
+            PhysicalShortcut {
+                physical_key,
+                modifier_shift,
+                modifier_control,
+                modifier_alt,
+                modifier_meta,
+            }
+        };
+
+        let cases = vec![
+            ("KeyK", Some(shortcut(PhysicalKey::KeyK, false, false, false, false))),
+            (
+                "Control+Shift+KeyK",
+                Some(shortcut(PhysicalKey::KeyK, true, true, false, false)),
+            ),
+            (
+                "ctrl + alt + Space",
+                Some(shortcut(PhysicalKey::Space, false, true, true, false)),
+            ),
+            (
+                "Cmd+Option+KeyK",
+                Some(shortcut(PhysicalKey::KeyK, false, false, true, true)),
+            ),
+            (
+                "Super+KeyK",
+                Some(shortcut(PhysicalKey::KeyK, false, false, false, true)),
+            ),
+            ("", None),
+            ("Control+", None),
+            ("Control+K", None),
+            ("Hyper+KeyK", None),
+        ];
+
+        for (value, expected) in cases {
+            assert_eq!(parse_shortcut(value).ok(), expected, "{}", value);
+        }
+    }
+
+    #[test]
+    fn shortcut_label_can_be_parsed() {
+        let shortcut = parse_shortcut("Shift+Alt+Control+Meta+KeyK").unwrap();
+
+        assert_eq!(shortcut.label(), "Meta+Control+Alt+Shift+KeyK");
+        assert_eq!(parse_shortcut(&shortcut.label()).unwrap(), shortcut);
+    }
+
+    #[test]
+    fn preference_value_cases() {
+        let enum_values = || {
+            vec![
+                DbPreferenceEnumValue {
+                    label: "First".to_string(),
+                    value: "first".to_string(),
+                },
+                DbPreferenceEnumValue {
+                    label: "Second".to_string(),
+                    value: "second".to_string(),
+                },
+            ]
+        };
+
+        let number = DbPluginPreference::Number {
+            name: None,
+            default: None,
+            description: "".to_string(),
+        };
+        let string = DbPluginPreference::String {
+            name: None,
+            default: None,
+            description: "".to_string(),
+        };
+        let enum_preference = DbPluginPreference::Enum {
+            name: None,
+            default: None,
+            description: "".to_string(),
+            enum_values: enum_values(),
+        };
+        let bool_preference = DbPluginPreference::Bool {
+            name: None,
+            default: None,
+            description: "".to_string(),
+        };
+        let list_of_strings = DbPluginPreference::ListOfStrings {
+            name: None,
+            default: None,
+            description: "".to_string(),
+        };
+        let list_of_numbers = DbPluginPreference::ListOfNumbers {
+            name: None,
+            default: None,
+            description: "".to_string(),
+        };
+        let list_of_enums = DbPluginPreference::ListOfEnums {
+            name: None,
+            default: None,
+            description: "".to_string(),
+            enum_values: enum_values(),
+        };
+
+        let cases = vec![
+            (
+                &number,
+                PreferenceValueConfig::Number(1.5),
+                Some(PluginPreferenceUserData::Number { value: Some(1.5) }),
+            ),
+            (&number, PreferenceValueConfig::String("1.5".to_string()), None),
+            (
+                &string,
+                PreferenceValueConfig::String("text".to_string()),
+                Some(PluginPreferenceUserData::String {
+                    value: Some("text".to_string()),
+                }),
+            ),
+            (&string, PreferenceValueConfig::Bool(true), None),
+            (
+                &enum_preference,
+                PreferenceValueConfig::String("second".to_string()),
+                Some(PluginPreferenceUserData::Enum {
+                    value: Some("second".to_string()),
+                }),
+            ),
+            (
+                &enum_preference,
+                PreferenceValueConfig::String("third".to_string()),
+                None,
+            ),
+            (
+                &bool_preference,
+                PreferenceValueConfig::Bool(false),
+                Some(PluginPreferenceUserData::Bool { value: Some(false) }),
+            ),
+            (&bool_preference, PreferenceValueConfig::Number(0.0), None),
+            (
+                &list_of_strings,
+                PreferenceValueConfig::ListOfStrings(vec!["a".to_string()]),
+                Some(PluginPreferenceUserData::ListOfStrings {
+                    value: Some(vec!["a".to_string()]),
+                }),
+            ),
+            (
+                &list_of_strings,
+                PreferenceValueConfig::ListOfNumbers(vec![]),
+                Some(PluginPreferenceUserData::ListOfStrings { value: Some(vec![]) }),
+            ),
+            (&list_of_strings, PreferenceValueConfig::ListOfNumbers(vec![1.0]), None),
+            (
+                &list_of_numbers,
+                PreferenceValueConfig::ListOfNumbers(vec![1.0, 2.0]),
+                Some(PluginPreferenceUserData::ListOfNumbers {
+                    value: Some(vec![1.0, 2.0]),
+                }),
+            ),
+            (
+                &list_of_enums,
+                PreferenceValueConfig::ListOfStrings(vec!["first".to_string(), "second".to_string()]),
+                Some(PluginPreferenceUserData::ListOfEnums {
+                    value: Some(vec!["first".to_string(), "second".to_string()]),
+                }),
+            ),
+            (
+                &list_of_enums,
+                PreferenceValueConfig::ListOfNumbers(vec![]),
+                Some(PluginPreferenceUserData::ListOfEnums { value: Some(vec![]) }),
+            ),
+            (
+                &list_of_enums,
+                PreferenceValueConfig::ListOfStrings(vec!["first".to_string(), "third".to_string()]),
+                None,
+            ),
+        ];
+
+        for (preference, value, expected) in cases {
+            assert_eq!(
+                preference_value(preference, &value).ok(),
+                expected,
+                "{:?} {:?}",
+                preference,
+                value
+            );
+        }
+    }
+}
@@ -11,18 +11,35 @@ use gauntlet_common::dirs::Dirs;
 use gauntlet_common::model::PhysicalKey;
 use gauntlet_common::model::PhysicalShortcut;
 use gauntlet_utils_macros::RusqliteFromRow;
+use itertools::Itertools;
 use rusqlite::Connection;
 use rusqlite::OptionalExtension;
 use rusqlite::Row;
+use rusqlite::Transaction;
 use rusqlite::named_params;
 use serde::Deserialize;
 use serde::Serialize;
+use sha2::Digest;
+use sha2::Sha256;
 use uuid::Uuid;
 
 use crate::model::ActionShortcutKey;
 use crate::plugins::data_db_repository::migrations::setup_migrator;
 use crate::plugins::frecency::FrecencyItemStats;
 use crate::plugins::frecency::FrecencyMetaParams;
+use crate::plugins::frecency::current_time_secs;
+
+// raw frecency values grow exponentially with time since reference time, so it is moved forward from time to time
+const FRECENCY_RENORMALIZE_AFTER_HALF_LIVES: f64 = 5.0;
+
+// query prefix which wasn't used for this long has negligible effect on ranking,
+// without removing them every typed query would stay in the table forever
+const QUERY_FRECENCY_RETENTION_HALF_LIVES: f64 = 10.0;
+
+const FRECENCY_TABLES: [&str; 2] = [
+    "plugin_entrypoint_frecency_stats",
+    "plugin_entrypoint_query_frecency_stats",
+];
 
 #[derive(Clone)]
 pub struct DataDbRepository {
@@ -46,6 +63,15 @@ pub struct DbReadPlugin {
     pub preferences: HashMap<String, DbPluginPreference>,
     #[rusqlite(json)]
     pub preferences_user_data: HashMap<String, DbPluginPreferenceUserData>,
+    // commit git ref of the plugin was resolved to when plugin was downloaded, none for local and bundled plugins
+    pub installed_commit: Option<String>,
+    // branch, tag or commit plugin was downloaded from, none if release branch is used
+    pub git_ref: Option<String>,
+    // hash of archive plugin was installed from, none if plugin is not installed from archive or hash was not specified
+    pub archive_sha256: Option<String>,
+    // result of signature verification when plugin was downloaded, none for local and bundled plugins
+    #[rusqlite(json)]
+    pub signature: Option<DbPluginSignature>,
 }
 
 #[derive(RusqliteFromRow)]
@@ -67,6 +93,8 @@ pub struct DbReadPluginEntrypoint {
     pub actions: Vec<DbPluginAction>,
     #[rusqlite(json)]
     pub actions_user_data: Vec<DbPluginActionUserData>,
+    #[rusqlite(json)]
+    pub keywords: Vec<String>,
 }
 
 #[derive(Deserialize, Serialize)]
@@ -74,6 +102,24 @@ pub struct DbCode {
     pub js: HashMap<String, String>,
 }
 
+impl DbCode {
+    /// Changes every time plugin code changes, e.g. when a new version of plugin is downloaded
+    pub fn version(&self) -> String {
+        // stored alongside cached data, so it has to be stable across gauntlet versions
+        let mut hasher = Sha256::new();
+
+        for (path, code) in self.js.iter().sorted() {
+            // length prefix keeps boundaries between path and code unambiguous
+            hasher.update((path.len() as u64).to_le_bytes());
+            hasher.update(path.as_bytes());
+            hasher.update((code.len() as u64).to_le_bytes());
+            hasher.update(code.as_bytes());
+        }
+
+        format!("{:x}", hasher.finalize())
+    }
+}
+
 pub struct DbWritePlugin {
     pub id: String,
     pub name: String,
@@ -85,6 +131,10 @@ pub struct DbWritePlugin {
     pub permissions: DbPluginPermissions,
     pub plugin_type: String,
     pub preferences: HashMap<String, DbPluginPreference>,
+    pub installed_commit: Option<String>,
+    pub git_ref: Option<String>,
+    pub archive_sha256: Option<String>,
+    pub signature: Option<DbPluginSignature>,
 }
 
 pub struct DbWritePluginEntrypoint {
@@ -95,6 +145,7 @@ pub struct DbWritePluginEntrypoint {
     pub entrypoint_type: String,
     pub preferences: HashMap<String, DbPluginPreference>,
     pub actions: Vec<DbPluginAction>,
+    pub keywords: Vec<String>,
 }
 
 pub struct DbWritePluginAssetData {
@@ -110,6 +161,19 @@ pub enum DbPluginEntrypointType {
     EntrypointGenerator,
 }
 
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
+#[serde(tag = "type")]
+pub enum DbPluginSignature {
+    // signed by this key from the list of trusted keys in config
+    #[serde(rename = "signed")]
+    Signed { public_key: String },
+    // signature is present, but it isn't made by any of trusted keys
+    #[serde(rename = "untrusted")]
+    Untrusted,
+    #[serde(rename = "unsigned")]
+    Unsigned,
+}
+
 #[derive(Debug, Clone)]
 pub enum DbPluginType {
     Normal,
@@ -238,6 +302,20 @@ pub struct DbSettingsEntrypointSearchAliasData {
     pub plugin_id: String,
     pub entrypoint_id: String,
     pub alias: String,
+    #[serde(default)]
+    pub keyword: bool,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct DbSettingsPinnedEntrypointData {
+    pub plugin_id: String,
+    pub entrypoint_id: String,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct DbSettingsPluginSearchScopeData {
+    pub plugin_id: String,
+    pub keyword: String,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -249,7 +327,19 @@ pub struct DbSettings {
     // none is unset, if whole settings object is unset, it is likely a first start and default shortcut will be used
     pub global_shortcut: Option<DbSettingsGlobalShortcutData>,
     pub global_entrypoint_shortcuts: Option<Vec<DbSettingsGlobalEntrypointShortcutData>>,
+    // entrypoint can have multiple aliases, order of the list is the order in which they are shown
     pub entrypoint_search_aliases: Option<Vec<DbSettingsEntrypointSearchAliasData>>,
+    // none is disabled
+    pub fuzzy_search: Option<bool>,
+    // none is default of 3 days
+    pub frecency_half_life_days: Option<u32>,
+    // order of the list is the order in which pinned entrypoints are shown
+    pub pinned_entrypoints: Option<Vec<DbSettingsPinnedEntrypointData>>,
+    pub plugin_search_scopes: Option<Vec<DbSettingsPluginSearchScopeData>>,
+    // none is default of 30 days
+    pub run_history_retention_days: Option<u32>,
+    // none is default of 5 entrypoints
+    pub recent_search_results_limit: Option<u32>,
 }
 
 impl Default for DbSettings {
@@ -281,6 +371,12 @@ impl Default for DbSettings {
             }),
             global_entrypoint_shortcuts: None,
             entrypoint_search_aliases: None,
+            fuzzy_search: None,
+            frecency_half_life_days: None,
+            pinned_entrypoints: None,
+            plugin_search_scopes: None,
+            run_history_retention_days: None,
+            recent_search_results_limit: None,
         }
     }
 }
@@ -366,9 +462,7 @@ pub struct DbPreferenceEnumValue {
 
 #[derive(RusqliteFromRow)]
 pub struct DbPluginEntrypointFrecencyStats {
-    #[allow(unused)]
     pub plugin_id: String,
-    #[allow(unused)]
     pub entrypoint_id: String,
 
     pub reference_time: f64,
@@ -378,9 +472,47 @@ pub struct DbPluginEntrypointFrecencyStats {
     pub num_accesses: i32,
 }
 
+#[derive(RusqliteFromRow)]
+pub struct DbEntrypointRunHistoryItem {
+    pub plugin_id: String,
+    pub entrypoint_id: String,
+    // seconds since unix epoch
+    pub run_at: f64,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct DbFrecencyExport {
+    pub entrypoints: Vec<DbFrecencyExportItem>,
+    pub queries: Vec<DbFrecencyExportItem>,
+}
+
+#[derive(Debug, Deserialize, Serialize, RusqliteFromRow)]
+pub struct DbFrecencyExportItem {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub query_prefix: Option<String>,
+    pub plugin_id: String,
+    pub entrypoint_id: String,
+    pub reference_time: f64,
+    pub half_life: f64,
+    pub last_accessed: f64,
+    pub frecency: f64,
+    pub num_accesses: i32,
+}
+
 const SETTINGS_DATA_ID: &str = "settings_data"; // only one row in the table
 
 impl DataDbRepository {
+    #[cfg(test)]
+    pub fn new_in_memory() -> anyhow::Result<Self> {
+        let mut connection = Connection::open_in_memory()?;
+
+        setup_migrator().to_latest(&mut connection)?;
+
+        Ok(Self {
+            connection: Arc::new(Mutex::new(connection)),
+        })
+    }
+
     pub fn new(dirs: Dirs) -> anyhow::Result<Self> {
         let data_db_file = dirs.data_db_file()?;
 
@@ -397,6 +529,14 @@ impl DataDbRepository {
         })
     }
 
+    pub fn flush(&self) -> anyhow::Result<()> {
+        let connection = self.connection.lock().map_err(|_| anyhow!("lock is poisoned"))?;
+
+        connection.cache_flush()?;
+
+        Ok(())
+    }
+
     pub fn list_plugins(&self) -> anyhow::Result<Vec<DbReadPlugin>> {
         // language=SQLite
         let query = "SELECT * FROM plugin";
@@ -874,14 +1014,17 @@ impl DataDbRepository {
         Ok(result)
     }
 
-    pub fn mark_entrypoint_frecency(&self, plugin_id: &str, entrypoint_id: &str) -> anyhow::Result<()> {
+    /// `query_prefixes` are prefixes of search query which was used to find the entrypoint, if any
+    pub fn mark_entrypoint_frecency(
+        &self,
+        plugin_id: &str,
+        entrypoint_id: &str,
+        query_prefixes: &[String],
+        half_life: f64,
+    ) -> anyhow::Result<()> {
         let mut connection = self.connection.lock().map_err(|_| anyhow!("lock is poisoned"))?;
         let tx = connection.transaction()?;
 
-        // TODO reset time after 5 half lives
-        //  https://github.com/camdencheek/fre/blob/6574ee7045061957de24855567e0abf05f2778d9/src/main.rs#L23
-        //  why? dunno
-
         #[derive(RusqliteFromRow)]
         struct DbFrecencyMetaParams {
             pub reference_time: f64,
@@ -894,11 +1037,22 @@ impl DataDbRepository {
         let meta_params = tx.query_row(query, [], DbFrecencyMetaParams::from_row).optional()?;
 
         let meta_params = match meta_params {
-            None => FrecencyMetaParams::default(),
+            None => FrecencyMetaParams::new(half_life),
             Some(meta_params) => {
-                FrecencyMetaParams {
-                    reference_time: meta_params.reference_time,
-                    half_life: meta_params.half_life,
+                let half_lives_passed =
+                    FrecencyItemStats::new(meta_params.reference_time, meta_params.half_life).half_lives_passed();
+
+                if half_lives_passed > FRECENCY_RENORMALIZE_AFTER_HALF_LIVES {
+                    let meta_params = FrecencyMetaParams::new(meta_params.half_life);
+
+                    renormalize_frecency(&tx, &meta_params)?;
+
+                    meta_params
+                } else {
+                    FrecencyMetaParams {
+                        reference_time: meta_params.reference_time,
+                        half_life: meta_params.half_life,
+                    }
                 }
             }
         };
@@ -964,22 +1118,92 @@ impl DataDbRepository {
             },
         )?;
 
+        for query_prefix in query_prefixes {
+            // language=SQLite
+            let query = r#"
+                SELECT plugin_id, entrypoint_id, reference_time, half_life, last_accessed, frecency, num_accesses
+                FROM plugin_entrypoint_query_frecency_stats
+                WHERE query_prefix = :query_prefix
+                    and plugin_id = :plugin_id
+                    and entrypoint_id = :entrypoint_id
+            "#;
+
+            let stats = tx
+                .query_row(
+                    query,
+                    named_params! {
+                        ":query_prefix": query_prefix,
+                        ":plugin_id": plugin_id,
+                        ":entrypoint_id": entrypoint_id,
+                    },
+                    DbPluginEntrypointFrecencyStats::from_row,
+                )
+                .optional()?;
+
+            let mut new_stats = match stats {
+                None => FrecencyItemStats::new(meta_params.reference_time, meta_params.half_life),
+                Some(stats) => {
+                    FrecencyItemStats {
+                        half_life: stats.half_life,
+                        reference_time: stats.reference_time,
+                        last_accessed: stats.last_accessed,
+                        frecency: stats.frecency,
+                        num_accesses: stats.num_accesses,
+                    }
+                }
+            };
+
+            new_stats.mark_used();
+
+            // language=SQLite
+            let query = r#"
+                INSERT OR REPLACE INTO plugin_entrypoint_query_frecency_stats (query_prefix, plugin_id, entrypoint_id, reference_time, half_life, last_accessed, frecency, num_accesses)
+                    VALUES(
+                        :query_prefix,
+                        :plugin_id,
+                        :entrypoint_id,
+                        :reference_time,
+                        :half_life,
+                        :last_accessed,
+                        :frecency,
+                        :num_accesses
+                    )
+            "#;
+
+            tx.execute(
+                query,
+                named_params! {
+                    ":query_prefix": query_prefix,
+                    ":plugin_id": plugin_id,
+                    ":entrypoint_id": entrypoint_id,
+                    ":reference_time": new_stats.reference_time,
+                    ":half_life": new_stats.half_life,
+                    ":last_accessed": new_stats.last_accessed,
+                    ":frecency": new_stats.frecency,
+                    ":num_accesses": new_stats.num_accesses
+                },
+            )?;
+        }
+
+        remove_stale_query_frecency(&tx)?;
+
         tx.commit()?;
 
         Ok(())
     }
 
+    /// Current frecency of entrypoints of the plugin, keyed by entrypoint id
     pub fn get_frecency_for_plugin(&self, plugin_id: &str) -> anyhow::Result<HashMap<String, f64>> {
         let connection = self.connection.lock().map_err(|_| anyhow!("lock is poisoned"))?;
 
-        #[derive(RusqliteFromRow)]
-        struct DbFrecencyStats {
-            pub entrypoint_id: String,
-            pub frecency: f64,
-        }
-
         // language=SQLite
-        let query = "SELECT entrypoint_id, frecency FROM plugin_entrypoint_frecency_stats WHERE plugin_id = :plugin_id";
+        let query = r#"
+            SELECT plugin_id, entrypoint_id, reference_time, half_life, last_accessed, frecency, num_accesses
+            FROM plugin_entrypoint_frecency_stats
+            WHERE plugin_id = :plugin_id
+        "#;
+
+        let current_time = current_time_secs();
 
         let result = connection
             .prepare(query)?
@@ -987,16 +1211,271 @@ impl DataDbRepository {
                 named_params! {
                     ":plugin_id": plugin_id
                 },
-                DbFrecencyStats::from_row,
+                DbPluginEntrypointFrecencyStats::from_row,
             )?
             .collect::<Result<Vec<_>, _>>()?
             .into_iter()
-            .map(|row| (row.entrypoint_id, row.frecency))
+            .map(|row| {
+                let frecency = frecency_item_stats(&row).get_frecency(current_time);
+
+                (row.entrypoint_id, frecency)
+            })
             .collect::<HashMap<_, _>>();
 
         Ok(result)
     }
 
+    /// Current frecency of entrypoints selected after typing `query_prefix`, keyed by plugin id and entrypoint id
+    pub fn get_query_frecency(&self, query_prefix: &str) -> anyhow::Result<HashMap<(String, String), f64>> {
+        let connection = self.connection.lock().map_err(|_| anyhow!("lock is poisoned"))?;
+
+        // language=SQLite
+        let query = r#"
+            SELECT plugin_id, entrypoint_id, reference_time, half_life, last_accessed, frecency, num_accesses
+            FROM plugin_entrypoint_query_frecency_stats
+            WHERE query_prefix = :query_prefix
+        "#;
+
+        let current_time = current_time_secs();
+
+        let result = connection
+            .prepare(query)?
+            .query_and_then(
+                named_params! {
+                    ":query_prefix": query_prefix
+                },
+                DbPluginEntrypointFrecencyStats::from_row,
+            )?
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .map(|row| {
+                let frecency = frecency_item_stats(&row).get_frecency(current_time);
+
+                ((row.plugin_id, row.entrypoint_id), frecency)
+            })
+            .collect::<HashMap<_, _>>();
+
+        Ok(result)
+    }
+
+    /// Changes half life of all frecency stats, keeping current frecency values
+    pub fn set_frecency_half_life(&self, half_life: f64) -> anyhow::Result<()> {
+        let mut connection = self.connection.lock().map_err(|_| anyhow!("lock is poisoned"))?;
+        let tx = connection.transaction()?;
+
+        renormalize_frecency(&tx, &FrecencyMetaParams::new(half_life))?;
+
+        tx.commit()?;
+
+        Ok(())
+    }
+
+    /// Removes frecency stats of the whole plugin or only of specific entrypoint
+    pub fn reset_frecency(&self, plugin_id: &str, entrypoint_id: Option<&str>) -> anyhow::Result<()> {
+        let mut connection = self.connection.lock().map_err(|_| anyhow!("lock is poisoned"))?;
+        let tx = connection.transaction()?;
+
+        for table in FRECENCY_TABLES {
+            match entrypoint_id {
+                None => {
+                    // language=SQLite
+                    let query = format!("DELETE FROM {} WHERE plugin_id = :plugin_id", table);
+
+                    tx.execute(&query, named_params! { ":plugin_id": plugin_id })?;
+                }
+                Some(entrypoint_id) => {
+                    // language=SQLite
+                    let query = format!(
+                        "DELETE FROM {} WHERE plugin_id = :plugin_id AND entrypoint_id = :entrypoint_id",
+                        table
+                    );
+
+                    tx.execute(
+                        &query,
+                        named_params! {
+                            ":plugin_id": plugin_id,
+                            ":entrypoint_id": entrypoint_id,
+                        },
+                    )?;
+                }
+            }
+        }
+
+        tx.commit()?;
+
+        Ok(())
+    }
+
+    pub fn export_frecency(&self) -> anyhow::Result<DbFrecencyExport> {
+        let connection = self.connection.lock().map_err(|_| anyhow!("lock is poisoned"))?;
+
+        // language=SQLite
+        let query = r#"
+            SELECT NULL as query_prefix, plugin_id, entrypoint_id, reference_time, half_life, last_accessed, frecency, num_accesses
+            FROM plugin_entrypoint_frecency_stats
+        "#;
+
+        let entrypoints = connection
+            .prepare(query)?
+            .query_and_then([], DbFrecencyExportItem::from_row)?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // language=SQLite
+        let query = r#"
+            SELECT query_prefix, plugin_id, entrypoint_id, reference_time, half_life, last_accessed, frecency, num_accesses
+            FROM plugin_entrypoint_query_frecency_stats
+        "#;
+
+        let queries = connection
+            .prepare(query)?
+            .query_and_then([], DbFrecencyExportItem::from_row)?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(DbFrecencyExport { entrypoints, queries })
+    }
+
+    /// Replaces frecency stats of exported entrypoints, stats of entrypoints not present in export are kept
+    pub fn import_frecency(&self, data: DbFrecencyExport, half_life: f64) -> anyhow::Result<()> {
+        let mut connection = self.connection.lock().map_err(|_| anyhow!("lock is poisoned"))?;
+        let tx = connection.transaction()?;
+
+        for item in data.entrypoints {
+            // language=SQLite
+            let query = r#"
+                INSERT OR REPLACE INTO plugin_entrypoint_frecency_stats (plugin_id, entrypoint_id, reference_time, half_life, last_accessed, frecency, num_accesses)
+                    VALUES(:plugin_id, :entrypoint_id, :reference_time, :half_life, :last_accessed, :frecency, :num_accesses)
+            "#;
+
+            tx.execute(
+                query,
+                named_params! {
+                    ":plugin_id": item.plugin_id,
+                    ":entrypoint_id": item.entrypoint_id,
+                    ":reference_time": item.reference_time,
+                    ":half_life": item.half_life,
+                    ":last_accessed": item.last_accessed,
+                    ":frecency": item.frecency,
+                    ":num_accesses": item.num_accesses
+                },
+            )?;
+        }
+
+        for item in data.queries {
+            let Some(query_prefix) = item.query_prefix else {
+                Err(anyhow!("Query frecency item must contain query prefix"))?
+            };
+
+            // language=SQLite
+            let query = r#"
+                INSERT OR REPLACE INTO plugin_entrypoint_query_frecency_stats (query_prefix, plugin_id, entrypoint_id, reference_time, half_life, last_accessed, frecency, num_accesses)
+                    VALUES(:query_prefix, :plugin_id, :entrypoint_id, :reference_time, :half_life, :last_accessed, :frecency, :num_accesses)
+            "#;
+
+            tx.execute(
+                query,
+                named_params! {
+                    ":query_prefix": query_prefix,
+                    ":plugin_id": item.plugin_id,
+                    ":entrypoint_id": item.entrypoint_id,
+                    ":reference_time": item.reference_time,
+                    ":half_life": item.half_life,
+                    ":last_accessed": item.last_accessed,
+                    ":frecency": item.frecency,
+                    ":num_accesses": item.num_accesses
+                },
+            )?;
+        }
+
+        // imported stats were calculated relative to reference time of other machine
+        renormalize_frecency(&tx, &FrecencyMetaParams::new(half_life))?;
+
+        tx.commit()?;
+
+        Ok(())
+    }
+
+    /// Records that entrypoint was run and removes history older than `retention` seconds
+    pub fn add_entrypoint_run_history(
+        &self,
+        plugin_id: &str,
+        entrypoint_id: &str,
+        retention: f64,
+    ) -> anyhow::Result<()> {
+        let mut connection = self.connection.lock().map_err(|_| anyhow!("lock is poisoned"))?;
+        let tx = connection.transaction()?;
+
+        let current_time = current_time_secs();
+
+        // language=SQLite
+        let query = r#"
+            INSERT INTO plugin_entrypoint_run_history (plugin_id, entrypoint_id, run_at)
+                VALUES(:plugin_id, :entrypoint_id, :run_at)
+        "#;
+
+        tx.execute(
+            query,
+            named_params! {
+                ":plugin_id": plugin_id,
+                ":entrypoint_id": entrypoint_id,
+                ":run_at": current_time,
+            },
+        )?;
+
+        remove_old_entrypoint_run_history(&tx, current_time - retention)?;
+
+        tx.commit()?;
+
+        Ok(())
+    }
+
+    /// Most recently run distinct entrypoints with the time they were last run, most recent first
+    pub fn get_entrypoint_run_history(&self, retention: f64) -> anyhow::Result<Vec<DbEntrypointRunHistoryItem>> {
+        let connection = self.connection.lock().map_err(|_| anyhow!("lock is poisoned"))?;
+
+        // language=SQLite
+        let query = r#"
+            SELECT plugin_id, entrypoint_id, MAX(run_at) AS run_at
+            FROM plugin_entrypoint_run_history
+            WHERE run_at >= :min_run_at
+            GROUP BY plugin_id, entrypoint_id
+            ORDER BY run_at DESC
+        "#;
+
+        let result = connection
+            .prepare(query)?
+            .query_and_then(
+                named_params! {
+                    ":min_run_at": current_time_secs() - retention
+                },
+                DbEntrypointRunHistoryItem::from_row,
+            )?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(result)
+    }
+
+    pub fn set_entrypoint_run_history_retention(&self, retention: f64) -> anyhow::Result<()> {
+        let mut connection = self.connection.lock().map_err(|_| anyhow!("lock is poisoned"))?;
+        let tx = connection.transaction()?;
+
+        remove_old_entrypoint_run_history(&tx, current_time_secs() - retention)?;
+
+        tx.commit()?;
+
+        Ok(())
+    }
+
+    pub fn clear_entrypoint_run_history(&self) -> anyhow::Result<()> {
+        let connection = self.connection.lock().map_err(|_| anyhow!("lock is poisoned"))?;
+
+        // language=SQLite
+        let query = "DELETE FROM plugin_entrypoint_run_history";
+
+        connection.execute(query, [])?;
+
+        Ok(())
+    }
+
     pub fn set_plugin_enabled(&self, plugin_id: &str, enabled: bool) -> anyhow::Result<()> {
         let connection = self.connection.lock().map_err(|_| anyhow!("lock is poisoned"))?;
 
@@ -1143,18 +1622,35 @@ impl DataDbRepository {
     }
 
     pub fn remove_plugin(&self, plugin_id: &str) -> anyhow::Result<()> {
-        let connection = self.connection.lock().map_err(|_| anyhow!("lock is poisoned"))?;
+        let mut connection = self.connection.lock().map_err(|_| anyhow!("lock is poisoned"))?;
+        let tx = connection.transaction()?;
 
         // language=SQLite
         let query = "DELETE FROM plugin WHERE id = :id";
 
-        connection.execute(
+        tx.execute(
             query,
             named_params! {
                 ":id": plugin_id
             },
         )?;
 
+        // frecency and run history tables don't reference plugin table, otherwise reinstalled plugin
+        // with the same id would be ranked using usage of removed one and shown in "Recent" section
+        for table in FRECENCY_TABLES.into_iter().chain(["plugin_entrypoint_run_history"]) {
+            // language=SQLite
+            let query = format!("DELETE FROM {} WHERE plugin_id = :plugin_id", table);
+
+            tx.execute(
+                &query,
+                named_params! {
+                    ":plugin_id": plugin_id,
+                },
+            )?;
+        }
+
+        tx.commit()?;
+
         Ok(())
     }
 
@@ -1169,8 +1665,8 @@ impl DataDbRepository {
 
         // language=SQLite
         let query = r#"
-            INSERT INTO plugin (id, name, enabled, code, permissions, preferences, preferences_user_data, description, type, uuid)
-                VALUES(:id, :name, :enabled, :code, :permissions, :preferences, :preferences_user_data, :description, :type, :uuid)
+            INSERT INTO plugin (id, name, enabled, code, permissions, preferences, preferences_user_data, description, type, uuid, installed_commit, git_ref, archive_sha256, signature)
+                VALUES(:id, :name, :enabled, :code, :permissions, :preferences, :preferences_user_data, :description, :type, :uuid, :installed_commit, :git_ref, :archive_sha256, :signature)
                     ON CONFLICT (id)
                         DO UPDATE SET
                             name = :name,
@@ -1181,7 +1677,11 @@ impl DataDbRepository {
                             preferences_user_data = :preferences_user_data ,
                             description = :description ,
                             type = :type,
-                            uuid = :uuid
+                            uuid = :uuid,
+                            installed_commit = :installed_commit,
+                            git_ref = :git_ref,
+                            archive_sha256 = :archive_sha256,
+                            signature = :signature
         "#;
 
         tx.execute(
@@ -1196,7 +1696,11 @@ impl DataDbRepository {
                 ":preferences_user_data": serde_json::to_value(&preferences_user_data)?,
                 ":description": new_plugin.description,
                 ":type": new_plugin.plugin_type,
-                ":uuid": uuid
+                ":uuid": uuid,
+                ":installed_commit": new_plugin.installed_commit,
+                ":git_ref": new_plugin.git_ref,
+                ":archive_sha256": new_plugin.archive_sha256,
+                ":signature": new_plugin.signature.map(|signature| serde_json::to_value(&signature)).transpose()?,
             },
         )?;
 
@@ -1223,7 +1727,7 @@ impl DataDbRepository {
 
             // language=SQLite
             let query = r#"
-                INSERT OR REPLACE INTO plugin_entrypoint (id, plugin_id, name, enabled, type, preferences, preferences_user_data, description, actions, actions_user_data, icon_path, uuid)
+                INSERT OR REPLACE INTO plugin_entrypoint (id, plugin_id, name, enabled, type, preferences, preferences_user_data, description, actions, actions_user_data, icon_path, uuid, keywords)
                     VALUES(
                         :id,
                         :plugin_id,
@@ -1236,7 +1740,8 @@ impl DataDbRepository {
                         :actions,
                         :actions_user_data,
                         :icon_path,
-                        :uuid
+                        :uuid,
+                        :keywords
                     )
             "#;
 
@@ -1255,20 +1760,39 @@ impl DataDbRepository {
                     ":actions_user_data": serde_json::to_value(actions_user_data)?,
                     ":icon_path": new_entrypoint.icon_path,
                     ":uuid": uuid,
+                    ":keywords": serde_json::to_value(new_entrypoint.keywords)?,
                 },
             )?;
         }
 
         for old_entrypoint_id in old_entrypoint_ids {
+            // entrypoint ids are unique only within plugin
             // language=SQLite
-            let query = "DELETE FROM plugin_entrypoint WHERE id = :id";
+            let query = "DELETE FROM plugin_entrypoint WHERE id = :id AND plugin_id = :plugin_id";
 
             tx.execute(
                 query,
                 named_params! {
-                    ":id": old_entrypoint_id
+                    ":id": &old_entrypoint_id,
+                    ":plugin_id": &new_plugin.id,
                 },
             )?;
+
+            for table in FRECENCY_TABLES.into_iter().chain(["plugin_entrypoint_run_history"]) {
+                // language=SQLite
+                let query = format!(
+                    "DELETE FROM {} WHERE plugin_id = :plugin_id AND entrypoint_id = :entrypoint_id",
+                    table
+                );
+
+                tx.execute(
+                    &query,
+                    named_params! {
+                        ":plugin_id": &new_plugin.id,
+                        ":entrypoint_id": &old_entrypoint_id,
+                    },
+                )?;
+            }
         }
 
         let mut old_asset_data_paths = self.get_all_asset_data_paths(&new_plugin.id, &mut tx)?;
@@ -1353,3 +1877,101 @@ pub trait RusqliteFromRow {
     where
         Self: Sized;
 }
+
+fn frecency_item_stats(stats: &DbPluginEntrypointFrecencyStats) -> FrecencyItemStats {
+    FrecencyItemStats {
+        half_life: stats.half_life,
+        reference_time: stats.reference_time,
+        last_accessed: stats.last_accessed,
+        frecency: stats.frecency,
+        num_accesses: stats.num_accesses,
+    }
+}
+
+/// Moves all frecency stats to the same reference time and half life, keeping current frecency values
+fn renormalize_frecency(tx: &Transaction, meta_params: &FrecencyMetaParams) -> anyhow::Result<()> {
+    #[derive(RusqliteFromRow)]
+    struct DbFrecencyRow {
+        pub rowid: i64,
+        pub reference_time: f64,
+        pub half_life: f64,
+        pub last_accessed: f64,
+        pub frecency: f64,
+        pub num_accesses: i32,
+    }
+
+    for table in FRECENCY_TABLES {
+        // language=SQLite
+        let query = format!(
+            "SELECT rowid, reference_time, half_life, last_accessed, frecency, num_accesses FROM {}",
+            table
+        );
+
+        let rows = tx
+            .prepare(&query)?
+            .query_and_then([], DbFrecencyRow::from_row)?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        for row in rows {
+            let mut stats = FrecencyItemStats {
+                half_life: row.half_life,
+                reference_time: row.reference_time,
+                last_accessed: row.last_accessed,
+                frecency: row.frecency,
+                num_accesses: row.num_accesses,
+            };
+
+            stats.reset_ref_time(meta_params.reference_time);
+
+            if stats.half_life != meta_params.half_life {
+                stats.set_half_life(meta_params.half_life);
+            }
+
+            // language=SQLite
+            let query = format!(
+                "UPDATE {} SET reference_time = :reference_time, half_life = :half_life, last_accessed = :last_accessed, frecency = :frecency WHERE rowid = :rowid",
+                table
+            );
+
+            tx.execute(
+                &query,
+                named_params! {
+                    ":rowid": row.rowid,
+                    ":reference_time": stats.reference_time,
+                    ":half_life": stats.half_life,
+                    ":last_accessed": stats.last_accessed,
+                    ":frecency": stats.frecency,
+                },
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+fn remove_stale_query_frecency(tx: &Transaction) -> anyhow::Result<()> {
+    // language=SQLite
+    let query = r#"
+        DELETE FROM plugin_entrypoint_query_frecency_stats
+        WHERE reference_time + last_accessed < :current_time - half_life * :retention_half_lives
+    "#;
+
+    tx.execute(
+        query,
+        named_params! {
+            ":current_time": current_time_secs(),
+            ":retention_half_lives": QUERY_FRECENCY_RETENTION_HALF_LIVES,
+        },
+    )?;
+
+    Ok(())
+}
+
+fn remove_old_entrypoint_run_history(tx: &Transaction, min_run_at: f64) -> anyhow::Result<()> {
+    // language=SQLite
+    let query = "DELETE FROM plugin_entrypoint_run_history WHERE run_at < :min_run_at";
+
+    tx.execute(query, named_params! { ":min_run_at": min_run_at })?;
+
+    Ok(())
+}
@@ -230,6 +230,13 @@ pub fn setup_migrator() -> Migrations<'static> {
             Ok(())
         })),
         M::up_with_hook("-- 16", legacy_migration("remove old global shortcut", include_str!("migrations/13_remove_old_global_shortcut.sql"))),
-        M::up(include_str!("migrations/14_migrate_to_rusqlite_migration.sql"))
+        M::up(include_str!("migrations/14_migrate_to_rusqlite_migration.sql")),
+        M::up(include_str!("migrations/15_query_frecency.sql")),
+        M::up(include_str!("migrations/16_plugin_entrypoint_keywords.sql")),
+        M::up(include_str!("migrations/17_entrypoint_run_history.sql")),
+        M::up(include_str!("migrations/18_plugin_installed_commit.sql")),
+        M::up(include_str!("migrations/19_plugin_signature.sql")),
+        M::up(include_str!("migrations/20_plugin_git_ref.sql")),
+        M::up(include_str!("migrations/21_plugin_archive_sha256.sql")),
     ])
 }
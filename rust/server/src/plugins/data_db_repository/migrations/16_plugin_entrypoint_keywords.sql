@@ -0,0 +1 @@
+ALTER TABLE plugin_entrypoint ADD COLUMN keywords JSON NOT NULL DEFAULT ('[]');
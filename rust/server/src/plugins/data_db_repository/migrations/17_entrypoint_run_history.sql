@@ -0,0 +1,9 @@
+CREATE TABLE plugin_entrypoint_run_history
+(
+    id            INTEGER PRIMARY KEY AUTOINCREMENT,
+    plugin_id     TEXT NOT NULL,
+    entrypoint_id TEXT NOT NULL,
+    run_at        REAL NOT NULL
+);
+
+CREATE INDEX plugin_entrypoint_run_history_run_at ON plugin_entrypoint_run_history (run_at);
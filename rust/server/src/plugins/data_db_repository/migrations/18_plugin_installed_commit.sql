@@ -0,0 +1 @@
+ALTER TABLE plugin ADD COLUMN installed_commit TEXT;
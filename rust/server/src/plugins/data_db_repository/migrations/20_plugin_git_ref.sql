@@ -0,0 +1 @@
+ALTER TABLE plugin ADD COLUMN git_ref TEXT;
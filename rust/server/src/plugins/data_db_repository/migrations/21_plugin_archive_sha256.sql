@@ -0,0 +1 @@
+ALTER TABLE plugin ADD COLUMN archive_sha256 TEXT;
@@ -7,11 +7,11 @@ pub struct FrecencyMetaParams {
     pub half_life: f64,
 }
 
-impl Default for FrecencyMetaParams {
-    fn default() -> FrecencyMetaParams {
+impl FrecencyMetaParams {
+    pub fn new(half_life: f64) -> FrecencyMetaParams {
         FrecencyMetaParams {
             reference_time: current_time_secs(),
-            half_life: 60.0 * 60.0 * 24.0 * 3.0, // three day half life
+            half_life,
         }
     }
 }
@@ -38,7 +38,6 @@ impl FrecencyItemStats {
     }
 
     /// Return the number of half lives passed since the reference time
-    #[allow(unused)]
     pub fn half_lives_passed(&self) -> f64 {
         (current_time_secs() - self.reference_time) / self.half_life
     }
@@ -50,7 +49,6 @@ impl FrecencyItemStats {
     }
 
     /// Change the half life of the item, maintaining the same frecency
-    #[allow(unused)]
     pub fn set_half_life(&mut self, half_life: f64) {
         let secs = current_time_secs();
         self.reset_ref_time(secs);
@@ -86,7 +84,6 @@ impl FrecencyItemStats {
     }
 
     /// Reset the reference time and recalculate the last_accessed time
-    #[allow(unused)]
     pub fn reset_ref_time(&mut self, new_time: f64) {
         let original_frecency = self.get_frecency(current_time_secs());
         let delta = self.reference_time - new_time;
@@ -102,7 +99,7 @@ impl FrecencyItemStats {
     }
 }
 
-fn current_time_secs() -> f64 {
+pub fn current_time_secs() -> f64 {
     SystemTime::now()
         .duration_since(SystemTime::UNIX_EPOCH)
         .expect("failed to get system time")
@@ -13,6 +13,7 @@ use gauntlet_common::model::PluginId;
 use gauntlet_common::model::RootWidget;
 use gauntlet_common::model::SearchResultAccessory;
 use gauntlet_common::model::SearchResultEntrypointType;
+use gauntlet_common::model::ServerEvent;
 use gauntlet_common::model::UiPropertyValue;
 use gauntlet_common::model::UiRenderLocation;
 use gauntlet_common::model::UiWidgetId;
@@ -84,6 +85,7 @@ pub struct PluginRuntimeData {
     pub frontend_api: FrontendApiProxy,
     pub dirs: Dirs,
     pub clipboard: Clipboard,
+    pub event_broadcaster: tokio::sync::broadcast::Sender<ServerEvent>,
 }
 
 pub struct PluginPermissions {
@@ -118,6 +120,7 @@ pub enum PluginCommand {
 pub enum OnePluginCommandData {
     OpenView {
         entrypoint_id: EntrypointId,
+        query: Option<String>,
     },
     CloseView,
     PopView {
@@ -125,10 +128,14 @@ pub enum OnePluginCommandData {
     },
     RunCommand {
         entrypoint_id: String,
+        arguments: HashMap<String, String>,
+        query: Option<String>,
     },
     RunGeneratedEntrypoint {
         entrypoint_id: String,
         action_index: usize,
+        arguments: HashMap<String, String>,
+        query: Option<String>,
     },
     HandleViewEvent {
         widget_id: UiWidgetId,
@@ -150,9 +157,28 @@ pub enum OnePluginCommandData {
 #[derive(Clone, Debug)]
 pub enum AllPluginCommandData {
     OpenInlineView { text: String },
+    RefreshSearchIndex,
 }
 
 pub async fn start_plugin_runtime(data: PluginRuntimeData, run_status_guard: RunStatusGuard) -> anyhow::Result<()> {
+    let plugin_id = data.id.clone();
+    let event_broadcaster = data.event_broadcaster.clone();
+
+    let result = run_plugin_runtime(data, run_status_guard).await;
+
+    // also sent if runtime failed to start or failed while stopping, so subscribers are not left thinking it is running
+    let crashed = match &result {
+        Ok(crashed) => *crashed,
+        Err(_) => true,
+    };
+
+    let _ = event_broadcaster.send(ServerEvent::PluginStopped { plugin_id, crashed });
+
+    result.map(|_| ())
+}
+
+// returns whether runtime process has crashed
+async fn run_plugin_runtime(data: PluginRuntimeData, run_status_guard: RunStatusGuard) -> anyhow::Result<bool> {
     let runtime_permissions = PluginRuntimePermissions {
         clipboard: data.permissions.clipboard,
     };
@@ -170,11 +196,11 @@ pub async fn start_plugin_runtime(data: PluginRuntimeData, run_status_guard: Run
     );
 
     let mut command_receiver = data.command_receiver;
+    let event_broadcaster = data.event_broadcaster;
     let plugin_uuid = data.uuid.clone();
     let plugin_id = data.id.clone();
 
-    let plugin_id_str = plugin_id.to_string();
-    let dev_plugin = plugin_id_str.starts_with("file://");
+    let dev_plugin = plugin_id.is_development();
 
     let (stdout_file, stderr_file) = if dev_plugin {
         let (stdout_file, stderr_file) = data.dirs.plugin_log_files(&plugin_uuid);
@@ -318,12 +344,17 @@ pub async fn start_plugin_runtime(data: PluginRuntimeData, run_status_guard: Run
 
     send_message(JsMessageSide::Backend, &mut sender, init).await?;
 
+    let _ = event_broadcaster.send(ServerEvent::PluginStarted {
+        plugin_id: plugin_id.clone(),
+    });
+
     let sender = Arc::new(Mutex::new(sender));
 
-    tokio::task::spawn({
+    let stop_task = tokio::task::spawn({
         let sender = sender.clone();
+        let stopped = run_status_guard.stopped();
         async move {
-            run_status_guard.stopped().await;
+            stopped.await;
 
             tracing::info!("Requesting plugin runtime to stop...");
 
@@ -374,8 +405,11 @@ pub async fn start_plugin_runtime(data: PluginRuntimeData, run_status_guard: Run
 
     drop((recver, sender));
 
+    // runtime exited on its own, nothing to stop anymore
+    stop_task.abort();
+
     #[cfg(not(feature = "scenario_runner"))]
-    {
+    let crashed = {
         let code = runtime_process
             .wait()
             .context("Error while waiting for JS runtime process to finish")?
@@ -384,16 +418,24 @@ pub async fn start_plugin_runtime(data: PluginRuntimeData, run_status_guard: Run
         match code {
             Some(code) => {
                 if code == 0 {
-                    tracing::info!("Plugin Runtime was stopped successfully")
+                    tracing::info!("Plugin Runtime was stopped successfully");
+                    false
                 } else {
-                    tracing::error!("Runtime process finished with status code: {code}")
+                    tracing::error!("Runtime process finished with status code: {code}");
+                    true
                 }
             }
-            None => tracing::error!("Process terminated by signal"),
+            None => {
+                tracing::error!("Process terminated by signal");
+                true
+            }
         }
-    }
+    };
 
-    Ok(())
+    #[cfg(feature = "scenario_runner")]
+    let crashed = false;
+
+    Ok(crashed)
 }
 
 async fn event_loop(
@@ -409,23 +451,35 @@ async fn event_loop(
                 None
             } else {
                 match data {
-                    OnePluginCommandData::OpenView { entrypoint_id } => {
-                        Some(IntermediateUiEvent::OpenView { entrypoint_id })
+                    OnePluginCommandData::OpenView { entrypoint_id, query } => {
+                        Some(IntermediateUiEvent::OpenView { entrypoint_id, query })
                     }
                     OnePluginCommandData::CloseView => Some(IntermediateUiEvent::CloseView),
                     OnePluginCommandData::PopView { entrypoint_id } => {
                         Some(IntermediateUiEvent::PopView { entrypoint_id })
                     }
-                    OnePluginCommandData::RunCommand { entrypoint_id } => {
-                        Some(IntermediateUiEvent::RunCommand { entrypoint_id })
+                    OnePluginCommandData::RunCommand {
+                        entrypoint_id,
+                        arguments,
+                        query,
+                    } => {
+                        Some(IntermediateUiEvent::RunCommand {
+                            entrypoint_id,
+                            arguments,
+                            query,
+                        })
                     }
                     OnePluginCommandData::RunGeneratedEntrypoint {
                         entrypoint_id,
                         action_index,
+                        arguments,
+                        query,
                     } => {
                         Some(IntermediateUiEvent::RunGeneratedEntrypoint {
                             entrypoint_id,
                             action_index,
+                            arguments,
+                            query,
                         })
                     }
                     OnePluginCommandData::HandleViewEvent {
@@ -465,6 +519,7 @@ async fn event_loop(
         PluginCommand::All { data } => {
             match data {
                 AllPluginCommandData::OpenInlineView { text } => Some(IntermediateUiEvent::OpenInlineView { text }),
+                AllPluginCommandData::RefreshSearchIndex => Some(IntermediateUiEvent::RefreshSearchIndex),
             }
         }
     };
@@ -524,9 +579,10 @@ async fn request_loop(
 
 fn from_intermediate_to_js_event(event: IntermediateUiEvent) -> JsEvent {
     match event {
-        IntermediateUiEvent::OpenView { entrypoint_id } => {
+        IntermediateUiEvent::OpenView { entrypoint_id, query } => {
             JsEvent::OpenView {
                 entrypoint_id: entrypoint_id.to_string(),
+                query,
             }
         }
         IntermediateUiEvent::CloseView => JsEvent::CloseView,
@@ -535,14 +591,28 @@ fn from_intermediate_to_js_event(event: IntermediateUiEvent) -> JsEvent {
                 entrypoint_id: entrypoint_id.to_string(),
             }
         }
-        IntermediateUiEvent::RunCommand { entrypoint_id } => JsEvent::RunCommand { entrypoint_id },
+        IntermediateUiEvent::RunCommand {
+            entrypoint_id,
+            arguments,
+            query,
+        } => {
+            JsEvent::RunCommand {
+                entrypoint_id,
+                arguments,
+                query,
+            }
+        }
         IntermediateUiEvent::RunGeneratedEntrypoint {
             entrypoint_id,
             action_index,
+            arguments,
+            query,
         } => {
             JsEvent::RunGeneratedEntrypoint {
                 entrypoint_id,
                 action_index,
+                arguments,
+                query,
             }
         }
         IntermediateUiEvent::HandleViewEvent {
@@ -645,7 +715,7 @@ impl BackendForPluginRuntimeApi for BackendForPluginRuntimeApiImpl {
         generated_entrypoints: Vec<JsGeneratedSearchItem>,
         refresh_search_list: bool,
     ) -> RequestResult<()> {
-        let DbReadPlugin { name, .. } = self
+        let DbReadPlugin { name, code, .. } = self
             .repository
             .get_plugin_by_id(&self.plugin_id.to_string())
             .context("error when getting plugin by id")?;
@@ -737,6 +807,8 @@ impl BackendForPluginRuntimeApi for BackendForPluginRuntimeApiImpl {
                     entrypoint_type: SearchResultEntrypointType::Generated,
                     entrypoint_id: EntrypointId::from_string(item.entrypoint_id),
                     entrypoint_name: item.entrypoint_name,
+                    entrypoint_description: "".to_string(),
+                    entrypoint_keywords: item.entrypoint_keywords,
                     entrypoint_icon,
                     entrypoint_frecency,
                     entrypoint_actions,
@@ -786,6 +858,8 @@ impl BackendForPluginRuntimeApi for BackendForPluginRuntimeApiImpl {
                         Ok(Some(SearchIndexItem {
                             entrypoint_type: SearchResultEntrypointType::Command,
                             entrypoint_name: entrypoint.name,
+                            entrypoint_description: entrypoint.description,
+                            entrypoint_keywords: entrypoint.keywords,
                             entrypoint_generator: None,
                             entrypoint_id,
                             entrypoint_icon,
@@ -798,6 +872,8 @@ impl BackendForPluginRuntimeApi for BackendForPluginRuntimeApiImpl {
                         Ok(Some(SearchIndexItem {
                             entrypoint_type: SearchResultEntrypointType::View,
                             entrypoint_name: entrypoint.name,
+                            entrypoint_description: entrypoint.description,
+                            entrypoint_keywords: entrypoint.keywords,
                             entrypoint_generator: None,
                             entrypoint_id,
                             entrypoint_icon,
@@ -820,6 +896,7 @@ impl BackendForPluginRuntimeApi for BackendForPluginRuntimeApiImpl {
             .save_for_plugin(
                 self.plugin_id.clone(),
                 name,
+                code.version(),
                 generated_search_items,
                 refresh_search_list,
             )
@@ -1,17 +1,26 @@
 use std::collections::HashMap;
 use std::ffi::OsStr;
 use std::fs::DirEntry;
+use std::io::Cursor;
 use std::io::ErrorKind;
+use std::io::Read;
 use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Arc;
 use std::thread;
 
 use anyhow::Context;
 use anyhow::anyhow;
+use flate2::read::GzDecoder;
 use gauntlet_common::model::DownloadStatus;
 use gauntlet_common::model::PluginId;
+use gauntlet_common::model::PluginSource;
+use gauntlet_common::model::ServerEvent;
 use gauntlet_common_plugin_runtime::PERMISSIONS_VARIABLE_PATTERN;
 use include_dir::Dir;
 use itertools::Itertools;
+use sha2::Digest;
+use sha2::Sha256;
 use typed_path::Utf8TypedPath;
 use typed_path::Utf8UnixComponent;
 use typed_path::Utf8WindowsComponent;
@@ -29,6 +38,7 @@ use crate::plugins::data_db_repository::DbPluginPermissions;
 use crate::plugins::data_db_repository::DbPluginPermissionsExec;
 use crate::plugins::data_db_repository::DbPluginPermissionsFileSystem;
 use crate::plugins::data_db_repository::DbPluginPreference;
+use crate::plugins::data_db_repository::DbPluginSignature;
 use crate::plugins::data_db_repository::DbPluginType;
 use crate::plugins::data_db_repository::DbPreferenceEnumValue;
 use crate::plugins::data_db_repository::DbWritePlugin;
@@ -36,19 +46,40 @@ use crate::plugins::data_db_repository::DbWritePluginAssetData;
 use crate::plugins::data_db_repository::DbWritePluginEntrypoint;
 use crate::plugins::data_db_repository::db_entrypoint_to_str;
 use crate::plugins::data_db_repository::db_plugin_type_to_str;
+use crate::plugins::download_status::DownloadStatusGuard;
 use crate::plugins::download_status::DownloadStatusHolder;
 use crate::plugins::plugin_manifest::*;
+use crate::plugins::settings::config::EffectiveConfig;
+use crate::plugins::signature::verify_plugin_signature;
+
+// guards against filling memory if url points to something other than plugin
+const MAX_PLUGIN_ARCHIVE_SIZE: u64 = 200 * 1024 * 1024;
+
+// guards against filling disk by small archive which expands to huge size
+const MAX_PLUGIN_UNPACKED_SIZE: u64 = 500 * 1024 * 1024;
+
+// branch from which plugins are downloaded if plugin id doesn't specify git ref,
+// it contains built plugin and is updated by plugin publishing
+pub const PLUGIN_RELEASE_BRANCH: &str = "gauntlet/release";
 
 pub struct PluginLoader {
     db_repository: DataDbRepository,
     download_status_holder: DownloadStatusHolder,
+    event_broadcaster: tokio::sync::broadcast::Sender<ServerEvent>,
+    config: Arc<EffectiveConfig>,
 }
 
 impl PluginLoader {
-    pub fn new(db_repository: DataDbRepository) -> Self {
+    pub fn new(
+        db_repository: DataDbRepository,
+        event_broadcaster: tokio::sync::broadcast::Sender<ServerEvent>,
+        config: Arc<EffectiveConfig>,
+    ) -> Self {
         Self {
             db_repository,
             download_status_holder: DownloadStatusHolder::new(),
+            event_broadcaster,
+            config,
         }
     }
 
@@ -56,62 +87,145 @@ impl PluginLoader {
         self.download_status_holder.download_status()
     }
 
-    pub fn download_plugin(&self, plugin_id: PluginId) {
+    pub fn download_plugin(&self, source: PluginSource) {
+        let plugin_id = source.plugin_id.clone();
+
         let download_status_guard = self.download_status_holder.download_started(plugin_id.clone());
 
         let data_db_repository = self.db_repository.clone();
+        let event_broadcaster = self.event_broadcaster.clone();
+        let config = self.config.clone();
         let handle = tokio::runtime::Handle::current();
 
-        let plugin_id_clone = plugin_id.clone();
-
         thread::Builder::new()
             .name("gauntlet-plugin-download".to_string())
             .spawn(move || {
-                let result = handle.block_on(async move {
-                    let temp_dir = tempfile::tempdir()?;
-
-                    PluginLoader::download(temp_dir.path(), plugin_id_clone.clone())?;
-
-                    let plugin_data = PluginLoader::read_plugin_dir(temp_dir.path(), plugin_id_clone.clone())?;
-
-                    data_db_repository.save_plugin(DbWritePlugin {
-                        id: plugin_data.id,
-                        name: plugin_data.name,
-                        description: plugin_data.description,
-                        enabled: false,
-                        code: plugin_data.code,
-                        entrypoints: plugin_data.entrypoints,
-                        asset_data: plugin_data.asset_data,
-                        permissions: plugin_data.permissions,
-                        plugin_type: db_plugin_type_to_str(DbPluginType::Normal).to_owned(),
-                        preferences: plugin_data.preferences,
-                    })?;
-
-                    anyhow::Ok(())
-                });
+                let result = PluginLoader::download_and_save(&data_db_repository, &config, source);
 
                 handle.block_on(async move {
-                    match result {
-                        Ok(()) => {
-                            tracing::info!("Finished download of plugin: {:?}", plugin_id);
-                            download_status_guard.download_finished()
-                        }
-                        Err(err) => {
-                            tracing::warn!("Download of plugin {:?} returned an error {:?}", plugin_id, err);
-                            download_status_guard.download_failed(format!("{}", err))
-                        }
-                    }
+                    PluginLoader::download_finished(&event_broadcaster, download_status_guard, plugin_id, &result);
                 })
             })
             .expect("failed to spawn thread");
     }
 
+    /// Downloads plugin and saves it as disabled, if plugin is already installed it is updated to the latest version,
+    /// preferences, enabled state and usage history of the plugin and its entrypoints are kept
+    pub async fn install_plugin(&self, source: PluginSource) -> anyhow::Result<()> {
+        let plugin_id = source.plugin_id.clone();
+
+        let download_status_guard = self.download_status_holder.download_started(plugin_id.clone());
+
+        let data_db_repository = self.db_repository.clone();
+        let config = self.config.clone();
+
+        let result =
+            tokio::task::spawn_blocking(move || PluginLoader::download_and_save(&data_db_repository, &config, source))
+                .await
+                .map_err(anyhow::Error::from)
+                .and_then(|result| result);
+
+        PluginLoader::download_finished(&self.event_broadcaster, download_status_guard, plugin_id, &result);
+
+        result
+    }
+
+    fn download_and_save(
+        data_db_repository: &DataDbRepository,
+        config: &EffectiveConfig,
+        source: PluginSource,
+    ) -> anyhow::Result<()> {
+        let temp_dir = tempfile::tempdir()?;
+
+        let (plugin_dir, installed_commit) = if source.plugin_id.is_archive() {
+            let plugin_dir = PluginLoader::download_archive(temp_dir.path(), &source)?;
+
+            (plugin_dir, None)
+        } else {
+            let installed_commit = PluginLoader::download(temp_dir.path(), &source)?;
+
+            (temp_dir.path().to_path_buf(), Some(installed_commit))
+        };
+
+        let plugin_data = PluginLoader::read_plugin_dir(&plugin_dir, source.plugin_id, &config.plugin_trusted_keys)?;
+
+        if config.plugin_signatures_required && !matches!(plugin_data.signature, DbPluginSignature::Signed { .. }) {
+            return Err(anyhow!(
+                "Plugin is not signed by any of trusted keys, signatures are required by config"
+            ));
+        }
+
+        let plugin_id = plugin_data.id.clone();
+        let signed = matches!(plugin_data.signature, DbPluginSignature::Signed { .. });
+
+        let previous_plugin = data_db_repository.get_plugin_by_id_option(&plugin_id)?;
+
+        // user data and enabled state of already installed plugin are kept by save_plugin
+        data_db_repository.save_plugin(DbWritePlugin {
+            id: plugin_data.id,
+            name: plugin_data.name,
+            description: plugin_data.description,
+            enabled: false,
+            code: plugin_data.code,
+            entrypoints: plugin_data.entrypoints,
+            asset_data: plugin_data.asset_data,
+            permissions: plugin_data.permissions,
+            plugin_type: db_plugin_type_to_str(DbPluginType::Normal).to_owned(),
+            preferences: plugin_data.preferences,
+            installed_commit,
+            git_ref: source.git_ref,
+            archive_sha256: source.archive_sha256,
+            signature: Some(plugin_data.signature),
+        })?;
+
+        // plugin may have been enabled without confirmation only because it was signed,
+        // so version which is not signed has to be confirmed again
+        if let Some(previous_plugin) = previous_plugin {
+            let previously_signed = matches!(previous_plugin.signature, Some(DbPluginSignature::Signed { .. }));
+
+            if previous_plugin.enabled && previously_signed && !signed {
+                tracing::warn!(
+                    "New version of plugin {:?} is not signed by any of trusted keys, plugin is disabled",
+                    plugin_id
+                );
+
+                data_db_repository.set_plugin_enabled(&plugin_id, false)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn download_finished(
+        event_broadcaster: &tokio::sync::broadcast::Sender<ServerEvent>,
+        download_status_guard: DownloadStatusGuard,
+        plugin_id: PluginId,
+        result: &anyhow::Result<()>,
+    ) {
+        let error = match result {
+            Ok(()) => {
+                tracing::info!("Finished download of plugin: {:?}", plugin_id);
+                download_status_guard.download_finished();
+                None
+            }
+            Err(err) => {
+                tracing::warn!("Download of plugin {:?} returned an error {:?}", plugin_id, err);
+                download_status_guard.download_failed(format!("{}", err));
+                Some(format!("{}", err))
+            }
+        };
+
+        // it is possible to have 0 subscribers
+        let _ = event_broadcaster.send(ServerEvent::DownloadFinished { plugin_id, error });
+    }
+
     pub fn save_local_plugin(&self, path: &str) -> anyhow::Result<PluginId> {
         let plugin_id = PluginId::from_string(format!("file://{}", &path));
 
         let plugin_dir = plugin_id.try_to_path()?.join("dist");
 
-        let plugin_data = PluginLoader::read_plugin_dir(&plugin_dir, plugin_id.clone())
+        // signatures are checked only for downloaded plugins
+        let plugin_data = PluginLoader::read_plugin_dir(&plugin_dir, plugin_id.clone(), &[])
             .context(format!("Unable to read plugin: {}", &plugin_id.to_string()))?;
 
         self.db_repository.save_plugin(DbWritePlugin {
@@ -125,6 +239,10 @@ impl PluginLoader {
             permissions: plugin_data.permissions,
             plugin_type: db_plugin_type_to_str(DbPluginType::Normal).to_owned(),
             preferences: plugin_data.preferences,
+            installed_commit: None,
+            git_ref: None,
+            archive_sha256: None,
+            signature: None,
         })?;
 
         Ok(plugin_id)
@@ -136,7 +254,7 @@ impl PluginLoader {
 
         dir.extract(&temp_dir)?;
 
-        let plugin_data = PluginLoader::read_plugin_dir(temp_dir.path(), plugin_id.clone())
+        let plugin_data = PluginLoader::read_plugin_dir(temp_dir.path(), plugin_id.clone(), &[])
             .context(format!("Unable to read plugin: {}", &plugin_id.to_string()))?;
 
         self.db_repository.save_plugin(DbWritePlugin {
@@ -150,22 +268,158 @@ impl PluginLoader {
             permissions: plugin_data.permissions,
             plugin_type: db_plugin_type_to_str(DbPluginType::Bundled).to_owned(),
             preferences: plugin_data.preferences,
+            installed_commit: None,
+            git_ref: None,
+            archive_sha256: None,
+            signature: None,
         })?;
 
         Ok(plugin_id)
     }
 
-    fn download(target_dir: &Path, plugin_id: PluginId) -> anyhow::Result<()> {
-        let url = plugin_id.try_to_git_url()?;
+    /// Returns hash of downloaded commit
+    fn download(target_dir: &Path, source: &PluginSource) -> anyhow::Result<String> {
+        let url = source.plugin_id.try_to_git_url()?;
+
+        let Some(git_ref) = &source.git_ref else {
+            let repository = git2::build::RepoBuilder::new()
+                .branch(PLUGIN_RELEASE_BRANCH)
+                .clone(&url, target_dir)?;
+
+            let commit = repository.head()?.peel_to_commit()?;
+
+            return Ok(commit.id().to_string());
+        };
+
+        let repository = git2::build::RepoBuilder::new().clone(&url, target_dir)?;
+
+        // branches are only available as remote tracking branches after clone
+        let object = repository
+            .revparse_single(&format!("origin/{}", git_ref))
+            .or_else(|_| repository.revparse_single(git_ref))
+            .context(format!(
+                "Unable to find branch, tag or commit '{}' in plugin repository",
+                git_ref
+            ))?;
+
+        let commit = object.peel_to_commit()?;
+
+        repository.checkout_tree(commit.as_object(), Some(git2::build::CheckoutBuilder::new().force()))?;
+        repository.set_head_detached(commit.id())?;
+
+        Ok(commit.id().to_string())
+    }
+
+    /// Downloads or copies archive of built plugin, verifies its hash and extracts it.
+    /// Returns directory containing plugin manifest
+    fn download_archive(target_dir: &Path, source: &PluginSource) -> anyhow::Result<PathBuf> {
+        let url = source.plugin_id.try_to_archive_url()?;
+
+        let data = match source.plugin_id.try_to_path() {
+            Ok(path) => std::fs::read(&path).context(format!("Unable to read plugin archive {:?}", path))?,
+            Err(_) => {
+                // remote archive can be replaced at any time, so hash is required to know what is installed
+                if source.archive_sha256.is_none() {
+                    return Err(anyhow!(
+                        "Plugin archive downloaded over network requires expected hash, append '#sha256=<hash>' to the url"
+                    ));
+                }
+
+                let mut data = vec![];
+
+                ureq::get(&url)
+                    .call()
+                    .context("Unable to download plugin archive")?
+                    .into_reader()
+                    .take(MAX_PLUGIN_ARCHIVE_SIZE)
+                    .read_to_end(&mut data)
+                    .context("Unable to download plugin archive")?;
+
+                data
+            }
+        };
+
+        if let Some(expected_sha256) = &source.archive_sha256 {
+            let actual_sha256 = format!("{:x}", Sha256::digest(&data));
+
+            if !actual_sha256.eq_ignore_ascii_case(expected_sha256) {
+                return Err(anyhow!(
+                    "Plugin archive hash doesn't match, expected: {}, actual: {}",
+                    expected_sha256,
+                    actual_sha256
+                ));
+            }
+        }
+
+        let extract_dir = target_dir.join("archive");
+
+        std::fs::create_dir_all(&extract_dir)?;
+
+        // both tar and zip skip entries which would be extracted outside of target directory
+        if url.to_lowercase().ends_with(".zip") {
+            let mut archive = zip::ZipArchive::new(Cursor::new(data)).context("Unable to read plugin zip archive")?;
+
+            let mut unpacked_size = 0;
+            for index in 0..archive.len() {
+                unpacked_size += archive.by_index_raw(index)?.size();
+            }
+
+            PluginLoader::check_unpacked_size(unpacked_size)?;
+
+            archive
+                .extract(&extract_dir)
+                .context("Unable to extract plugin zip archive")?;
+        } else {
+            let mut archive = tar::Archive::new(GzDecoder::new(Cursor::new(data)));
 
-        let _ = git2::build::RepoBuilder::new()
-            .branch("gauntlet/release")
-            .clone(&url, target_dir)?;
+            // size of tar.gz content is not known until it is decompressed, so it is checked entry by entry
+            let mut unpacked_size = 0;
+            for entry in archive.entries().context("Unable to read plugin tar.gz archive")? {
+                let mut entry = entry.context("Unable to read plugin tar.gz archive")?;
+
+                unpacked_size += entry.size();
+
+                PluginLoader::check_unpacked_size(unpacked_size)?;
+
+                entry
+                    .unpack_in(&extract_dir)
+                    .context("Unable to extract plugin tar.gz archive")?;
+            }
+        }
+
+        PluginLoader::find_archive_plugin_dir(&extract_dir)
+    }
+
+    fn check_unpacked_size(unpacked_size: u64) -> anyhow::Result<()> {
+        if unpacked_size > MAX_PLUGIN_UNPACKED_SIZE {
+            return Err(anyhow!(
+                "Plugin archive content is larger than {} bytes",
+                MAX_PLUGIN_UNPACKED_SIZE
+            ));
+        }
 
         Ok(())
     }
 
-    fn read_plugin_dir(plugin_dir: &Path, plugin_id: PluginId) -> anyhow::Result<PluginDownloadData> {
+    // archive can contain content of dist directory itself or the directory
+    fn find_archive_plugin_dir(extract_dir: &Path) -> anyhow::Result<PathBuf> {
+        if extract_dir.join("gauntlet.toml").is_file() {
+            return Ok(extract_dir.to_path_buf());
+        }
+
+        let entries = std::fs::read_dir(extract_dir)?.collect::<std::io::Result<Vec<DirEntry>>>()?;
+
+        match entries.as_slice() {
+            [entry] if entry.path().join("gauntlet.toml").is_file() => Ok(entry.path()),
+            _ => Err(anyhow!("Plugin archive doesn't contain gauntlet.toml of built plugin")),
+        }
+    }
+
+    fn read_plugin_dir(
+        plugin_dir: &Path,
+        plugin_id: PluginId,
+        trusted_keys: &[String],
+    ) -> anyhow::Result<PluginDownloadData> {
         let js_dir = plugin_dir.join("js");
         let assets = plugin_dir.join("assets");
 
@@ -226,6 +480,9 @@ impl PluginLoader {
             .into_iter()
             .collect();
 
+        let signature =
+            verify_plugin_signature(plugin_dir, trusted_keys).context("Unable to verify plugin signature")?;
+
         let plugin_manifest_path = plugin_dir.join("gauntlet.toml");
         let plugin_manifest_path_context = plugin_manifest_path.display().to_string();
         let plugin_manifest_content =
@@ -248,6 +505,7 @@ impl PluginLoader {
                     id: entrypoint.id,
                     name: entrypoint.name,
                     description: entrypoint.description,
+                    keywords: entrypoint.keywords,
                     icon_path: entrypoint.icon,
                     entrypoint_type: db_entrypoint_to_str(match entrypoint.entrypoint_type {
                         PluginManifestEntrypointTypes::Command => DbPluginEntrypointType::Command,
@@ -578,6 +836,7 @@ impl PluginLoader {
             asset_data,
             permissions,
             preferences: plugin_preferences,
+            signature,
         })
     }
 
@@ -892,4 +1151,117 @@ struct PluginDownloadData {
     pub asset_data: Vec<DbWritePluginAssetData>,
     pub permissions: DbPluginPermissions,
     pub preferences: HashMap<String, DbPluginPreference>,
+    pub signature: DbPluginSignature,
+}
+
+#[cfg(test)]
+mod tests {
+    use flate2::Compression;
+    use flate2::write::GzEncoder;
+
+    use super::*;
+
+    fn write_tar_gz(path: &Path, files: &[(&str, &str)]) {
+        let mut builder = tar::Builder::new(GzEncoder::new(
+            std::fs::File::create(path).unwrap(),
+            Compression::default(),
+        ));
+
+        for (file_path, content) in files {
+            let mut header = tar::Header::new_gnu();
+            header.set_size(content.len() as u64);
+            header.set_mode(0o644);
+            header.set_cksum();
+
+            builder.append_data(&mut header, file_path, content.as_bytes()).unwrap();
+        }
+
+        builder.into_inner().unwrap().finish().unwrap();
+    }
+
+    fn archive_source(archive_path: &Path, archive_sha256: Option<String>) -> PluginSource {
+        PluginSource {
+            archive_sha256,
+            ..PluginSource::new(PluginId::from_string(format!("file://{}", archive_path.display())))
+        }
+    }
+
+    #[test]
+    fn find_archive_plugin_dir_in_archive_root() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("gauntlet.toml"), "").unwrap();
+
+        assert_eq!(PluginLoader::find_archive_plugin_dir(dir.path()).unwrap(), dir.path());
+    }
+
+    #[test]
+    fn find_archive_plugin_dir_in_single_subdirectory() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir(dir.path().join("dist")).unwrap();
+        std::fs::write(dir.path().join("dist").join("gauntlet.toml"), "").unwrap();
+
+        assert_eq!(
+            PluginLoader::find_archive_plugin_dir(dir.path()).unwrap(),
+            dir.path().join("dist")
+        );
+    }
+
+    #[test]
+    fn find_archive_plugin_dir_without_manifest() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir(dir.path().join("dist")).unwrap();
+        std::fs::write(dir.path().join("dist").join("index.js"), "").unwrap();
+
+        assert!(PluginLoader::find_archive_plugin_dir(dir.path()).is_err());
+    }
+
+    #[test]
+    fn find_archive_plugin_dir_with_multiple_subdirectories() {
+        let dir = tempfile::tempdir().unwrap();
+        for name in ["first", "second"] {
+            std::fs::create_dir(dir.path().join(name)).unwrap();
+            std::fs::write(dir.path().join(name).join("gauntlet.toml"), "").unwrap();
+        }
+
+        assert!(PluginLoader::find_archive_plugin_dir(dir.path()).is_err());
+    }
+
+    #[test]
+    fn download_archive_with_matching_hash() {
+        let dir = tempfile::tempdir().unwrap();
+        let archive_path = dir.path().join("plugin.tar.gz");
+        write_tar_gz(&archive_path, &[("dist/gauntlet.toml", "[gauntlet]")]);
+
+        let sha256 = format!("{:x}", Sha256::digest(std::fs::read(&archive_path).unwrap()));
+
+        let target_dir = dir.path().join("target");
+        let plugin_dir =
+            PluginLoader::download_archive(&target_dir, &archive_source(&archive_path, Some(sha256))).unwrap();
+
+        assert_eq!(plugin_dir, target_dir.join("archive").join("dist"));
+        assert_eq!(
+            std::fs::read_to_string(plugin_dir.join("gauntlet.toml")).unwrap(),
+            "[gauntlet]"
+        );
+    }
+
+    #[test]
+    fn download_archive_with_hash_mismatch() {
+        let dir = tempfile::tempdir().unwrap();
+        let archive_path = dir.path().join("plugin.tar.gz");
+        write_tar_gz(&archive_path, &[("gauntlet.toml", "[gauntlet]")]);
+
+        let target_dir = dir.path().join("target");
+        let err = PluginLoader::download_archive(&target_dir, &archive_source(&archive_path, Some("0".repeat(64))))
+            .unwrap_err();
+
+        assert!(err.to_string().contains("hash doesn't match"), "{:#}", err);
+        assert!(!target_dir.join("archive").exists());
+    }
+
+    #[test]
+    fn check_unpacked_size_limit() {
+        assert!(PluginLoader::check_unpacked_size(MAX_PLUGIN_UNPACKED_SIZE).is_ok());
+        assert!(PluginLoader::check_unpacked_size(MAX_PLUGIN_UNPACKED_SIZE + 1).is_err());
+    }
 }
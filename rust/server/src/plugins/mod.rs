@@ -1,9 +1,19 @@
 use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::Mutex;
+use std::time::Duration;
+use std::time::SystemTime;
 
+use anyhow::Context;
 use anyhow::anyhow;
 use gauntlet_common::dirs::Dirs;
+use gauntlet_common::model::CliDiagnostics;
+use gauntlet_common::model::CliEntrypointAction;
+use gauntlet_common::model::CliPluginSyncReport;
+use gauntlet_common::model::CliRunHistoryItem;
 use gauntlet_common::model::DownloadStatus;
 use gauntlet_common::model::EntrypointId;
+use gauntlet_common::model::EntrypointSearchAlias;
 use gauntlet_common::model::KeyboardEventOrigin;
 use gauntlet_common::model::LocalSaveData;
 use gauntlet_common::model::PhysicalKey;
@@ -11,13 +21,18 @@ use gauntlet_common::model::PhysicalShortcut;
 use gauntlet_common::model::PluginId;
 use gauntlet_common::model::PluginPreference;
 use gauntlet_common::model::PluginPreferenceUserData;
+use gauntlet_common::model::PluginSource;
 use gauntlet_common::model::PreferenceEnumValue;
 use gauntlet_common::model::SearchResult;
+use gauntlet_common::model::SearchResultEntrypointActionType;
 use gauntlet_common::model::SearchResultEntrypointType;
+use gauntlet_common::model::SearchScope;
+use gauntlet_common::model::ServerEvent;
 use gauntlet_common::model::SettingsEntrypoint;
 use gauntlet_common::model::SettingsEntrypointType;
 use gauntlet_common::model::SettingsGeneratedEntrypoint;
 use gauntlet_common::model::SettingsPlugin;
+use gauntlet_common::model::SettingsPluginSignature;
 use gauntlet_common::model::SettingsTheme;
 use gauntlet_common::model::UiPropertyValue;
 use gauntlet_common::model::UiSetupData;
@@ -27,6 +42,7 @@ use gauntlet_common::rpc::frontend_api::FrontendApi;
 use gauntlet_common::rpc::frontend_api::FrontendApiProxy;
 use gauntlet_common::rpc::frontend_api::FrontendApiRequestData;
 use gauntlet_common::rpc::frontend_api::FrontendApiResponseData;
+use gauntlet_common::rpc::transport::BackendTransport;
 use gauntlet_common_plugin_runtime::model::JsPluginCode;
 use gauntlet_common_plugin_runtime::model::JsPluginPermissionsExec;
 use gauntlet_common_plugin_runtime::model::JsPluginPermissionsFileSystem;
@@ -38,13 +54,20 @@ use include_dir::include_dir;
 use itertools::Itertools;
 
 use crate::plugins::clipboard::Clipboard;
-use crate::plugins::data_db_repository::DataDbRepository;
+use crate::plugins::config_sync::PluginSyncChange;
+use crate::plugins::config_sync::configured_plugin_id;
+use crate::plugins::config_sync::configured_plugin_source;
+use crate::plugins::config_sync::plan_plugin_sync;
+pub(crate) use crate::plugins::data_db_repository::DataDbRepository;
 use crate::plugins::data_db_repository::DbPluginClipboardPermissions;
 use crate::plugins::data_db_repository::DbPluginEntrypointType;
 use crate::plugins::data_db_repository::DbPluginMainSearchBarPermissions;
 use crate::plugins::data_db_repository::DbPluginPreference;
 use crate::plugins::data_db_repository::DbPluginPreferenceUserData;
+use crate::plugins::data_db_repository::DbPluginSignature;
+use crate::plugins::data_db_repository::DbPluginType;
 use crate::plugins::data_db_repository::db_entrypoint_from_str;
+use crate::plugins::data_db_repository::db_plugin_type_from_str;
 use crate::plugins::icon_cache::IconCache;
 use crate::plugins::js::AllPluginCommandData;
 use crate::plugins::js::OnePluginCommandData;
@@ -56,16 +79,20 @@ use crate::plugins::js::start_plugin_runtime;
 use crate::plugins::loader::PluginLoader;
 use crate::plugins::run_status::RunStatusHolder;
 pub(crate) use crate::plugins::settings::Settings;
+use crate::plugins::settings::config::PluginConfig;
 use crate::plugins::settings::global_shortcut::GlobalShortcutAction;
 use crate::plugins::settings::global_shortcut::GlobalShortcutPressedEvent;
+use crate::plugins::update_check::PluginUpdateChecker;
 use crate::search::EntrypointActionDataView;
 use crate::search::EntrypointActionType;
 use crate::search::EntrypointDataView;
 use crate::search::PluginDataView;
 use crate::search::SearchIndex;
+use crate::search::parse_search_scope;
 
 mod binary_data_gatherer;
 mod clipboard;
+mod config_sync;
 mod data_db_repository;
 mod download_status;
 pub(super) mod frecency;
@@ -75,7 +102,9 @@ mod loader;
 pub mod plugin_manifest;
 mod run_status;
 pub mod settings;
+mod signature;
 pub mod theme;
+mod update_check;
 
 static BUNDLED_PLUGINS: [(&str, Dir); 1] = [(
     "gauntlet",
@@ -85,14 +114,18 @@ static BUNDLED_PLUGINS: [(&str, Dir); 1] = [(
 pub struct ApplicationManager {
     search_index: SearchIndex,
     command_broadcaster: tokio::sync::broadcast::Sender<PluginCommand>,
+    event_broadcaster: tokio::sync::broadcast::Sender<ServerEvent>,
     db_repository: DataDbRepository,
     plugin_downloader: PluginLoader,
+    plugin_update_checker: PluginUpdateChecker,
     run_status_holder: RunStatusHolder,
     icon_cache: IconCache,
     frontend_api: FrontendApiProxy,
     dirs: Dirs,
     clipboard: Clipboard,
     settings: Settings,
+    last_search_query: Mutex<Option<String>>,
+    pending_search_event: Mutex<Option<String>>,
 }
 
 impl ApplicationManager {
@@ -103,17 +136,19 @@ impl ApplicationManager {
         let frontend_api = FrontendApiProxy::new(frontend_sender);
         let dirs = Dirs::new();
         let db_repository = DataDbRepository::new(dirs.clone())?;
-        let plugin_downloader = PluginLoader::new(db_repository.clone());
-        let icon_cache = IconCache::new(dirs.clone());
-        let run_status_holder = RunStatusHolder::new();
-        let clipboard = Clipboard::new()?;
+        let (event_broadcaster, _) = tokio::sync::broadcast::channel::<ServerEvent>(100);
         let settings = Settings::new(
             dirs.clone(),
             db_repository.clone(),
             frontend_api.clone(),
             layer_shell_supported,
         )?;
-        let search_index = SearchIndex::create_index(frontend_api.clone(), settings.clone())?;
+        let plugin_downloader = PluginLoader::new(db_repository.clone(), event_broadcaster.clone(), settings.config());
+        let plugin_update_checker = PluginUpdateChecker::new(db_repository.clone());
+        let icon_cache = IconCache::new(dirs.clone());
+        let run_status_holder = RunStatusHolder::new();
+        let clipboard = Clipboard::new()?;
+        let search_index = SearchIndex::create_index(frontend_api.clone(), settings.clone(), &dirs)?;
 
         let (command_broadcaster, _) = tokio::sync::broadcast::channel::<PluginCommand>(100);
 
@@ -122,14 +157,18 @@ impl ApplicationManager {
         let application_manager = Self {
             search_index,
             command_broadcaster,
+            event_broadcaster,
             db_repository,
             plugin_downloader,
+            plugin_update_checker,
             run_status_holder,
             icon_cache,
             frontend_api,
             clipboard,
             settings,
             dirs,
+            last_search_query: Mutex::new(None),
+            pending_search_event: Mutex::new(None),
         };
 
         #[cfg(not(feature = "scenario_runner"))]
@@ -142,9 +181,15 @@ impl ApplicationManager {
             tracing::error!("error loading dev plugin: {:?}", err);
         }
 
+        // stored search index is shown until plugins are started, but it can't contain plugins which will not be started
+        application_manager.remove_outdated_search_index_entries()?;
+
         #[cfg(not(feature = "scenario_runner"))]
         application_manager.reload_all_plugins()?;
 
+        #[cfg(not(feature = "scenario_runner"))]
+        application_manager.plugin_update_checker.start();
+
         Ok(application_manager)
     }
 
@@ -170,29 +215,479 @@ impl ApplicationManager {
         })
     }
 
-    pub fn download_plugin(&self, plugin_id: PluginId) {
-        self.plugin_downloader.download_plugin(plugin_id)
+    pub fn control_transport(&self) -> BackendTransport {
+        self.settings.config().control_transport.clone()
+    }
+
+    pub fn control_tools_server(&self) -> bool {
+        self.settings.config().control_tools_server
+    }
+
+    pub fn event_broadcaster(&self) -> tokio::sync::broadcast::Sender<ServerEvent> {
+        self.event_broadcaster.clone()
+    }
+
+    pub fn window_visibility_changed(&self, visible: bool) {
+        if visible {
+            self.send_event(ServerEvent::WindowShown)
+        } else {
+            *self.last_search_query.lock().expect("lock is poisoned") = None;
+
+            self.send_event(ServerEvent::WindowHidden)
+        }
+    }
+
+    pub fn download_plugin(&self, source: PluginSource) {
+        self.plugin_downloader.download_plugin(source)
     }
 
     pub fn download_status(&self) -> HashMap<PluginId, DownloadStatus> {
         self.plugin_downloader.download_status()
     }
 
-    pub fn search(&self, text: &str, render_inline_view: bool) -> anyhow::Result<Vec<SearchResult>> {
-        let result = self.search_index.search(&text);
+    /// Checks for new version of the plugin right away instead of waiting for periodic check
+    pub async fn check_plugin_update(&self, plugin_id: PluginId) -> anyhow::Result<()> {
+        let plugin = self.db_repository.get_plugin_by_id(&plugin_id.to_string())?;
+
+        let plugin_update_checker = self.plugin_update_checker.clone();
+
+        tokio::task::spawn_blocking(move || plugin_update_checker.check(plugin)).await??;
+
+        Ok(())
+    }
+
+    pub async fn update_plugin(&self, plugin_id: PluginId) -> anyhow::Result<()> {
+        tracing::info!(target = "plugin", "Updating plugin with id: {:?}", plugin_id);
+
+        let plugin = self.db_repository.get_plugin_by_id(&plugin_id.to_string())?;
+
+        // plugin is updated from the same branch, tag or commit it was installed from,
+        // remote archive can only be downloaded again if its content still has the same hash
+        let source = PluginSource {
+            git_ref: plugin.git_ref,
+            archive_sha256: plugin.archive_sha256,
+            ..PluginSource::new(plugin_id.clone())
+        };
+
+        self.plugin_downloader.install_plugin(source).await?;
+
+        self.plugin_update_checker.forget(&plugin_id);
 
-        if render_inline_view {
+        // runtime has to be restarted to run new code
+        self.reload_plugin(plugin_id)?;
+
+        Ok(())
+    }
+
+    /// Makes installed plugins and their settings match `[[plugins]]` list in config.
+    /// Plugins which are not installed yet are only listed in `installing` of the report,
+    /// they have to be installed by calling `install_plugins_from_config` afterwards
+    pub fn sync_plugins_with_config(
+        &self,
+        global_hotkey_manager: &Option<GlobalHotKeyManager>,
+        dry_run: bool,
+    ) -> anyhow::Result<CliPluginSyncReport> {
+        let (plugins_config, sync_config) = self.settings.plugins_config();
+
+        let Some(plugins_config) = plugins_config else {
+            return Ok(CliPluginSyncReport {
+                changes: vec![],
+                warnings: vec!["Config doesn't contain [[plugins]] list, nothing to sync".to_string()],
+                installing: vec![],
+            });
+        };
+
+        tracing::info!("Syncing plugins with config, dry run: {}", dry_run);
+
+        let global_shortcuts = self
+            .settings
+            .global_entrypoint_shortcuts()?
+            .into_iter()
+            .map(|(key, (shortcut, _))| (key, shortcut))
+            .collect();
+
+        let plan = plan_plugin_sync(
+            &plugins_config,
+            sync_config.remove_unlisted.unwrap_or(false),
+            self.db_repository.list_plugins_and_entrypoints()?,
+            &self.settings.entrypoint_search_aliases()?,
+            &global_shortcuts,
+        );
+
+        for warning in &plan.warnings {
+            tracing::warn!("{}", warning);
+        }
+
+        let changes = plan.changes.iter().map(|change| change.to_string()).collect();
+
+        let installing = if dry_run {
+            vec![]
+        } else {
+            self.apply_plugin_sync_changes(global_hotkey_manager, plan.changes)?
+        };
+
+        Ok(CliPluginSyncReport {
+            changes,
+            warnings: plan.warnings,
+            installing,
+        })
+    }
+
+    /// Downloads plugins listed in config and applies settings specified for them in config
+    pub async fn install_plugins_from_config(&self, plugin_ids: Vec<PluginId>) {
+        let (plugins_config, _) = self.settings.plugins_config();
+
+        let plugins_config = plugins_config.unwrap_or_default();
+
+        let installs = plugin_ids.into_iter().map(|plugin_id| {
+            let plugin_config = plugins_config
+                .iter()
+                .find(|plugin_config| configured_plugin_id(plugin_config) == plugin_id)
+                .cloned();
+
+            async move {
+                let Some(mut plugin_config) = plugin_config else {
+                    tracing::warn!("Plugin {:?} was removed from config before it was installed", plugin_id);
+                    return;
+                };
+
+                if let Err(err) = self.install_plugin_from_config(&mut plugin_config).await {
+                    tracing::warn!("Unable to install plugin {:?} from config: {:?}", plugin_id, err);
+                }
+            }
+        });
+
+        // downloads run at the same time, so all of them are reported as in progress right away
+        futures::future::join_all(installs).await;
+    }
+
+    async fn install_plugin_from_config(&self, plugin_config: &mut PluginConfig) -> anyhow::Result<()> {
+        let source = configured_plugin_source(plugin_config);
+        let plugin_id = source.plugin_id.clone();
+
+        tracing::info!(
+            target = "plugin",
+            "Installing plugin from config with id: {:?}",
+            plugin_id
+        );
+
+        let installed = self
+            .db_repository
+            .get_plugin_by_id_option(&plugin_id.to_string())?
+            .is_some();
+
+        self.plugin_downloader.install_plugin(source).await?;
+
+        // downloaded plugins are saved as disabled, but plugin listed in config is expected to be enabled by default,
+        // already installed plugin downloaded from different ref or archive keeps its state
+        if !installed {
+            plugin_config.enabled = Some(plugin_config.enabled.unwrap_or(true));
+        }
+
+        let global_shortcuts = self
+            .settings
+            .global_entrypoint_shortcuts()?
+            .into_iter()
+            .map(|(key, (shortcut, _))| (key, shortcut))
+            .collect();
+
+        let installed_plugins = self
+            .db_repository
+            .list_plugins_and_entrypoints()?
+            .into_iter()
+            .filter(|(plugin, _)| plugin.id == plugin_id.to_string())
+            .collect();
+
+        let plan = plan_plugin_sync(
+            std::slice::from_ref(plugin_config),
+            false,
+            installed_plugins,
+            &self.settings.entrypoint_search_aliases()?,
+            &global_shortcuts,
+        );
+
+        for warning in &plan.warnings {
+            tracing::warn!("{}", warning);
+        }
+
+        // global shortcuts were already set when sync was started
+        self.apply_plugin_sync_changes(&None, plan.changes)?;
+
+        Ok(())
+    }
+
+    // returns plugins which have to be installed
+    fn apply_plugin_sync_changes(
+        &self,
+        global_hotkey_manager: &Option<GlobalHotKeyManager>,
+        changes: Vec<PluginSyncChange>,
+    ) -> anyhow::Result<Vec<PluginId>> {
+        let mut to_install = vec![];
+        let mut to_reload = HashSet::new();
+        let mut plugin_states = vec![];
+
+        for change in changes {
+            tracing::info!("Applying change from config: {}", change);
+
+            match change {
+                PluginSyncChange::InstallPlugin { plugin_id }
+                | PluginSyncChange::SwitchPluginRef { plugin_id, .. }
+                | PluginSyncChange::SwitchPluginArchive { plugin_id, .. } => {
+                    to_install.push(plugin_id);
+                }
+                PluginSyncChange::RemovePlugin { plugin_id } => {
+                    self.remove_plugin(plugin_id)?;
+                }
+                PluginSyncChange::SetPluginEnabled {
+                    plugin_id,
+                    enabled,
+                    acknowledge_unsigned,
+                } => {
+                    plugin_states.push((plugin_id, enabled, acknowledge_unsigned));
+                }
+                PluginSyncChange::SetEntrypointEnabled {
+                    plugin_id,
+                    entrypoint_id,
+                    enabled,
+                } => {
+                    self.db_repository.set_plugin_entrypoint_enabled(
+                        &plugin_id.to_string(),
+                        &entrypoint_id.to_string(),
+                        enabled,
+                    )?;
+
+                    to_reload.insert(plugin_id);
+                }
+                PluginSyncChange::SetPreference {
+                    plugin_id,
+                    entrypoint_id,
+                    preference_id,
+                    value,
+                } => {
+                    self.db_repository.set_preference_value(
+                        plugin_id.to_string(),
+                        entrypoint_id.map(|id| id.to_string()),
+                        preference_id,
+                        plugin_preference_user_data_to_db(value),
+                    )?;
+
+                    to_reload.insert(plugin_id);
+                }
+                PluginSyncChange::SetSearchAliases {
+                    plugin_id,
+                    entrypoint_id,
+                    aliases,
+                } => {
+                    self.set_entrypoint_search_aliases(plugin_id, entrypoint_id, aliases)?;
+                }
+                PluginSyncChange::SetGlobalShortcut {
+                    plugin_id,
+                    entrypoint_id,
+                    shortcut,
+                } => {
+                    match global_hotkey_manager {
+                        Some(global_hotkey_manager) => {
+                            self.set_global_entrypoint_shortcut(
+                                global_hotkey_manager,
+                                plugin_id,
+                                entrypoint_id,
+                                Some(shortcut),
+                            )?;
+                        }
+                        None => {
+                            tracing::warn!(
+                                "Global shortcuts are not supported, shortcut of entrypoint {:?} of plugin {:?} is not set",
+                                entrypoint_id,
+                                plugin_id
+                            );
+                        }
+                    }
+                }
+            }
+        }
+
+        // plugin is restarted only once even if multiple of its settings have changed
+        for (plugin_id, enabled, acknowledge_unsigned) in plugin_states {
+            to_reload.remove(&plugin_id);
+
+            // one plugin which cannot be enabled shouldn't prevent the rest of config from being applied
+            if let Err(err) = self.set_plugin_state(plugin_id.clone(), enabled, acknowledge_unsigned) {
+                tracing::warn!(
+                    "Unable to change state of plugin {:?} from config: {:#}",
+                    plugin_id,
+                    err
+                );
+            }
+        }
+
+        for plugin_id in to_reload {
+            self.reload_plugin(plugin_id)?;
+        }
+
+        Ok(to_install)
+    }
+
+    /// Returns scope and rest of the query if query starts with scope keyword of one of the plugins
+    pub fn search_scope(&self, text: &str) -> anyhow::Result<Option<(SearchScope, String)>> {
+        let scopes = self.settings.plugin_search_scopes()?;
+
+        let Some((plugin_id, rest)) = parse_search_scope(text, &scopes) else {
+            return Ok(None);
+        };
+
+        let Some(plugin_data) = self.search_index.plugin_entrypoint_data().remove(&plugin_id) else {
+            return Ok(None);
+        };
+
+        let scope = SearchScope {
+            keyword: scopes[&plugin_id].clone(),
+            plugin_id,
+            plugin_name: plugin_data.plugin_name,
+        };
+
+        Ok(Some((scope, rest.to_owned())))
+    }
+
+    /// Sends search performed event for the last query typed in main window, if it wasn't sent already.
+    /// Called once user stops typing and when entrypoint is run, so that subscribers don't receive every keystroke
+    pub fn send_search_performed_event(&self) {
+        let query = self.pending_search_event.lock().expect("lock is poisoned").take();
+
+        if let Some(query) = query {
+            self.send_event(ServerEvent::SearchPerformed { query });
+        }
+    }
+
+    pub fn set_plugin_search_scope(&self, plugin_id: PluginId, keyword: Option<String>) -> anyhow::Result<()> {
+        self.settings.set_plugin_search_scope(plugin_id, keyword)
+    }
+
+    /// `typed_in_main_window` is set for queries typed by user in main window, inline views are rendered only for them
+    /// and only they are remembered to learn which entrypoint is picked for which query.
+    /// Search performed event is not sent right away, see [`Self::send_search_performed_event`]
+    pub fn search(
+        &self,
+        text: &str,
+        scope: Option<&PluginId>,
+        typed_in_main_window: bool,
+    ) -> anyhow::Result<Vec<SearchResult>> {
+        let query = normalize_search_query(text);
+
+        let query_frecency = self
+            .db_repository
+            .get_query_frecency(&query)?
+            .into_iter()
+            .map(|((plugin_id, entrypoint_id), frecency)| {
+                (
+                    (
+                        PluginId::from_string(plugin_id),
+                        EntrypointId::from_string(entrypoint_id),
+                    ),
+                    frecency,
+                )
+            })
+            .collect();
+
+        let show_recent = query.is_empty() && scope.is_none();
+
+        if typed_in_main_window {
+            *self.last_search_query.lock().expect("lock is poisoned") =
+                if query.is_empty() { None } else { Some(query) };
+
+            *self.pending_search_event.lock().expect("lock is poisoned") =
+                if text.is_empty() { None } else { Some(text.to_owned()) };
+        }
+
+        let result = self.search_index.search(&text, scope, &query_frecency);
+
+        let result = if show_recent {
+            result.and_then(|result| self.with_recent_search_results(result))
+        } else {
+            result
+        };
+
+        if typed_in_main_window {
             self.handle_inline_view(&text);
         }
 
         result
     }
 
+    /// Moves most recently run entrypoints to the beginning of the list, entrypoints which are not in search results are skipped
+    fn with_recent_search_results(&self, mut result: Vec<SearchResult>) -> anyhow::Result<Vec<SearchResult>> {
+        let history = self
+            .db_repository
+            .get_entrypoint_run_history(self.settings.run_history_retention_secs()?)?;
+
+        let limit = self.settings.recent_search_results_limit_setting()? as usize;
+
+        let mut recent = vec![];
+
+        for item in history {
+            if recent.len() >= limit {
+                break;
+            }
+
+            let position = result.iter().position(|search_result| {
+                search_result.plugin_id.to_string() == item.plugin_id
+                    && search_result.entrypoint_id.to_string() == item.entrypoint_id
+            });
+
+            // pinned entrypoints stay in pinned section
+            if let Some(position) = position.filter(|position| !result[*position].entrypoint_pinned) {
+                let mut search_result = result.remove(position);
+                search_result.entrypoint_last_run = Some(system_time_from_secs(item.run_at));
+
+                recent.push(search_result);
+            }
+        }
+
+        // pinned entrypoints go first when search query is empty, "Recent" section goes right after them
+        let pinned_count = result
+            .iter()
+            .take_while(|search_result| search_result.entrypoint_pinned)
+            .count();
+
+        result.splice(pinned_count..pinned_count, recent);
+
+        Ok(result)
+    }
+
+    pub fn run_history(&self) -> anyhow::Result<Vec<CliRunHistoryItem>> {
+        let plugins = self.search_index.plugin_entrypoint_data();
+
+        let result = self
+            .db_repository
+            .get_entrypoint_run_history(self.settings.run_history_retention_secs()?)?
+            .into_iter()
+            .filter_map(|item| {
+                let plugin_id = PluginId::from_string(item.plugin_id);
+                let entrypoint_id = EntrypointId::from_string(item.entrypoint_id);
+
+                // entrypoints of removed or disabled plugins are not shown
+                let plugin = plugins.get(&plugin_id)?;
+                let entrypoint = plugin.entrypoints.get(&entrypoint_id)?;
+
+                Some(CliRunHistoryItem {
+                    plugin_name: plugin.plugin_name.clone(),
+                    entrypoint_name: entrypoint.entrypoint_name.clone(),
+                    plugin_id,
+                    entrypoint_id,
+                    last_run: system_time_from_secs(item.run_at),
+                })
+            })
+            .collect();
+
+        Ok(result)
+    }
+
     pub async fn run_action(
         &self,
         plugin_id: PluginId,
         entrypoint_id: EntrypointId,
         action_id: String,
+        arguments: HashMap<String, String>,
+        query: Option<String>,
     ) -> anyhow::Result<()> {
         let data = self.search_index.plugin_entrypoint_data();
 
@@ -220,7 +715,7 @@ impl ApplicationManager {
             ":primary" => {
                 match entrypoint_type {
                     SearchResultEntrypointType::Command => {
-                        self.run_command(plugin_id, entrypoint_id);
+                        self.run_command(plugin_id, entrypoint_id, arguments, query);
                     }
                     SearchResultEntrypointType::View => {
                         self.frontend_api.open_plugin_view(plugin_id, entrypoint_id).await?;
@@ -234,7 +729,7 @@ impl ApplicationManager {
 
                         match action_type {
                             EntrypointActionType::Command => {
-                                self.request_run_generated_entrypoint(plugin_id, entrypoint_id, 0);
+                                self.request_run_generated_entrypoint(plugin_id, entrypoint_id, 0, arguments, query);
                             }
                             EntrypointActionType::View => {
                                 self.frontend_api
@@ -262,7 +757,7 @@ impl ApplicationManager {
 
                         match action_type {
                             EntrypointActionType::Command => {
-                                self.request_run_generated_entrypoint(plugin_id, entrypoint_id, 1);
+                                self.request_run_generated_entrypoint(plugin_id, entrypoint_id, 1, arguments, query);
                             }
                             EntrypointActionType::View => {
                                 self.frontend_api
@@ -299,7 +794,13 @@ impl ApplicationManager {
 
                                 match action_data.action_type {
                                     EntrypointActionType::Command => {
-                                        self.request_run_generated_entrypoint(plugin_id, entrypoint_id, index);
+                                        self.request_run_generated_entrypoint(
+                                            plugin_id,
+                                            entrypoint_id,
+                                            index,
+                                            arguments,
+                                            query,
+                                        );
                                     }
                                     EntrypointActionType::View => {
                                         self.frontend_api
@@ -317,6 +818,66 @@ impl ApplicationManager {
         Ok(())
     }
 
+    pub fn entrypoint_actions(
+        &self,
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+    ) -> anyhow::Result<Vec<CliEntrypointAction>> {
+        let data = self.search_index.plugin_entrypoint_data();
+
+        let Some(data) = data.get(&plugin_id) else {
+            return Err(anyhow!("Unable to find plugin with id: {}", plugin_id));
+        };
+
+        let Some(entrypoint_data) = data.entrypoints.get(&entrypoint_id) else {
+            return Err(anyhow!("Unable to find entrypoint with id: {}", entrypoint_id));
+        };
+
+        let actions = match entrypoint_data.entrypoint_type {
+            SearchResultEntrypointType::Command => {
+                vec![CliEntrypointAction {
+                    action_id: Some(":primary".to_string()),
+                    label: "Run Command".to_string(),
+                    action_type: SearchResultEntrypointActionType::Command,
+                }]
+            }
+            SearchResultEntrypointType::View => {
+                vec![CliEntrypointAction {
+                    action_id: Some(":primary".to_string()),
+                    label: "Open View".to_string(),
+                    action_type: SearchResultEntrypointActionType::View,
+                }]
+            }
+            SearchResultEntrypointType::Generated => {
+                entrypoint_data
+                    .actions
+                    .iter()
+                    .enumerate()
+                    .map(|(index, action)| {
+                        // same resolution as in run_action
+                        let action_id = match (&action.id, index) {
+                            (Some(id), _) => Some(id.clone()),
+                            (None, 0) => Some(":primary".to_string()),
+                            (None, 1) => Some(":secondary".to_string()),
+                            (None, _) => None,
+                        };
+
+                        CliEntrypointAction {
+                            action_id,
+                            label: action.label.clone(),
+                            action_type: match action.action_type {
+                                EntrypointActionType::Command => SearchResultEntrypointActionType::Command,
+                                EntrypointActionType::View => SearchResultEntrypointActionType::View,
+                            },
+                        }
+                    })
+                    .collect()
+            }
+        };
+
+        Ok(actions)
+    }
+
     pub fn save_local_plugin(&self, path: &str) -> anyhow::Result<LocalSaveData> {
         tracing::info!(target = "plugin", "Saving local plugin at path: {:?}", path);
 
@@ -390,6 +951,8 @@ impl ApplicationManager {
             })
             .collect();
 
+        let search_scopes = self.settings.plugin_search_scopes()?;
+
         let result = self
             .db_repository
             .list_plugins_and_entrypoints()?
@@ -453,6 +1016,11 @@ impl ApplicationManager {
                     plugin_name: plugin.name,
                     plugin_description: plugin.description,
                     enabled: plugin.enabled,
+                    search_scope: search_scopes.get(&plugin_id).cloned(),
+                    update_available: self.plugin_update_checker.is_update_available(&plugin_id),
+                    signature: self.plugin_signature(&plugin_id, &plugin.plugin_type, &plugin.signature),
+                    installed_commit: plugin.installed_commit,
+                    git_ref: plugin.git_ref,
                     entrypoints,
                     preferences: plugin
                         .preferences
@@ -476,7 +1044,13 @@ impl ApplicationManager {
         Ok(result)
     }
 
-    pub fn set_plugin_state(&self, plugin_id: PluginId, set_enabled: bool) -> anyhow::Result<()> {
+    /// Plugins which are not signed by trusted key are enabled only if `acknowledge_unsigned` is set
+    pub fn set_plugin_state(
+        &self,
+        plugin_id: PluginId,
+        set_enabled: bool,
+        acknowledge_unsigned: bool,
+    ) -> anyhow::Result<()> {
         let currently_running = self.run_status_holder.is_plugin_running(&plugin_id);
         let currently_enabled = self.is_plugin_enabled(&plugin_id)?;
 
@@ -491,6 +1065,8 @@ impl ApplicationManager {
 
         match (currently_running, currently_enabled, set_enabled) {
             (false, false, true) => {
+                self.check_unsigned_acknowledged(&plugin_id, acknowledge_unsigned)?;
+
                 self.db_repository.set_plugin_enabled(&plugin_id.to_string(), true)?;
 
                 self.start_plugin(plugin_id)?;
@@ -573,27 +1149,56 @@ impl ApplicationManager {
         self.settings.global_entrypoint_shortcuts()
     }
 
-    pub fn set_entrypoint_search_alias(
+    pub fn set_entrypoint_search_aliases(
         &self,
         plugin_id: PluginId,
         entrypoint_id: EntrypointId,
-        alias: Option<String>,
+        aliases: Vec<EntrypointSearchAlias>,
     ) -> anyhow::Result<()> {
         self.settings
-            .set_entrypoint_search_alias(plugin_id.clone(), entrypoint_id.clone(), alias.clone())?;
+            .set_entrypoint_search_aliases(plugin_id.clone(), entrypoint_id.clone(), aliases.clone())?;
 
         self.search_index
-            .set_entrypoint_search_alias(plugin_id, entrypoint_id, alias)?;
+            .set_entrypoint_search_aliases(plugin_id, entrypoint_id, aliases)?;
 
         Ok(())
     }
 
-    pub fn get_entrypoint_search_aliases(&self) -> anyhow::Result<HashMap<(PluginId, EntrypointId), String>> {
+    pub fn get_entrypoint_search_aliases(
+        &self,
+    ) -> anyhow::Result<HashMap<(PluginId, EntrypointId), Vec<EntrypointSearchAlias>>> {
         self.settings.entrypoint_search_aliases()
     }
 
+    pub fn set_entrypoint_pinned(
+        &self,
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        pinned: bool,
+    ) -> anyhow::Result<()> {
+        self.settings.set_entrypoint_pinned(plugin_id, entrypoint_id, pinned)
+    }
+
+    pub fn set_pinned_entrypoint_position(
+        &self,
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        position: usize,
+    ) -> anyhow::Result<()> {
+        self.settings
+            .set_pinned_entrypoint_position(plugin_id, entrypoint_id, position)
+    }
+
+    pub fn get_pinned_entrypoints(&self) -> anyhow::Result<Vec<(PluginId, EntrypointId)>> {
+        self.settings.pinned_entrypoints()
+    }
+
     pub async fn set_theme(&self, theme: SettingsTheme) -> anyhow::Result<()> {
-        self.settings.set_theme_setting(theme).await
+        self.settings.set_theme_setting(theme.clone()).await?;
+
+        self.send_event(ServerEvent::ThemeChanged { theme });
+
+        Ok(())
     }
 
     #[cfg(feature = "scenario_runner")]
@@ -613,6 +1218,92 @@ impl ApplicationManager {
         self.settings.window_position_mode_setting()
     }
 
+    pub fn set_fuzzy_search(&self, enabled: bool) -> anyhow::Result<()> {
+        self.settings.set_fuzzy_search_setting(enabled)
+    }
+
+    pub fn get_fuzzy_search(&self) -> anyhow::Result<bool> {
+        self.settings.fuzzy_search_setting()
+    }
+
+    pub fn set_frecency_half_life_days(&self, days: u32) -> anyhow::Result<()> {
+        self.settings.set_frecency_half_life_days_setting(days)?;
+
+        self.request_search_index_refresh_all();
+
+        Ok(())
+    }
+
+    pub fn get_frecency_half_life_days(&self) -> anyhow::Result<u32> {
+        self.settings.frecency_half_life_days_setting()
+    }
+
+    pub fn set_run_history_retention_days(&self, days: u32) -> anyhow::Result<()> {
+        self.settings.set_run_history_retention_days_setting(days)
+    }
+
+    pub fn get_run_history_retention_days(&self) -> anyhow::Result<u32> {
+        self.settings.run_history_retention_days_setting()
+    }
+
+    pub async fn set_recent_search_results_limit(&self, limit: u32) -> anyhow::Result<()> {
+        self.settings.set_recent_search_results_limit_setting(limit)?;
+
+        // "Recent" section of search results has to be updated, search index itself is not affected
+        self.frontend_api.request_search_results_update().await?;
+
+        Ok(())
+    }
+
+    pub fn get_recent_search_results_limit(&self) -> anyhow::Result<u32> {
+        self.settings.recent_search_results_limit_setting()
+    }
+
+    pub async fn clear_run_history(&self) -> anyhow::Result<()> {
+        tracing::info!("Clearing run history");
+
+        self.db_repository.clear_entrypoint_run_history()?;
+
+        // "Recent" section of search results has to be updated, search index itself is not affected
+        self.frontend_api.request_search_results_update().await?;
+
+        Ok(())
+    }
+
+    pub fn reset_frecency(&self, plugin_id: PluginId, entrypoint_id: Option<EntrypointId>) -> anyhow::Result<()> {
+        tracing::info!(
+            "Resetting frecency for plugin id: {:?}, entrypoint_id: {:?}",
+            plugin_id,
+            entrypoint_id
+        );
+
+        self.db_repository.reset_frecency(
+            &plugin_id.to_string(),
+            entrypoint_id.map(|id| id.to_string()).as_deref(),
+        )?;
+
+        self.request_search_index_refresh(plugin_id);
+
+        Ok(())
+    }
+
+    pub fn export_frecency(&self) -> anyhow::Result<String> {
+        let data = self.db_repository.export_frecency()?;
+
+        Ok(serde_json::to_string_pretty(&data)?)
+    }
+
+    pub fn import_frecency(&self, data: String) -> anyhow::Result<()> {
+        let data = serde_json::from_str(&data).context("Unable to parse frecency data")?;
+
+        self.db_repository
+            .import_frecency(data, self.settings.frecency_half_life_secs()?)?;
+
+        self.request_search_index_refresh_all();
+
+        Ok(())
+    }
+
     pub fn set_preference_value(
         &self,
         plugin_id: PluginId,
@@ -642,6 +1333,57 @@ impl ApplicationManager {
         Ok(())
     }
 
+    pub fn diagnostics(&self) -> anyhow::Result<CliDiagnostics> {
+        let config = self.settings.config();
+
+        let global_shortcut_error = self.settings.global_shortcut()?.and_then(|(_, error)| error);
+
+        let global_entrypoint_shortcut_errors = self
+            .settings
+            .global_entrypoint_shortcuts()?
+            .into_iter()
+            .filter_map(|((plugin_id, entrypoint_id), (_, error))| error.map(|error| (plugin_id, entrypoint_id, error)))
+            .collect();
+
+        let not_running_plugins = self
+            .db_repository
+            .list_plugins()?
+            .into_iter()
+            .filter(|plugin| plugin.enabled)
+            .map(|plugin| (PluginId::from_string(plugin.id), plugin.name))
+            .filter(|(plugin_id, _)| !self.run_status_holder.is_plugin_running(plugin_id))
+            .collect();
+
+        Ok(CliDiagnostics {
+            close_on_unfocus: config.close_on_unfocus,
+            layer_shell: config.layer_shell,
+            wayland_use_legacy_x11_api: config.wayland_use_legacy_x11_api,
+            linux_native_hud: config.linux_native_hud,
+            global_shortcut_error,
+            global_entrypoint_shortcut_errors,
+            not_running_plugins,
+        })
+    }
+
+    pub async fn shutdown(&self) -> anyhow::Result<()> {
+        tracing::info!("Shutting down, stopping all plugins");
+
+        self.run_status_holder.stop_all_plugins();
+
+        let stopped = self
+            .run_status_holder
+            .wait_for_all_runtimes_to_exit(Duration::from_secs(5))
+            .await;
+
+        if !stopped {
+            tracing::warn!("Some plugin runtimes did not stop in time, proceeding with shutdown anyway");
+        }
+
+        self.db_repository.flush()?;
+
+        Ok(())
+    }
+
     pub fn reload_all_plugins(&self) -> anyhow::Result<()> {
         tracing::info!("Reloading all plugins");
 
@@ -650,6 +1392,11 @@ impl ApplicationManager {
             let running = self.run_status_holder.is_plugin_running(&plugin_id);
             match (running, plugin.enabled) {
                 (false, true) => {
+                    if let Err(err) = self.check_plugin_signature(&plugin_id, &plugin.plugin_type, &plugin.signature) {
+                        tracing::warn!("Plugin {:?} is not started: {:#}", plugin_id, err);
+                        continue;
+                    }
+
                     self.start_plugin(plugin_id)?;
                 }
                 (true, false) => {
@@ -663,6 +1410,20 @@ impl ApplicationManager {
         Ok(())
     }
 
+    fn remove_outdated_search_index_entries(&self) -> anyhow::Result<()> {
+        let plugin_versions = self
+            .db_repository
+            .list_plugins()?
+            .into_iter()
+            .filter(|plugin| plugin.enabled)
+            .map(|plugin| (PluginId::from_string(plugin.id), plugin.code.version()))
+            .collect();
+
+        self.search_index.retain_plugins(&plugin_versions)?;
+
+        Ok(())
+    }
+
     pub fn save_local_dev_plugin(&self) -> anyhow::Result<()> {
         let plugin_path = concat!(env!("CARGO_MANIFEST_DIR"), "/../../dev_plugin").to_owned();
         let plugin_path = std::fs::canonicalize(plugin_path).expect("valid path");
@@ -681,6 +1442,11 @@ impl ApplicationManager {
             self.stop_plugin(plugin_id.clone());
         }
         self.db_repository.remove_plugin(&plugin_id.to_string())?;
+        // pins and search scope are stored in settings, not in plugin tables,
+        // so they are not removed together with the plugin
+        self.settings.remove_plugin_pinned_entrypoints(&plugin_id)?;
+        self.settings.remove_plugin_search_scope(&plugin_id)?;
+        self.plugin_update_checker.forget(&plugin_id);
         self.search_index.remove_for_plugin(plugin_id)?;
         Ok(())
     }
@@ -691,15 +1457,23 @@ impl ApplicationManager {
         })
     }
 
-    pub fn run_command(&self, plugin_id: PluginId, entrypoint_id: EntrypointId) {
+    pub fn run_command(
+        &self,
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        arguments: HashMap<String, String>,
+        query: Option<String>,
+    ) {
         self.send_command(PluginCommand::One {
             id: plugin_id.clone(),
             data: OnePluginCommandData::RunCommand {
                 entrypoint_id: entrypoint_id.to_string(),
+                arguments,
+                query,
             },
         });
 
-        self.mark_entrypoint_frecency(plugin_id, entrypoint_id)
+        self.mark_entrypoint_run(plugin_id, entrypoint_id)
     }
 
     pub fn request_run_generated_entrypoint(
@@ -707,31 +1481,37 @@ impl ApplicationManager {
         plugin_id: PluginId,
         entrypoint_id: EntrypointId,
         action_index: usize,
+        arguments: HashMap<String, String>,
+        query: Option<String>,
     ) {
         self.send_command(PluginCommand::One {
             id: plugin_id.clone(),
             data: OnePluginCommandData::RunGeneratedEntrypoint {
                 entrypoint_id: entrypoint_id.to_string(),
                 action_index,
+                arguments,
+                query,
             },
         });
 
-        self.mark_entrypoint_frecency(plugin_id, entrypoint_id)
+        self.mark_entrypoint_run(plugin_id, entrypoint_id)
     }
 
     pub fn request_render_view(
         &self,
         plugin_id: PluginId,
         entrypoint_id: EntrypointId,
+        query: Option<String>,
     ) -> anyhow::Result<HashMap<String, PhysicalShortcut>> {
         self.send_command(PluginCommand::One {
             id: plugin_id.clone(),
             data: OnePluginCommandData::OpenView {
                 entrypoint_id: entrypoint_id.clone(),
+                query,
             },
         });
 
-        self.mark_entrypoint_frecency(plugin_id.clone(), entrypoint_id.clone());
+        self.mark_entrypoint_run(plugin_id.clone(), entrypoint_id.clone());
 
         let shortcuts = self.action_shortcuts(plugin_id, entrypoint_id)?;
 
@@ -801,6 +1581,12 @@ impl ApplicationManager {
         })
     }
 
+    fn request_search_index_refresh_all(&self) {
+        self.send_command(PluginCommand::All {
+            data: AllPluginCommandData::RefreshSearchIndex,
+        })
+    }
+
     pub fn handle_open(&self, href: String) {
         match open::that_detached(&href) {
             Ok(()) => tracing::info!("Opened '{}' successfully.", href),
@@ -837,6 +1623,68 @@ impl ApplicationManager {
         Ok(())
     }
 
+    fn plugin_signature(
+        &self,
+        plugin_id: &PluginId,
+        plugin_type: &str,
+        signature: &Option<DbPluginSignature>,
+    ) -> SettingsPluginSignature {
+        let downloaded = matches!(db_plugin_type_from_str(plugin_type), DbPluginType::Normal);
+
+        if !downloaded || plugin_id.is_development() {
+            return SettingsPluginSignature::NotChecked;
+        }
+
+        match signature {
+            // plugins downloaded before signatures were introduced
+            None | Some(DbPluginSignature::Unsigned) => SettingsPluginSignature::Unsigned,
+            Some(DbPluginSignature::Untrusted) => SettingsPluginSignature::Untrusted,
+            // key may have been removed from config since plugin was downloaded
+            Some(DbPluginSignature::Signed { public_key }) => {
+                if self.settings.config().plugin_trusted_keys.contains(public_key) {
+                    SettingsPluginSignature::Trusted
+                } else {
+                    SettingsPluginSignature::Untrusted
+                }
+            }
+        }
+    }
+
+    fn check_plugin_signature(
+        &self,
+        plugin_id: &PluginId,
+        plugin_type: &str,
+        signature: &Option<DbPluginSignature>,
+    ) -> anyhow::Result<()> {
+        if !self.settings.config().plugin_signatures_required {
+            return Ok(());
+        }
+
+        match self.plugin_signature(plugin_id, plugin_type, signature) {
+            SettingsPluginSignature::NotChecked | SettingsPluginSignature::Trusted => Ok(()),
+            SettingsPluginSignature::Untrusted | SettingsPluginSignature::Unsigned => {
+                Err(anyhow!(
+                    "Plugin is not signed by any of trusted keys, signatures are required by config"
+                ))
+            }
+        }
+    }
+
+    fn check_unsigned_acknowledged(&self, plugin_id: &PluginId, acknowledge_unsigned: bool) -> anyhow::Result<()> {
+        let plugin = self.db_repository.get_plugin_by_id(&plugin_id.to_string())?;
+
+        let signature = self.plugin_signature(plugin_id, &plugin.plugin_type, &plugin.signature);
+
+        if signature.requires_confirmation() && !acknowledge_unsigned {
+            return Err(anyhow!(
+                "Plugin {} is not signed by any of trusted keys, enabling it has to be explicitly allowed",
+                plugin_id
+            ));
+        }
+
+        Ok(())
+    }
+
     fn is_plugin_enabled(&self, plugin_id: &PluginId) -> anyhow::Result<bool> {
         self.db_repository.is_plugin_enabled(&plugin_id.to_string())
     }
@@ -855,6 +1703,8 @@ impl ApplicationManager {
 
         let plugin = self.db_repository.get_plugin_by_id(&plugin_id_str)?;
 
+        self.check_plugin_signature(&plugin_id, &plugin.plugin_type, &plugin.signature)?;
+
         let entrypoint_names = self
             .db_repository
             .get_entrypoints_by_plugin_id(&plugin_id_str)?
@@ -922,6 +1772,7 @@ impl ApplicationManager {
             settings: self.settings.clone(),
             dirs: self.dirs.clone(),
             clipboard: self.clipboard.clone(),
+            event_broadcaster: self.event_broadcaster.clone(),
         };
 
         let run_status_guard = self.run_status_holder.start_block(data.id.clone());
@@ -946,10 +1797,43 @@ impl ApplicationManager {
         let _ = self.command_broadcaster.send(command);
     }
 
+    fn send_event(&self, event: ServerEvent) {
+        // it is possible to have 0 subscribers
+        let _ = self.event_broadcaster.send(event);
+    }
+
+    fn mark_entrypoint_run(&self, plugin_id: PluginId, entrypoint_id: EntrypointId) {
+        // entrypoint can be run before search debounce has finished
+        self.send_search_performed_event();
+
+        self.send_event(ServerEvent::EntrypointRun {
+            plugin_id: plugin_id.clone(),
+            entrypoint_id: entrypoint_id.clone(),
+        });
+
+        self.mark_entrypoint_frecency(plugin_id, entrypoint_id)
+    }
+
     fn mark_entrypoint_frecency(&self, plugin_id: PluginId, entrypoint_id: EntrypointId) {
-        let result = self
-            .db_repository
-            .mark_entrypoint_frecency(&plugin_id.to_string(), &entrypoint_id.to_string());
+        // remember which query led to this entrypoint, so it can be ranked higher next time the same thing is typed,
+        // query is used only once, so that entrypoints run later from cli or using shortcut are not attributed to it
+        let query_prefixes = self
+            .last_search_query
+            .lock()
+            .expect("lock is poisoned")
+            .take()
+            .as_deref()
+            .map(search_query_prefixes)
+            .unwrap_or_default();
+
+        let result = self.settings.frecency_half_life_secs().and_then(|half_life| {
+            self.db_repository.mark_entrypoint_frecency(
+                &plugin_id.to_string(),
+                &entrypoint_id.to_string(),
+                &query_prefixes,
+                half_life,
+            )
+        });
 
         if let Err(err) = &result {
             tracing::warn!(
@@ -959,6 +1843,19 @@ impl ApplicationManager {
             )
         }
 
+        let result = self.settings.run_history_retention_secs().and_then(|retention| {
+            self.db_repository
+                .add_entrypoint_run_history(&plugin_id.to_string(), &entrypoint_id.to_string(), retention)
+        });
+
+        if let Err(err) = &result {
+            tracing::warn!(
+                target = "rpc",
+                "error occurred when adding entrypoint to run history {:?}",
+                err
+            )
+        }
+
         self.request_search_index_refresh(plugin_id);
     }
 
@@ -1103,3 +2000,23 @@ fn plugin_preference_user_data_from_db(value: DbPluginPreferenceUserData) -> Plu
         DbPluginPreferenceUserData::ListOfEnums { value, .. } => PluginPreferenceUserData::ListOfEnums { value },
     }
 }
+
+const MAX_QUERY_PREFIX_LENGTH: usize = 32;
+
+fn normalize_search_query(query: &str) -> String {
+    query.split_whitespace().collect::<Vec<_>>().join(" ").to_lowercase()
+}
+
+/// All prefixes of the query, so that entrypoint picked after typing "term" is also preferred when typing "te"
+fn search_query_prefixes(query: &str) -> Vec<String> {
+    let chars = query.chars().take(MAX_QUERY_PREFIX_LENGTH).collect::<Vec<_>>();
+
+    (1..=chars.len())
+        .map(|len| chars[..len].iter().collect::<String>())
+        .filter(|prefix| !prefix.ends_with(' '))
+        .collect()
+}
+
+fn system_time_from_secs(secs: f64) -> SystemTime {
+    SystemTime::UNIX_EPOCH + Duration::from_secs_f64(secs)
+}
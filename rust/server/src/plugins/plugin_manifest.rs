@@ -34,6 +34,9 @@ pub struct PluginManifestEntrypoint {
     pub name: String,
     #[schemars(description = "Entrypoint description")]
     pub description: String,
+    #[serde(default)]
+    #[schemars(description = "List of additional words by which entrypoint can be found in search")]
+    pub keywords: Vec<String>,
     #[allow(unused)] // Used during plugin build
     #[schemars(description = "Path to TypeScript file relative to package directory")]
     path: String,
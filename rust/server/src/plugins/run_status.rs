@@ -1,6 +1,7 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 use std::sync::Mutex;
+use std::time::Duration;
 
 use gauntlet_common::model::PluginId;
 use tokio_util::sync::CancellationToken;
@@ -8,21 +9,27 @@ use tokio_util::sync::WaitForCancellationFutureOwned;
 
 pub struct RunStatusHolder {
     running_plugins: Arc<Mutex<HashMap<PluginId, CancellationToken>>>,
+    // every runtime holds a clone until it exits, including the ones that were asked to stop
+    runtimes: Arc<()>,
 }
 
 impl RunStatusHolder {
     pub fn new() -> Self {
         Self {
             running_plugins: Arc::new(Mutex::new(HashMap::new())),
+            runtimes: Arc::new(()),
         }
     }
 
     pub fn start_block(&self, plugin_id: PluginId) -> RunStatusGuard {
         let mut running_plugins = self.running_plugins.lock().expect("lock is poisoned");
-        running_plugins.insert(plugin_id.clone(), CancellationToken::new());
+        let token = CancellationToken::new();
+        running_plugins.insert(plugin_id.clone(), token.clone());
         RunStatusGuard {
             running_plugins: self.running_plugins.clone(),
             id: plugin_id,
+            token,
+            _runtime: self.runtimes.clone(),
         }
     }
 
@@ -34,26 +41,52 @@ impl RunStatusHolder {
     pub fn stop_plugin(&self, plugin_id: &PluginId) {
         let mut running_plugins = self.running_plugins.lock().expect("lock is poisoned");
 
-        running_plugins
-            .remove(plugin_id)
-            .expect("value should always exist for specified id")
-            .cancel()
+        // runtime could have exited on its own between running check and this call
+        if let Some(token) = running_plugins.remove(plugin_id) {
+            token.cancel()
+        }
+    }
+
+    pub fn stop_all_plugins(&self) {
+        let mut running_plugins = self.running_plugins.lock().expect("lock is poisoned");
+
+        for (_, token) in running_plugins.drain() {
+            token.cancel()
+        }
+    }
+
+    /// Returns `false` if some runtimes are still alive after timeout
+    pub async fn wait_for_all_runtimes_to_exit(&self, timeout: Duration) -> bool {
+        let wait = async {
+            while Arc::strong_count(&self.runtimes) > 1 {
+                tokio::time::sleep(Duration::from_millis(50)).await;
+            }
+        };
+
+        tokio::time::timeout(timeout, wait).await.is_ok()
     }
 }
 
 pub struct RunStatusGuard {
     id: PluginId,
     running_plugins: Arc<Mutex<HashMap<PluginId, CancellationToken>>>,
+    token: CancellationToken,
+    _runtime: Arc<()>,
 }
 
 impl RunStatusGuard {
     pub fn stopped(&self) -> WaitForCancellationFutureOwned {
-        let running_plugins = self.running_plugins.lock().expect("lock is poisoned");
+        self.token.clone().cancelled_owned()
+    }
+}
 
-        running_plugins
-            .get(&self.id)
-            .expect("value should always exist for specified id")
-            .clone()
-            .cancelled_owned()
+impl Drop for RunStatusGuard {
+    fn drop(&mut self) {
+        // runtime exited without being asked to stop, e.g. it crashed.
+        // if it was asked to stop, entry is already removed or belongs to a new runtime
+        if !self.token.is_cancelled() {
+            let mut running_plugins = self.running_plugins.lock().expect("lock is poisoned");
+            running_plugins.remove(&self.id);
+        }
     }
 }
@@ -1,3 +1,7 @@
+use std::collections::HashMap;
+
+use gauntlet_common::rpc::transport::BackendTransport;
+use gauntlet_common::rpc::transport::ControlConfig;
 use serde::Deserialize;
 
 #[derive(Deserialize, Debug, Default)]
@@ -6,6 +10,11 @@ pub struct ApplicationConfig {
     pub main_window: Option<ApplicationWindowConfig>,
     pub wayland: Option<WaylandConfig>,
     pub linux: Option<LinuxConfig>,
+    pub control: Option<ControlConfig>,
+    pub search: Option<SearchConfig>,
+    pub plugins: Option<Vec<PluginConfig>>,
+    pub plugin_sync: Option<PluginSyncConfig>,
+    pub plugin_signatures: Option<PluginSignaturesConfig>,
 }
 
 #[derive(Deserialize, Debug, Default)]
@@ -24,6 +33,69 @@ pub struct LinuxConfig {
     pub native_hud: Option<bool>,
 }
 
+#[derive(Deserialize, Debug, Default)]
+pub struct SearchConfig {
+    pub initials_boost: Option<f64>,
+    pub match_weight: Option<f64>,
+    pub frecency_weight: Option<f64>,
+    pub query_frecency_weight: Option<f64>,
+}
+
+#[derive(Deserialize, Debug, Default)]
+pub struct PluginSignaturesConfig {
+    // base64 encoded ed25519 public keys
+    pub trusted_keys: Option<Vec<String>>,
+    // refuse to install and run downloaded plugins which are not signed by one of trusted keys
+    pub required: Option<bool>,
+}
+
+#[derive(Deserialize, Debug, Default)]
+pub struct PluginSyncConfig {
+    // remove installed plugins which are not listed in [[plugins]], bundled and local plugins are never removed
+    pub remove_unlisted: Option<bool>,
+}
+
+// settings which are not specified are left as is and can be changed in settings ui
+#[derive(Deserialize, Debug, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct PluginConfig {
+    pub id: String,
+    #[serde(rename = "ref")]
+    pub git_ref: Option<String>,
+    // expected hash if id is an url of plugin archive
+    pub sha256: Option<String>,
+    pub enabled: Option<bool>,
+    // allows enabling plugin which is not signed by trusted key
+    pub allow_unsigned: Option<bool>,
+    #[serde(default)]
+    pub preferences: HashMap<String, PreferenceValueConfig>,
+    #[serde(default)]
+    pub entrypoints: HashMap<String, PluginEntrypointConfig>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct PluginEntrypointConfig {
+    pub enabled: Option<bool>,
+    #[serde(default)]
+    pub preferences: HashMap<String, PreferenceValueConfig>,
+    pub aliases: Option<Vec<String>>,
+    pub keyword_aliases: Option<Vec<String>>,
+    // e.g. "Control+Shift+KeyK"
+    pub shortcut: Option<String>,
+}
+
+// type of the value is checked against preference declared in plugin manifest
+#[derive(Deserialize, Debug, Clone)]
+#[serde(untagged)]
+pub enum PreferenceValueConfig {
+    Bool(bool),
+    Number(f64),
+    String(String),
+    ListOfNumbers(Vec<f64>),
+    ListOfStrings(Vec<String>),
+}
+
 #[derive(Deserialize, Debug)]
 pub enum WaylandMainWindowConfig {
     #[serde(rename = "prefer_wlr_layer_shell")] // default
@@ -47,4 +119,12 @@ pub struct EffectiveConfig {
     pub layer_shell: bool,
     pub wayland_use_legacy_x11_api: bool,
     pub linux_native_hud: bool,
+    pub control_transport: BackendTransport,
+    pub control_tools_server: bool,
+    pub search_initials_boost: f64,
+    pub search_match_weight: f64,
+    pub search_frecency_weight: f64,
+    pub search_query_frecency_weight: f64,
+    pub plugin_trusted_keys: Vec<String>,
+    pub plugin_signatures_required: bool,
 }
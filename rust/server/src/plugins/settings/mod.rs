@@ -1,4 +1,4 @@
-mod config;
+pub mod config;
 pub mod config_reader;
 pub mod global_shortcut;
 
@@ -9,6 +9,7 @@ use anyhow::anyhow;
 use dark_light::Mode;
 use gauntlet_common::dirs::Dirs;
 use gauntlet_common::model::EntrypointId;
+use gauntlet_common::model::EntrypointSearchAlias;
 use gauntlet_common::model::PhysicalShortcut;
 use gauntlet_common::model::PluginId;
 use gauntlet_common::model::SettingsTheme;
@@ -16,14 +17,19 @@ use gauntlet_common::model::UiTheme;
 use gauntlet_common::model::WindowPositionMode;
 use gauntlet_common::rpc::frontend_api::FrontendApi;
 use gauntlet_common::rpc::frontend_api::FrontendApiProxy;
+use gauntlet_common::rpc::transport::BackendTransport;
 use global_hotkey::GlobalHotKeyManager;
 
 use crate::plugins::data_db_repository::DataDbRepository;
 use crate::plugins::data_db_repository::DbSettingsEntrypointSearchAliasData;
+use crate::plugins::data_db_repository::DbSettingsPinnedEntrypointData;
+use crate::plugins::data_db_repository::DbSettingsPluginSearchScopeData;
 use crate::plugins::data_db_repository::DbTheme;
 use crate::plugins::data_db_repository::DbWindowPositionMode;
 use crate::plugins::settings::config::ApplicationConfig;
 use crate::plugins::settings::config::EffectiveConfig;
+use crate::plugins::settings::config::PluginConfig;
+use crate::plugins::settings::config::PluginSyncConfig;
 use crate::plugins::settings::config::WaylandGlobalShortcutConfig;
 use crate::plugins::settings::config::WaylandMainWindowConfig;
 use crate::plugins::settings::config_reader::ConfigReader;
@@ -33,12 +39,17 @@ use crate::plugins::settings::global_shortcut::GlobalShortcutSettings;
 use crate::plugins::theme::BundledThemes;
 use crate::plugins::theme::read_theme_file;
 
+const DEFAULT_FRECENCY_HALF_LIFE_DAYS: u32 = 3;
+const DEFAULT_RUN_HISTORY_RETENTION_DAYS: u32 = 30;
+const DEFAULT_RECENT_SEARCH_RESULTS_LIMIT: u32 = 5;
+
 #[derive(Clone)]
 pub struct Settings {
     dirs: Dirs,
     repository: DataDbRepository,
     frontend_api: FrontendApiProxy,
     config: Arc<EffectiveConfig>,
+    config_reader: ConfigReader,
     global_hotkey_settings: GlobalShortcutSettings,
     themes: Arc<BundledThemes>,
 }
@@ -59,11 +70,21 @@ impl Settings {
             repository: repository.clone(),
             frontend_api,
             global_hotkey_settings: GlobalShortcutSettings::new(repository)?,
-            config: Arc::new(effective_config(config, layer_shell_supported)),
+            config: Arc::new(effective_config(&dirs, config, layer_shell_supported)),
+            config_reader,
             themes: Arc::new(BundledThemes::new()?),
         })
     }
 
+    /// Unlike the rest of the config, plugin list is read from config file every time,
+    /// so changes to it can be applied without restart.
+    /// None if config file doesn't have [[plugins]] section
+    pub fn plugins_config(&self) -> (Option<Vec<PluginConfig>>, PluginSyncConfig) {
+        let config = self.config_reader.read_config();
+
+        (config.plugins, config.plugin_sync.unwrap_or_default())
+    }
+
     pub fn setup_global_shortcuts(&self, global_hotkey_manager: &GlobalHotKeyManager) -> anyhow::Result<()> {
         self.global_hotkey_settings.setup(global_hotkey_manager)?;
 
@@ -111,20 +132,70 @@ impl Settings {
         )
     }
 
-    pub fn entrypoint_search_aliases(&self) -> anyhow::Result<HashMap<(PluginId, EntrypointId), String>> {
+    pub fn entrypoint_search_aliases(
+        &self,
+    ) -> anyhow::Result<HashMap<(PluginId, EntrypointId), Vec<EntrypointSearchAlias>>> {
         let settings = self.repository.get_settings()?;
 
-        let data: HashMap<_, _> = settings
-            .entrypoint_search_aliases
+        let mut data: HashMap<_, Vec<_>> = HashMap::new();
+
+        for alias_data in settings.entrypoint_search_aliases.unwrap_or_default() {
+            let key = (
+                PluginId::from_string(alias_data.plugin_id),
+                EntrypointId::from_string(alias_data.entrypoint_id),
+            );
+
+            data.entry(key).or_default().push(EntrypointSearchAlias {
+                alias: alias_data.alias,
+                keyword: alias_data.keyword,
+            });
+        }
+
+        Ok(data)
+    }
+
+    pub fn set_entrypoint_search_aliases(
+        &self,
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        aliases: Vec<EntrypointSearchAlias>,
+    ) -> anyhow::Result<()> {
+        let plugin_id = plugin_id.to_string();
+        let entrypoint_id = entrypoint_id.to_string();
+
+        self.repository.mutate_settings(|mut settings| {
+            let mut alias_data = settings.entrypoint_search_aliases.unwrap_or_default();
+
+            alias_data.retain(|data| data.plugin_id != plugin_id || data.entrypoint_id != entrypoint_id);
+
+            alias_data.extend(aliases.into_iter().map(|alias| {
+                DbSettingsEntrypointSearchAliasData {
+                    plugin_id: plugin_id.clone(),
+                    entrypoint_id: entrypoint_id.clone(),
+                    alias: alias.alias,
+                    keyword: alias.keyword,
+                }
+            }));
+
+            settings.entrypoint_search_aliases = Some(alias_data);
+
+            Ok(settings)
+        })?;
+
+        Ok(())
+    }
+
+    pub fn pinned_entrypoints(&self) -> anyhow::Result<Vec<(PluginId, EntrypointId)>> {
+        let settings = self.repository.get_settings()?;
+
+        let data = settings
+            .pinned_entrypoints
             .unwrap_or_default()
             .into_iter()
             .map(|data| {
                 (
-                    (
-                        PluginId::from_string(data.plugin_id),
-                        EntrypointId::from_string(data.entrypoint_id),
-                    ),
-                    data.alias,
+                    PluginId::from_string(data.plugin_id),
+                    EntrypointId::from_string(data.entrypoint_id),
                 )
             })
             .collect();
@@ -132,45 +203,141 @@ impl Settings {
         Ok(data)
     }
 
-    pub fn set_entrypoint_search_alias(
+    pub fn set_entrypoint_pinned(
         &self,
         plugin_id: PluginId,
         entrypoint_id: EntrypointId,
-        alias: Option<String>,
+        pinned: bool,
     ) -> anyhow::Result<()> {
+        self.mutate_pinned_entrypoints(|pinned_data| {
+            let position = pinned_data.iter().position(|(pinned_plugin_id, pinned_entrypoint_id)| {
+                pinned_plugin_id == &plugin_id && pinned_entrypoint_id == &entrypoint_id
+            });
+
+            match (position, pinned) {
+                (None, true) => pinned_data.push((plugin_id, entrypoint_id)),
+                (Some(position), false) => {
+                    pinned_data.remove(position);
+                }
+                _ => {}
+            }
+        })
+    }
+
+    pub fn set_pinned_entrypoint_position(
+        &self,
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        new_position: usize,
+    ) -> anyhow::Result<()> {
+        self.mutate_pinned_entrypoints(|pinned_data| {
+            let position = pinned_data.iter().position(|(pinned_plugin_id, pinned_entrypoint_id)| {
+                pinned_plugin_id == &plugin_id && pinned_entrypoint_id == &entrypoint_id
+            });
+
+            if let Some(position) = position {
+                let item = pinned_data.remove(position);
+
+                pinned_data.insert(new_position.min(pinned_data.len()), item);
+            }
+        })
+    }
+
+    pub fn remove_plugin_pinned_entrypoints(&self, plugin_id: &PluginId) -> anyhow::Result<()> {
+        self.mutate_pinned_entrypoints(|pinned_data| {
+            pinned_data.retain(|(pinned_plugin_id, _)| pinned_plugin_id != plugin_id);
+        })
+    }
+
+    fn mutate_pinned_entrypoints(&self, f: impl FnOnce(&mut Vec<(PluginId, EntrypointId)>)) -> anyhow::Result<()> {
         self.repository.mutate_settings(|mut settings| {
-            let mut alias_data: HashMap<_, _> = settings
-                .entrypoint_search_aliases
+            let mut pinned_data: Vec<_> = settings
+                .pinned_entrypoints
                 .unwrap_or_default()
                 .into_iter()
                 .map(|data| {
                     (
-                        (
-                            PluginId::from_string(data.plugin_id),
-                            EntrypointId::from_string(data.entrypoint_id),
-                        ),
-                        data.alias,
+                        PluginId::from_string(data.plugin_id),
+                        EntrypointId::from_string(data.entrypoint_id),
                     )
                 })
                 .collect();
 
-            match alias {
-                None => alias_data.remove(&(plugin_id, entrypoint_id)),
-                Some(alias) => alias_data.insert((plugin_id, entrypoint_id), alias),
-            };
+            f(&mut pinned_data);
 
-            let alias_data = alias_data
+            let pinned_data = pinned_data
                 .into_iter()
-                .map(|((plugin_id, entrypoint_id), alias)| {
-                    DbSettingsEntrypointSearchAliasData {
+                .map(|(plugin_id, entrypoint_id)| {
+                    DbSettingsPinnedEntrypointData {
                         plugin_id: plugin_id.to_string(),
                         entrypoint_id: entrypoint_id.to_string(),
-                        alias,
                     }
                 })
                 .collect();
 
-            settings.entrypoint_search_aliases = Some(alias_data);
+            settings.pinned_entrypoints = Some(pinned_data);
+
+            Ok(settings)
+        })?;
+
+        Ok(())
+    }
+
+    pub fn plugin_search_scopes(&self) -> anyhow::Result<HashMap<PluginId, String>> {
+        let settings = self.repository.get_settings()?;
+
+        let data: HashMap<_, _> = settings
+            .plugin_search_scopes
+            .unwrap_or_default()
+            .into_iter()
+            .map(|data| (PluginId::from_string(data.plugin_id), data.keyword))
+            .collect();
+
+        Ok(data)
+    }
+
+    pub fn set_plugin_search_scope(&self, plugin_id: PluginId, keyword: Option<String>) -> anyhow::Result<()> {
+        self.repository.mutate_settings(|mut settings| {
+            let mut scope_data: HashMap<_, _> = settings
+                .plugin_search_scopes
+                .unwrap_or_default()
+                .into_iter()
+                .map(|data| (PluginId::from_string(data.plugin_id), data.keyword))
+                .collect();
+
+            match keyword {
+                None => {
+                    scope_data.remove(&plugin_id);
+                }
+                Some(keyword) => {
+                    // keyword is matched case-insensitively, see `parse_search_scope`
+                    let used_by = scope_data.iter().find(|(other_plugin_id, other_keyword)| {
+                        **other_plugin_id != plugin_id && other_keyword.eq_ignore_ascii_case(&keyword)
+                    });
+
+                    if let Some((other_plugin_id, _)) = used_by {
+                        return Err(anyhow!(
+                            "Search scope keyword \"{}\" is already used by plugin {}",
+                            keyword,
+                            other_plugin_id
+                        ));
+                    }
+
+                    scope_data.insert(plugin_id, keyword);
+                }
+            };
+
+            let scope_data = scope_data
+                .into_iter()
+                .map(|(plugin_id, keyword)| {
+                    DbSettingsPluginSearchScopeData {
+                        plugin_id: plugin_id.to_string(),
+                        keyword,
+                    }
+                })
+                .collect();
+
+            settings.plugin_search_scopes = Some(scope_data);
 
             Ok(settings)
         })?;
@@ -178,6 +345,10 @@ impl Settings {
         Ok(())
     }
 
+    pub fn remove_plugin_search_scope(&self, plugin_id: &PluginId) -> anyhow::Result<()> {
+        self.set_plugin_search_scope(plugin_id.clone(), None)
+    }
+
     pub fn effective_theme(&self) -> anyhow::Result<UiTheme> {
         if let Some(theme) = read_theme_file(self.dirs.theme_file()) {
             return Ok(theme);
@@ -282,6 +453,106 @@ impl Settings {
         Ok(())
     }
 
+    pub fn fuzzy_search_setting(&self) -> anyhow::Result<bool> {
+        let settings = self.repository.get_settings()?;
+
+        Ok(settings.fuzzy_search.unwrap_or(false))
+    }
+
+    pub fn set_fuzzy_search_setting(&self, enabled: bool) -> anyhow::Result<()> {
+        self.repository.mutate_settings(|mut settings| {
+            settings.fuzzy_search = Some(enabled);
+
+            Ok(settings)
+        })?;
+
+        Ok(())
+    }
+
+    pub fn frecency_half_life_days_setting(&self) -> anyhow::Result<u32> {
+        let settings = self.repository.get_settings()?;
+
+        Ok(settings
+            .frecency_half_life_days
+            .unwrap_or(DEFAULT_FRECENCY_HALF_LIFE_DAYS))
+    }
+
+    pub fn frecency_half_life_secs(&self) -> anyhow::Result<f64> {
+        let days = self.frecency_half_life_days_setting()?;
+
+        Ok(60.0 * 60.0 * 24.0 * days as f64)
+    }
+
+    pub fn set_frecency_half_life_days_setting(&self, days: u32) -> anyhow::Result<()> {
+        if days == 0 {
+            return Err(anyhow!("Frecency half life should be at least one day"));
+        }
+
+        self.repository.mutate_settings(|mut settings| {
+            settings.frecency_half_life_days = Some(days);
+
+            Ok(settings)
+        })?;
+
+        self.repository
+            .set_frecency_half_life(self.frecency_half_life_secs()?)?;
+
+        Ok(())
+    }
+
+    pub fn run_history_retention_days_setting(&self) -> anyhow::Result<u32> {
+        let settings = self.repository.get_settings()?;
+
+        Ok(settings
+            .run_history_retention_days
+            .unwrap_or(DEFAULT_RUN_HISTORY_RETENTION_DAYS))
+    }
+
+    pub fn run_history_retention_secs(&self) -> anyhow::Result<f64> {
+        let days = self.run_history_retention_days_setting()?;
+
+        Ok(60.0 * 60.0 * 24.0 * days as f64)
+    }
+
+    pub fn set_run_history_retention_days_setting(&self, days: u32) -> anyhow::Result<()> {
+        if days == 0 {
+            return Err(anyhow!("Run history retention should be at least one day"));
+        }
+
+        self.repository.mutate_settings(|mut settings| {
+            settings.run_history_retention_days = Some(days);
+
+            Ok(settings)
+        })?;
+
+        self.repository
+            .set_entrypoint_run_history_retention(self.run_history_retention_secs()?)?;
+
+        Ok(())
+    }
+
+    pub fn recent_search_results_limit_setting(&self) -> anyhow::Result<u32> {
+        let settings = self.repository.get_settings()?;
+
+        Ok(settings
+            .recent_search_results_limit
+            .unwrap_or(DEFAULT_RECENT_SEARCH_RESULTS_LIMIT))
+    }
+
+    pub fn set_recent_search_results_limit_setting(&self, limit: u32) -> anyhow::Result<()> {
+        if limit == 0 {
+            return Err(anyhow!("Number of recent entrypoints should be at least one"));
+        }
+
+        self.repository.mutate_settings(|mut settings| {
+            settings.recent_search_results_limit = Some(limit);
+
+            Ok(settings)
+        })?;
+
+        Ok(())
+    }
+
     fn autodetect_theme(&self) -> UiTheme {
         match dark_light::detect() {
             Mode::Dark => self.themes.macos_dark_theme.clone(),
@@ -295,13 +566,21 @@ impl Settings {
     }
 }
 
-fn effective_config(config: ApplicationConfig, layer_shell_supported: bool) -> EffectiveConfig {
+fn effective_config(dirs: &Dirs, config: ApplicationConfig, layer_shell_supported: bool) -> EffectiveConfig {
     let window_config = config.main_window.unwrap_or_default();
     let wayland_config = config.wayland.unwrap_or_default();
     let linux_config = config.linux.unwrap_or_default();
+    let search_config = config.search.unwrap_or_default();
+    let plugin_signatures_config = config.plugin_signatures.unwrap_or_default();
 
     let close_on_unfocus = window_config.close_on_unfocus.unwrap_or(true);
     let linux_native_hud = linux_config.native_hud.unwrap_or(true);
+    let search_initials_boost = search_config.initials_boost.unwrap_or(2.0);
+    let search_match_weight = search_config.match_weight.unwrap_or(1.0);
+    let search_frecency_weight = search_config.frecency_weight.unwrap_or(1.5);
+    let search_query_frecency_weight = search_config.query_frecency_weight.unwrap_or(3.0);
+    let plugin_trusted_keys = plugin_signatures_config.trusted_keys.unwrap_or_default();
+    let plugin_signatures_required = plugin_signatures_config.required.unwrap_or(false);
 
     let main_window_surface = wayland_config
         .main_window_surface
@@ -322,10 +601,27 @@ fn effective_config(config: ApplicationConfig, layer_shell_supported: bool) -> E
         WaylandGlobalShortcutConfig::LegacyX11Api => true,
     };
 
+    // unauthenticated, so only enabled by default in dev builds where plugins are developed
+    let control_tools_server = config
+        .control
+        .as_ref()
+        .and_then(|control| control.tools_server)
+        .unwrap_or(!cfg!(feature = "release"));
+
+    let control_transport = BackendTransport::from_config(dirs, config.control);
+
     EffectiveConfig {
         close_on_unfocus,
         layer_shell,
         wayland_use_legacy_x11_api,
         linux_native_hud,
+        control_transport,
+        control_tools_server,
+        search_initials_boost,
+        search_match_weight,
+        search_frecency_weight,
+        search_query_frecency_weight,
+        plugin_trusted_keys,
+        plugin_signatures_required,
     }
 }
@@ -0,0 +1,269 @@
+use std::path::Path;
+
+use anyhow::Context;
+use anyhow::anyhow;
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use ed25519_dalek::Signature;
+use ed25519_dalek::VerifyingKey;
+use sha2::Digest;
+use sha2::Sha256;
+use walkdir::WalkDir;
+
+use crate::plugins::data_db_repository::DbPluginSignature;
+
+/// Detached signature located next to plugin manifest in built plugin.
+///
+/// Contains base64 encoded ed25519 signature of sha256 digest of plugin content.
+/// Digest is computed over plugin manifest and every file in `js` and `assets` directories
+/// ordered by path relative to plugin directory, for every file it is updated with the path using `/` as separator,
+/// zero byte, length of file content as little endian u64 and file content
+pub const PLUGIN_SIGNATURE_FILE: &str = "gauntlet.sig";
+
+// manifest declares permissions, so it has to be signed together with the code
+const PLUGIN_MANIFEST_FILE: &str = "gauntlet.toml";
+
+/// Checks whether plugin in `plugin_dir` is signed by one of `trusted_keys`,
+/// keys are base64 encoded ed25519 public keys
+pub fn verify_plugin_signature(plugin_dir: &Path, trusted_keys: &[String]) -> anyhow::Result<DbPluginSignature> {
+    let signature_path = plugin_dir.join(PLUGIN_SIGNATURE_FILE);
+
+    if !signature_path.exists() {
+        return Ok(DbPluginSignature::Unsigned);
+    }
+
+    let signature = std::fs::read_to_string(&signature_path).context("Unable to read plugin signature")?;
+
+    let signature = BASE64
+        .decode(signature.trim())
+        .context("Plugin signature is not a valid base64")?;
+
+    let signature = Signature::from_slice(&signature).context("Plugin signature is not a valid ed25519 signature")?;
+
+    let digest = plugin_content_digest(plugin_dir)?;
+
+    for trusted_key in trusted_keys {
+        let verifying_key = match parse_public_key(trusted_key) {
+            Ok(verifying_key) => verifying_key,
+            Err(err) => {
+                tracing::warn!("Invalid trusted plugin key {:?} in config: {:#}", trusted_key, err);
+                continue;
+            }
+        };
+
+        if verifying_key.verify_strict(&digest, &signature).is_ok() {
+            return Ok(DbPluginSignature::Signed {
+                public_key: trusted_key.clone(),
+            });
+        }
+    }
+
+    Ok(DbPluginSignature::Untrusted)
+}
+
+fn plugin_content_digest(plugin_dir: &Path) -> anyhow::Result<Vec<u8>> {
+    let manifest_path = plugin_dir.join(PLUGIN_MANIFEST_FILE);
+
+    if !manifest_path.is_file() {
+        return Err(anyhow!("Plugin doesn't contain {}", PLUGIN_MANIFEST_FILE));
+    }
+
+    let mut files = vec![(PLUGIN_MANIFEST_FILE.to_string(), manifest_path)];
+
+    for dir in ["js", "assets"] {
+        let dir = plugin_dir.join(dir);
+
+        if !dir.exists() {
+            continue;
+        }
+
+        for entry in WalkDir::new(&dir) {
+            let entry = entry.context("Unable to get list of plugin files")?;
+
+            if !entry.file_type().is_file() {
+                continue;
+            }
+
+            let relative_path = entry
+                .path()
+                .strip_prefix(plugin_dir)
+                .expect("plugin_dir is a base of plugin file")
+                .components()
+                .map(|component| {
+                    component
+                        .as_os_str()
+                        .to_str()
+                        .ok_or(anyhow!("filename is not a valid utf-8"))
+                })
+                .collect::<anyhow::Result<Vec<_>>>()?
+                .join("/");
+
+            files.push((relative_path, entry.into_path()));
+        }
+    }
+
+    files.sort();
+
+    let mut hasher = Sha256::new();
+
+    for (relative_path, path) in files {
+        let content = std::fs::read(&path).context(format!("Unable to read plugin file {:?}", path))?;
+
+        hasher.update(relative_path.as_bytes());
+        hasher.update([0]);
+        hasher.update((content.len() as u64).to_le_bytes());
+        hasher.update(&content);
+    }
+
+    Ok(hasher.finalize().to_vec())
+}
+
+fn parse_public_key(key: &str) -> anyhow::Result<VerifyingKey> {
+    let key = BASE64.decode(key.trim()).context("key is not a valid base64")?;
+
+    let key: [u8; 32] = key
+        .try_into()
+        .map_err(|_| anyhow!("ed25519 public key is expected to be 32 bytes long"))?;
+
+    Ok(VerifyingKey::from_bytes(&key)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use ed25519_dalek::Signer;
+    use ed25519_dalek::SigningKey;
+
+    use super::*;
+
+    fn create_plugin() -> tempfile::TempDir {
+        let plugin_dir = tempfile::tempdir().unwrap();
+
+        std::fs::write(plugin_dir.path().join("gauntlet.toml"), "[gauntlet]\nname = 'Test'\n").unwrap();
+        std::fs::create_dir_all(plugin_dir.path().join("js")).unwrap();
+        std::fs::write(plugin_dir.path().join("js").join("index.js"), "export default 1").unwrap();
+        std::fs::create_dir_all(plugin_dir.path().join("assets").join("icons")).unwrap();
+        std::fs::write(
+            plugin_dir.path().join("assets").join("icons").join("icon.png"),
+            [1, 2, 3],
+        )
+        .unwrap();
+
+        plugin_dir
+    }
+
+    fn sign_plugin(plugin_dir: &Path, signing_key: &SigningKey) {
+        let digest = plugin_content_digest(plugin_dir).unwrap();
+        let signature = signing_key.sign(&digest);
+
+        std::fs::write(
+            plugin_dir.join(PLUGIN_SIGNATURE_FILE),
+            BASE64.encode(signature.to_bytes()),
+        )
+        .unwrap();
+    }
+
+    fn public_key(signing_key: &SigningKey) -> String {
+        BASE64.encode(signing_key.verifying_key().to_bytes())
+    }
+
+    #[test]
+    fn signed_by_trusted_key() {
+        let plugin_dir = create_plugin();
+        let signing_key = SigningKey::from_bytes(&[1; 32]);
+        let other_key = SigningKey::from_bytes(&[2; 32]);
+
+        sign_plugin(plugin_dir.path(), &signing_key);
+
+        let trusted_keys = vec![public_key(&other_key), public_key(&signing_key)];
+
+        let signature = verify_plugin_signature(plugin_dir.path(), &trusted_keys).unwrap();
+
+        assert_eq!(
+            signature,
+            DbPluginSignature::Signed {
+                public_key: public_key(&signing_key)
+            }
+        );
+    }
+
+    #[test]
+    fn signed_by_untrusted_key() {
+        let plugin_dir = create_plugin();
+        let signing_key = SigningKey::from_bytes(&[1; 32]);
+        let other_key = SigningKey::from_bytes(&[2; 32]);
+
+        sign_plugin(plugin_dir.path(), &signing_key);
+
+        let trusted_keys = vec!["not a key".to_string(), public_key(&other_key)];
+
+        let signature = verify_plugin_signature(plugin_dir.path(), &trusted_keys).unwrap();
+
+        assert_eq!(signature, DbPluginSignature::Untrusted);
+    }
+
+    #[test]
+    fn tampered_code() {
+        let plugin_dir = create_plugin();
+        let signing_key = SigningKey::from_bytes(&[1; 32]);
+
+        sign_plugin(plugin_dir.path(), &signing_key);
+
+        std::fs::write(plugin_dir.path().join("js").join("index.js"), "export default 2").unwrap();
+
+        let signature = verify_plugin_signature(plugin_dir.path(), &[public_key(&signing_key)]).unwrap();
+
+        assert_eq!(signature, DbPluginSignature::Untrusted);
+    }
+
+    #[test]
+    fn tampered_manifest() {
+        let plugin_dir = create_plugin();
+        let signing_key = SigningKey::from_bytes(&[1; 32]);
+
+        sign_plugin(plugin_dir.path(), &signing_key);
+
+        std::fs::write(
+            plugin_dir.path().join("gauntlet.toml"),
+            "[gauntlet]\nname = 'Test'\n[permissions]\nnetwork = ['example.com']\n",
+        )
+        .unwrap();
+
+        let signature = verify_plugin_signature(plugin_dir.path(), &[public_key(&signing_key)]).unwrap();
+
+        assert_eq!(signature, DbPluginSignature::Untrusted);
+    }
+
+    #[test]
+    fn added_file() {
+        let plugin_dir = create_plugin();
+        let signing_key = SigningKey::from_bytes(&[1; 32]);
+
+        sign_plugin(plugin_dir.path(), &signing_key);
+
+        std::fs::write(plugin_dir.path().join("js").join("extra.js"), "").unwrap();
+
+        let signature = verify_plugin_signature(plugin_dir.path(), &[public_key(&signing_key)]).unwrap();
+
+        assert_eq!(signature, DbPluginSignature::Untrusted);
+    }
+
+    #[test]
+    fn unsigned() {
+        let plugin_dir = create_plugin();
+        let signing_key = SigningKey::from_bytes(&[1; 32]);
+
+        let signature = verify_plugin_signature(plugin_dir.path(), &[public_key(&signing_key)]).unwrap();
+
+        assert_eq!(signature, DbPluginSignature::Unsigned);
+    }
+
+    #[test]
+    fn malformed_signature() {
+        let plugin_dir = create_plugin();
+        let signing_key = SigningKey::from_bytes(&[1; 32]);
+
+        std::fs::write(plugin_dir.path().join(PLUGIN_SIGNATURE_FILE), "not base64!").unwrap();
+
+        assert!(verify_plugin_signature(plugin_dir.path(), &[public_key(&signing_key)]).is_err());
+    }
+}
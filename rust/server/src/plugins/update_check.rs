@@ -0,0 +1,149 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::thread;
+use std::time::Duration;
+
+use gauntlet_common::model::PluginId;
+
+use crate::plugins::data_db_repository::DataDbRepository;
+use crate::plugins::data_db_repository::DbPluginType;
+use crate::plugins::data_db_repository::DbReadPlugin;
+use crate::plugins::data_db_repository::db_plugin_type_from_str;
+use crate::plugins::loader::PLUGIN_RELEASE_BRANCH;
+
+const UPDATE_CHECK_INTERVAL: Duration = Duration::from_secs(6 * 60 * 60);
+
+/// Periodically checks whether branch or tag downloaded plugins were installed from has moved since then
+#[derive(Clone)]
+pub struct PluginUpdateChecker {
+    db_repository: DataDbRepository,
+    // plugin id -> latest commit on remote
+    available_updates: Arc<Mutex<HashMap<PluginId, String>>>,
+}
+
+impl PluginUpdateChecker {
+    pub fn new(db_repository: DataDbRepository) -> Self {
+        Self {
+            db_repository,
+            available_updates: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    pub fn start(&self) {
+        let checker = self.clone();
+
+        thread::Builder::new()
+            .name("gauntlet-plugin-update-check".to_string())
+            .spawn(move || {
+                loop {
+                    if let Err(err) = checker.check_all() {
+                        tracing::warn!("error when checking for plugin updates: {:?}", err);
+                    }
+
+                    thread::sleep(UPDATE_CHECK_INTERVAL);
+                }
+            })
+            .expect("failed to spawn thread");
+    }
+
+    pub fn is_update_available(&self, plugin_id: &PluginId) -> bool {
+        let available_updates = self.available_updates.lock().expect("lock is poisoned");
+
+        available_updates.contains_key(plugin_id)
+    }
+
+    /// Called after plugin was updated or removed
+    pub fn forget(&self, plugin_id: &PluginId) {
+        let mut available_updates = self.available_updates.lock().expect("lock is poisoned");
+
+        available_updates.remove(plugin_id);
+    }
+
+    fn check_all(&self) -> anyhow::Result<()> {
+        tracing::debug!("Checking for plugin updates");
+
+        for plugin in self.db_repository.list_plugins()? {
+            let plugin_id = plugin.id.clone();
+
+            if let Err(err) = self.check(plugin) {
+                tracing::warn!("Unable to check for updates of plugin {:?}: {:?}", plugin_id, err);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Checks single plugin, result is returned by `is_update_available`
+    pub fn check(&self, plugin: DbReadPlugin) -> anyhow::Result<()> {
+        if !matches!(db_plugin_type_from_str(&plugin.plugin_type), DbPluginType::Normal) {
+            return Ok(());
+        }
+
+        let plugin_id = PluginId::from_string(plugin.id);
+
+        // local plugins are not downloaded, so there is nothing to update
+        let Ok(url) = plugin_id.try_to_git_url() else {
+            return Ok(());
+        };
+
+        let git_ref = plugin.git_ref.as_deref().unwrap_or(PLUGIN_RELEASE_BRANCH);
+
+        match remote_commit(&url, git_ref)? {
+            Some(remote_commit) => {
+                let mut available_updates = self.available_updates.lock().expect("lock is poisoned");
+
+                match plugin.installed_commit {
+                    // plugins installed before commit was stored cannot be compared with remote,
+                    // they are not marked as outdated, updating them stores the commit
+                    None => {
+                        available_updates.remove(&plugin_id);
+                    }
+                    Some(installed_commit) if installed_commit == remote_commit => {
+                        available_updates.remove(&plugin_id);
+                    }
+                    Some(_) => {
+                        tracing::info!("Update is available for plugin: {:?}", plugin_id);
+
+                        available_updates.insert(plugin_id, remote_commit);
+                    }
+                }
+            }
+            None if plugin.git_ref.is_some() => {
+                // git ref is neither branch nor tag, so plugin is pinned to a commit and cannot be updated
+            }
+            None => {
+                tracing::warn!(
+                    "Plugin {:?} repository doesn't have {} branch, unable to check for updates",
+                    plugin_id,
+                    PLUGIN_RELEASE_BRANCH
+                );
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// equivalent of "git ls-remote <url>" filtered to branch or tag with name `git_ref`
+fn remote_commit(url: &str, git_ref: &str) -> anyhow::Result<Option<String>> {
+    let mut remote = git2::Remote::create_detached(url)?;
+
+    remote.connect(git2::Direction::Fetch)?;
+
+    let heads = remote.list()?;
+
+    // "^{}" suffix is used for commits annotated tags point to
+    let candidates = [
+        format!("refs/heads/{}", git_ref),
+        format!("refs/tags/{}^{{}}", git_ref),
+        format!("refs/tags/{}", git_ref),
+    ];
+
+    let commit = candidates
+        .iter()
+        .find_map(|name| heads.iter().find(|head| head.name() == name))
+        .map(|head| head.oid().to_string());
+
+    Ok(commit)
+}
@@ -1,12 +1,27 @@
+use std::collections::HashMap;
+use std::io::Write;
+use std::path::Path;
+
+use gauntlet_common::model::CliDiagnostics;
+use gauntlet_common::model::CliEntrypointAction;
+use gauntlet_common::model::CliPluginSyncReport;
+use gauntlet_common::model::CliRunHistoryItem;
+use gauntlet_common::model::DownloadStatus;
 use gauntlet_common::model::EntrypointId;
 use gauntlet_common::model::LocalSaveData;
 use gauntlet_common::model::PluginId;
+use gauntlet_common::model::PluginSource;
+use gauntlet_common::model::SearchResult;
+use gauntlet_common::model::ServerEvent;
+use gauntlet_common::model::SettingsPlugin;
 use gauntlet_common::rpc::backend_api::BackendForCliApi;
 use gauntlet_common::rpc::backend_api::BackendForToolsApi;
 use gauntlet_common::rpc::backend_server::start_backend_server;
 use gauntlet_common::rpc::server_grpc_api::ServerGrpcApi;
 use gauntlet_common::rpc::server_grpc_api::ServerGrpcApiProxy;
+use gauntlet_common::rpc::transport::BackendTransport;
 use gauntlet_utils::channel::RequestResult;
+use uuid::Uuid;
 
 pub struct BackendServerImpl {
     pub proxy: ServerGrpcApiProxy,
@@ -20,14 +35,73 @@ impl BackendServerImpl {
     }
 }
 
-pub async fn run_grpc_server(grpc_api: ServerGrpcApiProxy) {
+pub async fn run_grpc_server(
+    grpc_api: ServerGrpcApiProxy,
+    transport: BackendTransport,
+    tools_server: bool,
+    events: tokio::sync::broadcast::Sender<ServerEvent>,
+) {
+    if let BackendTransport::Tcp { token_file, .. } = &transport {
+        ensure_control_token(token_file).expect("unable to create control token file");
+    }
+
     start_backend_server(
+        transport,
+        tools_server,
+        events,
         Box::new(BackendServerImpl::new(grpc_api.clone())),
         Box::new(BackendServerImpl::new(grpc_api.clone())),
     )
     .await
 }
 
+fn ensure_control_token(token_file: &Path) -> anyhow::Result<()> {
+    if let Ok(token) = std::fs::read_to_string(token_file) {
+        if !token.trim().is_empty() && !control_token_exposed(token_file)? {
+            return Ok(());
+        }
+
+        // file is recreated below, so that existing token which other users could have read is not reused
+        // and permissions of new file are set on creation
+        std::fs::remove_file(token_file)?;
+    }
+
+    if let Some(parent) = token_file.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    let token = format!("{}{}", Uuid::new_v4().simple(), Uuid::new_v4().simple());
+
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+
+        options.mode(0o600);
+    }
+
+    let mut file = options.open(token_file)?;
+    file.write_all(token.as_bytes())?;
+
+    Ok(())
+}
+
+#[cfg(unix)]
+fn control_token_exposed(token_file: &Path) -> anyhow::Result<bool> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let mode = std::fs::metadata(token_file)?.permissions().mode();
+
+    Ok(mode & 0o077 != 0)
+}
+
+#[cfg(not(unix))]
+fn control_token_exposed(_token_file: &Path) -> anyhow::Result<bool> {
+    Ok(false)
+}
+
 #[tonic::async_trait]
 impl BackendForCliApi for BackendServerImpl {
     async fn ping(&self) -> RequestResult<()> {
@@ -52,11 +126,123 @@ impl BackendForCliApi for BackendServerImpl {
         plugin_id: PluginId,
         entrypoint_id: EntrypointId,
         action_id: String,
+        arguments: HashMap<String, String>,
+        query: Option<String>,
     ) -> RequestResult<()> {
-        self.proxy.run_action(plugin_id, entrypoint_id, action_id).await?;
+        self.proxy
+            .run_action(plugin_id, entrypoint_id, action_id, arguments, query)
+            .await?;
 
         Ok(())
     }
+
+    async fn search(&self, text: String) -> RequestResult<Vec<SearchResult>> {
+        let result = self.proxy.search(text).await?;
+
+        Ok(result)
+    }
+
+    async fn plugins(&self) -> RequestResult<HashMap<PluginId, SettingsPlugin>> {
+        let result = self.proxy.plugins().await?;
+
+        Ok(result)
+    }
+
+    async fn entrypoint_actions(
+        &self,
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+    ) -> RequestResult<Vec<CliEntrypointAction>> {
+        let result = self.proxy.entrypoint_actions(plugin_id, entrypoint_id).await?;
+
+        Ok(result)
+    }
+
+    async fn download_plugin(&self, source: PluginSource) -> RequestResult<()> {
+        self.proxy.download_plugin(source).await?;
+
+        Ok(())
+    }
+
+    async fn download_status(&self) -> RequestResult<HashMap<PluginId, DownloadStatus>> {
+        let result = self.proxy.download_status().await?;
+
+        Ok(result)
+    }
+
+    async fn remove_plugin(&self, plugin_id: PluginId) -> RequestResult<()> {
+        self.proxy.remove_plugin(plugin_id).await?;
+
+        Ok(())
+    }
+
+    async fn set_plugin_state(
+        &self,
+        plugin_id: PluginId,
+        enabled: bool,
+        acknowledge_unsigned: bool,
+    ) -> RequestResult<()> {
+        self.proxy
+            .set_plugin_state(plugin_id, enabled, acknowledge_unsigned)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn set_entrypoint_state(
+        &self,
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        enabled: bool,
+    ) -> RequestResult<()> {
+        self.proxy
+            .set_entrypoint_state(plugin_id, entrypoint_id, enabled)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn reload_all_plugins(&self) -> RequestResult<()> {
+        self.proxy.reload_all_plugins().await?;
+
+        Ok(())
+    }
+
+    async fn diagnostics(&self) -> RequestResult<CliDiagnostics> {
+        let result = self.proxy.diagnostics().await?;
+
+        Ok(result)
+    }
+
+    async fn shutdown(&self, restart: bool) -> RequestResult<()> {
+        self.proxy.shutdown(restart).await?;
+
+        Ok(())
+    }
+
+    async fn export_frecency(&self) -> RequestResult<String> {
+        let result = self.proxy.export_frecency().await?;
+
+        Ok(result)
+    }
+
+    async fn import_frecency(&self, data: String) -> RequestResult<()> {
+        self.proxy.import_frecency(data).await?;
+
+        Ok(())
+    }
+
+    async fn run_history(&self) -> RequestResult<Vec<CliRunHistoryItem>> {
+        let result = self.proxy.run_history().await?;
+
+        Ok(result)
+    }
+
+    async fn sync_plugins(&self, dry_run: bool) -> RequestResult<CliPluginSyncReport> {
+        let result = self.proxy.sync_plugins(dry_run).await?;
+
+        Ok(result)
+    }
 }
 
 #[tonic::async_trait]
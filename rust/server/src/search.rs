@@ -1,9 +1,12 @@
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::sync::Arc;
 use std::sync::Mutex;
 
 use anyhow::anyhow;
+use gauntlet_common::dirs::Dirs;
 use gauntlet_common::model::EntrypointId;
+use gauntlet_common::model::EntrypointSearchAlias;
 use gauntlet_common::model::PhysicalShortcut;
 use gauntlet_common::model::PluginId;
 use gauntlet_common::model::SearchResult;
@@ -21,6 +24,8 @@ use tantivy::collector::TopDocs;
 use tantivy::doc;
 use tantivy::query::AllQuery;
 use tantivy::query::BooleanQuery;
+use tantivy::query::FuzzyTermQuery;
+use tantivy::query::Occur;
 use tantivy::query::Query;
 use tantivy::query::RegexQuery;
 use tantivy::query::TermQuery;
@@ -28,14 +33,23 @@ use tantivy::schema::*;
 use tantivy::tokenizer::TokenizerManager;
 
 use crate::plugins::Settings;
+use crate::search::storage::SearchIndexStorage;
+use crate::search::writer::SearchIndexWriter;
+
+#[cfg(test)]
+mod benches;
+mod storage;
+mod writer;
 
 #[derive(Clone)]
 pub struct SearchIndex {
-    frontend_api: FrontendApiProxy,
     settings: Settings,
     index: Index,
     index_reader: IndexReader,
-    index_writer_mutex: Arc<Mutex<()>>,
+    index_writer: SearchIndexWriter,
+    storage: Option<SearchIndexStorage>,
+    // plugins which entrypoint data was changed, but not yet written to disk
+    unsaved_plugins: Arc<Mutex<HashSet<PluginId>>>,
 
     entrypoint_data: Arc<Mutex<HashMap<PluginId, PluginData>>>,
 
@@ -44,22 +58,29 @@ pub struct SearchIndex {
     plugin_name: Field,
     plugin_id: Field,
     entrypoint_alias: Field,
+    entrypoint_initials: Field,
+    entrypoint_description: Field,
+    entrypoint_keywords: Field,
 }
 
 struct PluginData {
     plugin_name: String,
+    // version of plugin code which reported entrypoints, used to find outdated data loaded from disk
+    plugin_version: String,
     entrypoints: HashMap<EntrypointId, EntrypointData>,
 }
 
 struct EntrypointData {
     entrypoint_name: String,
+    entrypoint_description: String,
+    entrypoint_keywords: Vec<String>,
     entrypoint_generator: Option<(EntrypointId, String)>,
     entrypoint_type: SearchResultEntrypointType,
     icon: Option<bytes::Bytes>,
     frecency: f64,
     actions: Vec<EntrypointActionData>,
     accessories: Vec<SearchResultAccessory>,
-    search_alias: Option<String>,
+    search_aliases: Vec<EntrypointSearchAlias>,
 }
 
 struct EntrypointActionData {
@@ -98,6 +119,8 @@ pub struct EntrypointActionDataView {
 pub struct SearchIndexItem {
     pub entrypoint_type: SearchResultEntrypointType,
     pub entrypoint_name: String,
+    pub entrypoint_description: String,
+    pub entrypoint_keywords: Vec<String>,
     pub entrypoint_generator: Option<(EntrypointId, String)>,
     pub entrypoint_id: EntrypointId,
     pub entrypoint_icon: Option<bytes::Bytes>,
@@ -121,7 +144,23 @@ pub enum SearchIndexItemActionActionType {
 }
 
 impl SearchIndex {
-    pub fn create_index(frontend_api: FrontendApiProxy, settings: Settings) -> tantivy::Result<Self> {
+    pub fn create_index(frontend_api: FrontendApiProxy, settings: Settings, dirs: &Dirs) -> anyhow::Result<Self> {
+        // scenario runner always starts with an empty index
+        let storage = if cfg!(feature = "scenario_runner") {
+            None
+        } else {
+            Some(SearchIndexStorage::new(dirs))
+        };
+
+        Self::create_index_with_storage(frontend_api, settings, storage)
+    }
+
+    /// Index is kept only in memory if `storage` is not specified
+    fn create_index_with_storage(
+        frontend_api: FrontendApiProxy,
+        settings: Settings,
+        storage: Option<SearchIndexStorage>,
+    ) -> anyhow::Result<Self> {
         let schema = {
             let mut schema_builder = Schema::builder();
 
@@ -130,6 +169,9 @@ impl SearchIndex {
             schema_builder.add_text_field("plugin_name", TEXT | STORED);
             schema_builder.add_text_field("plugin_id", STRING | STORED);
             schema_builder.add_text_field("entrypoint_alias", TEXT | STORED);
+            schema_builder.add_text_field("entrypoint_initials", STRING);
+            schema_builder.add_text_field("entrypoint_description", TEXT);
+            schema_builder.add_text_field("entrypoint_keywords", TEXT);
 
             schema_builder.build()
         };
@@ -145,59 +187,162 @@ impl SearchIndex {
         let entrypoint_alias = schema
             .get_field("entrypoint_alias")
             .expect("plugin_id field should exist");
+        let entrypoint_initials = schema
+            .get_field("entrypoint_initials")
+            .expect("entrypoint_initials field should exist");
+        let entrypoint_description = schema
+            .get_field("entrypoint_description")
+            .expect("entrypoint_description field should exist");
+        let entrypoint_keywords = schema
+            .get_field("entrypoint_keywords")
+            .expect("entrypoint_keywords field should exist");
+
+        let (index, mut entrypoint_data) = match &storage {
+            None => (Index::create_in_ram(schema.clone()), HashMap::new()),
+            Some(storage) => storage.open(&schema)?,
+        };
+
+        let aliases = settings.entrypoint_search_aliases()?;
+
+        for (stored_plugin_id, plugin_data) in &mut entrypoint_data {
+            for (entrypoint_id, entrypoint_data) in &mut plugin_data.entrypoints {
+                entrypoint_data.search_aliases = aliases
+                    .get(&(stored_plugin_id.clone(), entrypoint_id.clone()))
+                    .cloned()
+                    .unwrap_or_default();
+            }
+        }
+
+        // remove documents which do not have stored entrypoint data, e.g. if gauntlet was stopped while index was updated
+        let mut stale_documents_query: Vec<(Occur, Box<dyn Query>)> = vec![(Occur::Must, Box::new(AllQuery))];
+
+        for stored_plugin_id in entrypoint_data.keys() {
+            stale_documents_query.push((
+                Occur::MustNot,
+                Box::new(TermQuery::new(
+                    Term::from_field_text(plugin_id, &stored_plugin_id.to_string()),
+                    IndexRecordOption::Basic,
+                )),
+            ));
+        }
+
+        let index_reader: IndexReader = index.reader_builder().reload_policy(ReloadPolicy::Manual).try_into()?;
+
+        let entrypoint_data = Arc::new(Mutex::new(entrypoint_data));
+        let unsaved_plugins = Arc::new(Mutex::new(HashSet::new()));
+
+        let index_writer = {
+            let storage = storage.clone();
+            let entrypoint_data = entrypoint_data.clone();
+            let unsaved_plugins = unsaved_plugins.clone();
+            let handle = tokio::runtime::Handle::current();
+
+            SearchIndexWriter::new(&index, index_reader.clone(), move |refresh_search_list| {
+                if let Some(storage) = &storage {
+                    save_plugins(storage, &entrypoint_data, &unsaved_plugins);
+                }
+
+                if refresh_search_list {
+                    let frontend_api = frontend_api.clone();
+                    handle.spawn(async move {
+                        tracing::info!("requesting search results update because search index was updated");
 
-        let index = Index::create_in_ram(schema.clone());
+                        let result = frontend_api.request_search_results_update().await;
 
-        let index_reader = index.reader_builder().reload_policy(ReloadPolicy::Manual).try_into()?;
+                        if let Err(err) = &result {
+                            tracing::warn!("error occurred when requesting search results update {:?}", err)
+                        }
+                    });
+                }
+            })?
+        };
+
+        index_writer.update(Box::new(BooleanQuery::new(stale_documents_query)), vec![], false);
 
         Ok(Self {
-            frontend_api,
             settings,
             index,
             index_reader,
-            index_writer_mutex: Arc::new(Mutex::new(())),
-            entrypoint_data: Arc::new(Mutex::new(HashMap::new())),
+            index_writer,
+            storage,
+            unsaved_plugins,
+            entrypoint_data,
             entrypoint_name,
             entrypoint_id,
             plugin_name,
             plugin_id,
             entrypoint_alias,
+            entrypoint_initials,
+            entrypoint_description,
+            entrypoint_keywords,
         })
     }
 
-    pub fn remove_for_plugin(&self, plugin_id: PluginId) -> tantivy::Result<()> {
-        // writer panics if another writer exists
-        let _guard = self.index_writer_mutex.lock().expect("lock is poisoned");
-        let mut entrypoint_data = self.entrypoint_data.lock().expect("lock is poisoned");
+    /// Waits until all changes made so far are committed and visible to searches
+    #[cfg(test)]
+    fn flush(&self) {
+        self.index_writer.flush()
+    }
 
-        let mut index_writer = self.index.writer::<TantivyDocument>(15_000_000)?;
+    pub fn remove_for_plugin(&self, plugin_id: PluginId) -> anyhow::Result<()> {
+        // entrypoint data lock is held while sending to writer, so index updates are in the same order as entrypoint data updates
+        let mut entrypoint_data = self.entrypoint_data.lock().expect("lock is poisoned");
 
-        index_writer.delete_query(Box::new(TermQuery::new(
-            Term::from_field_text(self.plugin_id, &plugin_id.to_string()),
-            IndexRecordOption::Basic,
-        )))?;
-        index_writer.commit()?;
-        self.index_reader.reload()?;
+        self.index_writer.update(
+            Box::new(TermQuery::new(
+                Term::from_field_text(self.plugin_id, &plugin_id.to_string()),
+                IndexRecordOption::Basic,
+            )),
+            vec![],
+            false,
+        );
 
         entrypoint_data.remove(&plugin_id);
 
+        self.unsaved_plugins
+            .lock()
+            .expect("lock is poisoned")
+            .remove(&plugin_id);
+
+        if let Some(storage) = &self.storage {
+            storage.remove_plugin(&plugin_id)?;
+        }
+
         Ok(())
     }
 
-    pub fn set_entrypoint_search_alias(
+    /// Removes entrypoints loaded from disk of plugins which were removed, disabled or changed their code since they were stored
+    pub fn retain_plugins(&self, plugin_versions: &HashMap<PluginId, String>) -> anyhow::Result<()> {
+        let outdated_plugins: Vec<_> = self
+            .entrypoint_data
+            .lock()
+            .expect("lock is poisoned")
+            .iter()
+            .filter(|(plugin_id, plugin_data)| plugin_versions.get(plugin_id) != Some(&plugin_data.plugin_version))
+            .map(|(plugin_id, _)| plugin_id.clone())
+            .collect();
+
+        for plugin_id in outdated_plugins {
+            tracing::debug!("Removing outdated search index entries for plugin {:?}", plugin_id);
+
+            self.remove_for_plugin(plugin_id)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn set_entrypoint_search_aliases(
         &self,
         plugin_id: PluginId,
         entrypoint_id: EntrypointId,
-        alias: Option<String>,
+        aliases: Vec<EntrypointSearchAlias>,
     ) -> anyhow::Result<()> {
         tracing::debug!(
-            "Updating the entrypoint search alias in search index for plugin {:?} - {:?}",
+            "Updating the entrypoint search aliases in search index for plugin {:?} - {:?}",
             plugin_id,
             entrypoint_id
         );
 
-        // writer panics if another writer exists
-        let _guard = self.index_writer_mutex.lock().expect("lock is poisoned");
         let mut plugins = self.entrypoint_data.lock().expect("lock is poisoned");
 
         let Some(plugin_data) = plugins.get_mut(&plugin_id) else {
@@ -208,10 +353,9 @@ impl SearchIndex {
             return Ok(());
         };
 
-        entrypoint_data.search_alias = alias;
+        entrypoint_data.search_aliases = aliases;
 
-        let mut index_writer = self.index.writer::<TantivyDocument>(15_000_000)?;
-        let query = Box::new(BooleanQuery::union(vec![
+        let query = Box::new(BooleanQuery::intersection(vec![
             Box::new(TermQuery::new(
                 Term::from_field_text(self.plugin_id, &plugin_id.to_string()),
                 IndexRecordOption::Basic,
@@ -222,23 +366,9 @@ impl SearchIndex {
             )),
         ]));
 
-        index_writer.delete_query(query)?;
-
-        let mut document = doc!(
-            self.entrypoint_name => entrypoint_data.entrypoint_name.clone(),
-            self.entrypoint_id => entrypoint_id.to_string(),
-            self.plugin_name => plugin_data.plugin_name.clone(),
-            self.plugin_id => plugin_id.to_string(),
-        );
-
-        if let Some(alias) = &entrypoint_data.search_alias {
-            document.add_field_value(self.entrypoint_alias, alias.clone())
-        }
+        let document = self.create_document(&plugin_id, &plugin_data.plugin_name, &entrypoint_id, entrypoint_data);
 
-        index_writer.add_document(document)?;
-
-        index_writer.commit()?;
-        self.index_reader.reload()?;
+        self.index_writer.update(query, vec![document], false);
 
         Ok(())
     }
@@ -247,6 +377,7 @@ impl SearchIndex {
         &self,
         plugin_id: PluginId,
         plugin_name: String,
+        plugin_version: String,
         search_items: Vec<SearchIndexItem>,
         refresh_search_list: bool,
     ) -> anyhow::Result<()> {
@@ -254,8 +385,6 @@ impl SearchIndex {
 
         let aliases = self.settings.entrypoint_search_aliases()?;
 
-        // writer panics if another writer exists
-        let _guard = self.index_writer_mutex.lock().expect("lock is poisoned");
         let mut entrypoint_data = self.entrypoint_data.lock().expect("lock is poisoned");
 
         let entrypoint_ids: Vec<_> = search_items.iter().map(|item| item.entrypoint_id.clone()).collect();
@@ -281,13 +410,18 @@ impl SearchIndex {
 
                 let data = EntrypointData {
                     entrypoint_name: item.entrypoint_name,
+                    entrypoint_description: item.entrypoint_description,
+                    entrypoint_keywords: item.entrypoint_keywords,
                     entrypoint_generator: item.entrypoint_generator,
                     entrypoint_type: item.entrypoint_type,
                     icon: item.entrypoint_icon,
                     frecency: item.entrypoint_frecency,
                     actions,
                     accessories: item.entrypoint_accessories,
-                    search_alias: aliases.get(&(plugin_id.clone(), item.entrypoint_id.clone())).cloned(),
+                    search_aliases: aliases
+                        .get(&(plugin_id.clone(), item.entrypoint_id.clone()))
+                        .cloned()
+                        .unwrap_or_default(),
                 };
 
                 (item.entrypoint_id.clone(), data)
@@ -298,55 +432,61 @@ impl SearchIndex {
             plugin_id.clone(),
             PluginData {
                 plugin_name,
+                plugin_version,
                 entrypoints: data,
             },
         );
 
-        let mut index_writer = self.index.writer::<TantivyDocument>(15_000_000)?;
-
-        index_writer.delete_query(Box::new(TermQuery::new(
-            Term::from_field_text(self.plugin_id, &plugin_id.to_string()),
-            IndexRecordOption::Basic,
-        )))?;
+        let plugin_data = entrypoint_data.get(&plugin_id).unwrap();
 
-        for entrypoint_id in entrypoint_ids {
-            let plugin_data = entrypoint_data.get(&plugin_id).unwrap();
-            let entrypoint_data = plugin_data.entrypoints.get(&entrypoint_id).unwrap();
+        let documents = entrypoint_ids
+            .into_iter()
+            .map(|entrypoint_id| {
+                let entrypoint_data = plugin_data.entrypoints.get(&entrypoint_id).unwrap();
 
-            let mut document = doc!(
-                self.entrypoint_name => entrypoint_data.entrypoint_name.clone(),
-                self.entrypoint_id => entrypoint_id.to_string(),
-                self.plugin_name => plugin_data.plugin_name.clone(),
-                self.plugin_id => plugin_id.to_string(),
-            );
+                self.create_document(&plugin_id, &plugin_data.plugin_name, &entrypoint_id, entrypoint_data)
+            })
+            .collect();
 
-            if let Some(alias) = &entrypoint_data.search_alias {
-                document.add_field_value(self.entrypoint_alias, alias.clone())
-            }
+        self.index_writer.update(
+            Box::new(TermQuery::new(
+                Term::from_field_text(self.plugin_id, &plugin_id.to_string()),
+                IndexRecordOption::Basic,
+            )),
+            documents,
+            refresh_search_list,
+        );
 
-            index_writer.add_document(document)?;
-        }
+        self.unsaved_plugins.lock().expect("lock is poisoned").insert(plugin_id);
 
-        index_writer.commit()?;
-        self.index_reader.reload()?;
+        Ok(())
+    }
 
-        if refresh_search_list {
-            let frontend_api = self.frontend_api.clone();
-            tokio::spawn(async move {
-                tracing::info!(
-                    "requesting search results update because search index update for plugin: {:?}",
-                    plugin_id
-                );
+    fn create_document(
+        &self,
+        plugin_id: &PluginId,
+        plugin_name: &str,
+        entrypoint_id: &EntrypointId,
+        entrypoint_data: &EntrypointData,
+    ) -> TantivyDocument {
+        let mut document = doc!(
+            self.entrypoint_name => entrypoint_data.entrypoint_name.clone(),
+            self.entrypoint_id => entrypoint_id.to_string(),
+            self.plugin_name => plugin_name.to_owned(),
+            self.plugin_id => plugin_id.to_string(),
+            self.entrypoint_initials => word_initials(&entrypoint_data.entrypoint_name),
+            self.entrypoint_description => entrypoint_data.entrypoint_description.clone(),
+        );
 
-                let result = frontend_api.request_search_results_update().await;
+        for alias in &entrypoint_data.search_aliases {
+            document.add_field_value(self.entrypoint_alias, alias.alias.clone())
+        }
 
-                if let Err(err) = &result {
-                    tracing::warn!("error occurred when requesting search results update {:?}", err)
-                }
-            });
+        for keyword in &entrypoint_data.entrypoint_keywords {
+            document.add_field_value(self.entrypoint_keywords, keyword.clone())
         }
 
-        Ok(())
+        document
     }
 
     pub fn plugin_entrypoint_data(&self) -> HashMap<PluginId, PluginDataView> {
@@ -395,7 +535,17 @@ impl SearchIndex {
             .collect()
     }
 
-    pub fn search(&self, query: &str) -> anyhow::Result<Vec<SearchResult>> {
+    /// `scope` limits results to entrypoints of a single plugin, see [`parse_search_scope`]
+    /// `query_frecency` is frecency of entrypoints previously picked after typing the same query
+    pub fn search(
+        &self,
+        query: &str,
+        scope: Option<&PluginId>,
+        query_frecency: &HashMap<(PluginId, EntrypointId), f64>,
+    ) -> anyhow::Result<Vec<SearchResult>> {
+        let fuzzy_search = self.settings.fuzzy_search_setting()?;
+        let config = self.settings.config();
+
         let entrypoint_data = self.entrypoint_data.lock().expect("lock is poisoned");
 
         let searcher = self.index_reader.searcher();
@@ -404,19 +554,95 @@ impl SearchIndex {
             self.index.tokenizers().clone(),
             self.entrypoint_name,
             self.plugin_name,
+            self.plugin_id,
             self.entrypoint_alias,
+            self.entrypoint_initials,
+            self.entrypoint_description,
+            self.entrypoint_keywords,
+        );
+
+        let ranking = Ranking {
+            terms: query_parser.tokenize(query),
+            entrypoint_data: &entrypoint_data,
+            query_frecency,
+            weights: RankingWeights {
+                match_weight: config.search_match_weight,
+                frecency_weight: config.search_frecency_weight,
+                query_frecency_weight: config.search_query_frecency_weight,
+                initials_weight: config.search_initials_boost,
+            },
+        };
+
+        let mut result = ranking.rank(
+            &query_parser,
+            self.fetch_all(&entrypoint_data, &query_parser.create_query(query, scope), &searcher)?,
         );
 
-        let query = query_parser.create_query(query);
+        // fuzzy matches always go after exact and substring matches, regardless of frecency
+        if fuzzy_search {
+            if let Some(fuzzy_query) = query_parser.create_fuzzy_query(query, scope) {
+                let fuzzy_result = self
+                    .fetch_all(&entrypoint_data, &fuzzy_query, &searcher)?
+                    .into_iter()
+                    .filter(|(fuzzy_item, _)| {
+                        !result.iter().any(|item| {
+                            item.plugin_id == fuzzy_item.plugin_id && item.entrypoint_id == fuzzy_item.entrypoint_id
+                        })
+                    })
+                    .collect::<Vec<_>>();
 
+                result.extend(ranking.rank(&query_parser, fuzzy_result));
+            }
+        }
+
+        let keyword_alias_result = keyword_alias_search(&entrypoint_data, query, scope);
+
+        drop(entrypoint_data);
+
+        // pinned entrypoints go first in user-defined order, stable sort keeps ranking order for everything else
+        let pinned_entrypoints = self.settings.pinned_entrypoints()?;
+
+        for item in &mut result {
+            item.entrypoint_pinned = pinned_entrypoints
+                .iter()
+                .any(|(plugin_id, entrypoint_id)| plugin_id == &item.plugin_id && entrypoint_id == &item.entrypoint_id);
+        }
+
+        result.sort_by_key(|item| {
+            pinned_entrypoints
+                .iter()
+                .position(|(plugin_id, entrypoint_id)| {
+                    plugin_id == &item.plugin_id && entrypoint_id == &item.entrypoint_id
+                })
+                .unwrap_or(usize::MAX)
+        });
+
+        // keyword aliases are explicitly typed by user, so they go before everything else, including pinned entrypoints
+        result.retain(|item| {
+            !keyword_alias_result.iter().any(|keyword_item| {
+                keyword_item.plugin_id == item.plugin_id && keyword_item.entrypoint_id == item.entrypoint_id
+            })
+        });
+
+        result.splice(0..0, keyword_alias_result);
+
+        Ok(result)
+    }
+
+    fn fetch_all(
+        &self,
+        entrypoint_data: &HashMap<PluginId, PluginData>,
+        query: &dyn Query,
+        searcher: &Searcher,
+    ) -> anyhow::Result<Vec<(SearchResult, f64)>> {
         let mut index = 0;
 
         let fetch = std::iter::from_fn(|| -> Option<anyhow::Result<Vec<(SearchResult, f64)>>> {
             let result = self.fetch(
-                &entrypoint_data,
-                &query,
+                entrypoint_data,
+                query,
                 TopDocs::with_limit(20).and_offset(index * 20),
-                &searcher,
+                searcher,
             );
 
             index += 1;
@@ -435,13 +661,7 @@ impl SearchIndex {
 
         let result = fetch.collect::<Result<Vec<Vec<_>>, _>>()?;
 
-        let mut result = result.into_iter().flatten().collect::<Vec<_>>();
-
-        result.sort_by(|(_, score_a), (_, score_b)| score_b.total_cmp(score_a));
-
-        let result = result.into_iter().map(|(item, _)| item).collect::<Vec<_>>();
-
-        drop(entrypoint_data);
+        let result = result.into_iter().flatten().collect::<Vec<_>>();
 
         Ok(result)
     }
@@ -464,7 +684,7 @@ impl SearchIndex {
         let result = searcher
             .search(query, &collector)?
             .into_iter()
-            .map(|(_score, doc_address)| {
+            .map(|(_score, doc_address)| -> anyhow::Result<Option<(SearchResult, f64)>> {
                 let retrieved_doc = searcher
                     .doc::<TantivyDocument>(doc_address)
                     .expect("index should contain just searched results");
@@ -473,58 +693,27 @@ impl SearchIndex {
                     .ok_or(anyhow!("document must contain entrypoint id"))?;
                 let plugin_id =
                     get_str_field(&retrieved_doc, self.plugin_id).ok_or(anyhow!("document must contain plugin id"))?;
-                let entrypoint_name = get_str_field(&retrieved_doc, self.entrypoint_name)
-                    .ok_or(anyhow!("document must contain entrypoint name"))?;
-                let plugin_name = get_str_field(&retrieved_doc, self.plugin_name)
-                    .ok_or(anyhow!("document must contain plugin name"))?;
-                let entrypoint_alias = get_str_field(&retrieved_doc, self.entrypoint_alias);
 
                 let entrypoint_id = EntrypointId::from_string(entrypoint_id);
                 let plugin_id = PluginId::from_string(plugin_id);
 
-                let entrypoint_data = entrypoint_data
-                    .get(&plugin_id)
-                    .expect("Plugin should always exist in entrypoint data")
-                    .entrypoints
-                    .get(&entrypoint_id)
-                    .expect("Entrypoint should always exist in plugin in entrypoint data");
-
-                let entrypoint_actions = entrypoint_data
-                    .actions
-                    .iter()
-                    .map(|data| {
-                        SearchResultEntrypointAction {
-                            action_type: match data.action_type {
-                                EntrypointActionType::Command => SearchResultEntrypointActionType::Command,
-                                EntrypointActionType::View => SearchResultEntrypointActionType::View,
-                            },
-                            label: data.label.clone(),
-                            shortcut: data.shortcut.clone(),
-                        }
-                    })
-                    .collect();
+                // index loaded from disk can be slightly out of sync with entrypoint data if gauntlet was stopped while saving it
+                let Some(plugin_data) = entrypoint_data.get(&plugin_id) else {
+                    return Ok(None);
+                };
 
-                let entrypoint_accessories = entrypoint_data.accessories.iter().cloned().collect();
-
-                let result_item = SearchResult {
-                    entrypoint_type: entrypoint_data.entrypoint_type.clone(),
-                    entrypoint_name,
-                    entrypoint_generator_name: entrypoint_data
-                        .entrypoint_generator
-                        .as_ref()
-                        .map(|(_, name)| name.clone()),
-                    entrypoint_id,
-                    entrypoint_icon: entrypoint_data.icon.clone(),
-                    plugin_name,
-                    plugin_id,
-                    entrypoint_actions,
-                    entrypoint_accessories,
-                    entrypoint_alias,
+                let Some(entrypoint_data) = plugin_data.entrypoints.get(&entrypoint_id) else {
+                    return Ok(None);
                 };
 
-                Ok((result_item, entrypoint_data.frecency))
+                let result_item = search_result(plugin_id, plugin_data, entrypoint_id, entrypoint_data);
+
+                Ok(Some((result_item, entrypoint_data.frecency)))
             })
-            .collect::<anyhow::Result<Vec<_>>>()?;
+            .collect::<anyhow::Result<Vec<_>>>()?
+            .into_iter()
+            .flatten()
+            .collect();
 
         Ok(result)
     }
@@ -534,7 +723,11 @@ struct QueryParser {
     tokenizer_manager: TokenizerManager,
     entrypoint_name: Field,
     plugin_name: Field,
+    plugin_id: Field,
     entrypoint_alias: Field,
+    entrypoint_initials: Field,
+    entrypoint_description: Field,
+    entrypoint_keywords: Field,
 }
 
 impl QueryParser {
@@ -542,17 +735,43 @@ impl QueryParser {
         tokenizer_manager: TokenizerManager,
         entrypoint_name: Field,
         plugin_name: Field,
+        plugin_id: Field,
         entrypoint_alias: Field,
+        entrypoint_initials: Field,
+        entrypoint_description: Field,
+        entrypoint_keywords: Field,
     ) -> Self {
         Self {
             tokenizer_manager,
             entrypoint_name,
             plugin_name,
+            plugin_id,
             entrypoint_alias,
+            entrypoint_initials,
+            entrypoint_description,
+            entrypoint_keywords,
+        }
+    }
+
+    fn create_query(&self, query: &str, scope: Option<&PluginId>) -> Box<dyn Query> {
+        self.scoped(self.create_match_query(query), scope)
+    }
+
+    fn scoped(&self, query: Box<dyn Query>, scope: Option<&PluginId>) -> Box<dyn Query> {
+        match scope {
+            None => query,
+            Some(plugin_id) => {
+                let plugin_query = TermQuery::new(
+                    Term::from_field_text(self.plugin_id, &plugin_id.to_string()),
+                    IndexRecordOption::Basic,
+                );
+
+                Box::new(BooleanQuery::intersection(vec![query, Box::new(plugin_query)]))
+            }
         }
     }
 
-    fn create_query(&self, query: &str) -> Box<dyn Query> {
+    fn create_match_query(&self, query: &str) -> Box<dyn Query> {
         if query.is_empty() {
             return Box::new(AllQuery);
         }
@@ -578,12 +797,71 @@ impl QueryParser {
         let entrypoint_name_terms = terms_fn(self.entrypoint_name);
         let plugin_name_terms = terms_fn(self.plugin_name);
         let entrypoint_alias_terms = terms_fn(self.entrypoint_alias);
+        let entrypoint_description_terms = terms_fn(self.entrypoint_description);
+        let entrypoint_keywords_terms = terms_fn(self.entrypoint_keywords);
 
-        Box::new(BooleanQuery::union(vec![
+        let contains_query = BooleanQuery::union(vec![
             Box::new(entrypoint_name_terms),
             Box::new(plugin_name_terms),
             Box::new(entrypoint_alias_terms),
-        ]))
+            Box::new(entrypoint_description_terms),
+            Box::new(entrypoint_keywords_terms),
+        ]);
+
+        let contains_query = Box::new(contains_query);
+
+        let initials = self.tokenize(query).concat();
+        if initials.is_empty() {
+            return contains_query;
+        }
+
+        // "vsc" matches "Visual Studio Code"
+        let initials_query =
+            RegexQuery::from_pattern(&format!("{}.*", regex::escape(&initials)), self.entrypoint_initials)
+                .expect("there should not exist a situation where that regex is invalid");
+
+        Box::new(BooleanQuery::union(vec![contains_query, Box::new(initials_query)]))
+    }
+
+    /// Same as [`QueryParser::create_query`] but each term also matches words within small edit distance
+    fn create_fuzzy_query(&self, query: &str, scope: Option<&PluginId>) -> Option<Box<dyn Query>> {
+        let terms = self.tokenize(query);
+
+        if terms.iter().all(|term| fuzzy_distance(term) == 0) {
+            return None;
+        }
+
+        let terms_fn = |field: Field| -> Box<dyn Query> {
+            let res = terms
+                .iter()
+                .map(|term| -> Box<dyn Query> {
+                    let contains: Box<dyn Query> = Box::new(
+                        RegexQuery::from_pattern(&format!(".*{}.*", regex::escape(term)), field)
+                            .expect("there should not exist a situation where that regex is invalid"),
+                    );
+
+                    match fuzzy_distance(term) {
+                        0 => contains,
+                        distance => {
+                            let fuzzy = FuzzyTermQuery::new_prefix(Term::from_field_text(field, term), distance, true);
+
+                            Box::new(BooleanQuery::union(vec![contains, Box::new(fuzzy)]))
+                        }
+                    }
+                })
+                .collect::<Vec<_>>();
+
+            Box::new(BooleanQuery::intersection(res))
+        };
+
+        let fuzzy_query = Box::new(BooleanQuery::union(vec![
+            terms_fn(self.entrypoint_name),
+            terms_fn(self.plugin_name),
+            terms_fn(self.entrypoint_alias),
+            terms_fn(self.entrypoint_keywords),
+        ]));
+
+        Some(self.scoped(fuzzy_query, scope))
     }
 
     fn tokenize(&self, query: &str) -> Vec<String> {
@@ -601,3 +879,491 @@ impl QueryParser {
         terms
     }
 }
+
+// weights of match kinds, relative to substring match
+const EXACT_MATCH: f64 = 4.0;
+const PREFIX_MATCH: f64 = 3.0;
+const WORD_START_MATCH: f64 = 2.0;
+const SUBSTRING_MATCH: f64 = 1.0;
+
+// weights of fields in which match was found
+const ALIAS_FIELD: f64 = 1.5;
+const ENTRYPOINT_NAME_FIELD: f64 = 1.0;
+const PLUGIN_NAME_FIELD: f64 = 0.5;
+const KEYWORDS_FIELD: f64 = 0.4;
+const DESCRIPTION_FIELD: f64 = 0.25;
+
+struct RankingWeights {
+    match_weight: f64,
+    frecency_weight: f64,
+    query_frecency_weight: f64,
+    initials_weight: f64,
+}
+
+/// Orders search results by blending how well the query matched with frecency of entrypoint
+struct Ranking<'a> {
+    terms: Vec<String>,
+    entrypoint_data: &'a HashMap<PluginId, PluginData>,
+    query_frecency: &'a HashMap<(PluginId, EntrypointId), f64>,
+    weights: RankingWeights,
+}
+
+impl Ranking<'_> {
+    fn rank(&self, query_parser: &QueryParser, items: Vec<(SearchResult, f64)>) -> Vec<SearchResult> {
+        let max_frecency = items.iter().map(|(_, frecency)| *frecency).fold(0.0, f64::max);
+        let max_query_frecency = self.query_frecency.values().copied().fold(0.0, f64::max);
+
+        let mut items = items
+            .into_iter()
+            .map(|(item, frecency)| {
+                let query_frecency = self.normalized_query_frecency(&item, max_query_frecency);
+
+                let score = self.score(query_parser, &item, frecency, max_frecency)
+                    + self.weights.query_frecency_weight * query_frecency;
+
+                (item, score, frecency)
+            })
+            .collect::<Vec<_>>();
+
+        items.sort_by(|(item_a, score_a, frecency_a), (item_b, score_b, frecency_b)| {
+            score_b
+                .total_cmp(score_a)
+                .then_with(|| frecency_b.total_cmp(frecency_a))
+                .then_with(|| {
+                    item_a
+                        .entrypoint_name
+                        .to_lowercase()
+                        .cmp(&item_b.entrypoint_name.to_lowercase())
+                })
+                .then_with(|| item_a.plugin_id.to_string().cmp(&item_b.plugin_id.to_string()))
+                .then_with(|| item_a.entrypoint_id.to_string().cmp(&item_b.entrypoint_id.to_string()))
+        });
+
+        items.into_iter().map(|(item, _, _)| item).collect()
+    }
+
+    fn score(&self, query_parser: &QueryParser, item: &SearchResult, frecency: f64, max_frecency: f64) -> f64 {
+        let alias_match = item
+            .entrypoint_aliases
+            .iter()
+            .map(|alias| self.match_quality(query_parser, alias))
+            .fold(0.0, f64::max);
+
+        let entrypoint_name_match = self.match_quality(query_parser, &item.entrypoint_name);
+        let plugin_name_match = self.match_quality(query_parser, &item.plugin_name);
+
+        let entrypoint_data = self
+            .entrypoint_data
+            .get(&item.plugin_id)
+            .and_then(|plugin_data| plugin_data.entrypoints.get(&item.entrypoint_id));
+
+        let (keywords_match, description_match) = match entrypoint_data {
+            None => (0.0, 0.0),
+            Some(entrypoint_data) => {
+                let keywords_match = entrypoint_data
+                    .entrypoint_keywords
+                    .iter()
+                    .map(|keyword| self.match_quality(query_parser, keyword))
+                    .fold(0.0, f64::max);
+
+                let description_match = self.match_quality(query_parser, &entrypoint_data.entrypoint_description);
+
+                (keywords_match, description_match)
+            }
+        };
+
+        let match_quality = [
+            alias_match * ALIAS_FIELD,
+            entrypoint_name_match * ENTRYPOINT_NAME_FIELD,
+            plugin_name_match * PLUGIN_NAME_FIELD,
+            keywords_match * KEYWORDS_FIELD,
+            description_match * DESCRIPTION_FIELD,
+        ]
+        .into_iter()
+        .fold(0.0, f64::max);
+
+        let frecency = if max_frecency > 0.0 {
+            frecency / max_frecency
+        } else {
+            0.0
+        };
+
+        self.weights.match_weight * match_quality + self.weights.frecency_weight * frecency
+    }
+
+    fn normalized_query_frecency(&self, item: &SearchResult, max_query_frecency: f64) -> f64 {
+        if max_query_frecency <= 0.0 {
+            return 0.0;
+        }
+
+        self.query_frecency
+            .get(&(item.plugin_id.clone(), item.entrypoint_id.clone()))
+            .map(|frecency| frecency / max_query_frecency)
+            .unwrap_or(0.0)
+    }
+
+    fn match_quality(&self, query_parser: &QueryParser, value: &str) -> f64 {
+        if self.terms.is_empty() {
+            return 0.0;
+        }
+
+        let value_terms = query_parser.tokenize(value);
+
+        if value_terms == self.terms {
+            return EXACT_MATCH;
+        }
+
+        if value_terms.join(" ").starts_with(&self.terms.join(" ")) {
+            return PREFIX_MATCH;
+        }
+
+        let words = words(value);
+
+        if self
+            .terms
+            .iter()
+            .all(|term| words.iter().any(|word| word.starts_with(term.as_str())))
+        {
+            return WORD_START_MATCH;
+        }
+
+        let initials = words.iter().filter_map(|word| word.chars().next()).collect::<String>();
+
+        if initials.starts_with(&self.terms.concat()) {
+            return self.weights.initials_weight;
+        }
+
+        let value = value.to_lowercase();
+
+        if self.terms.iter().all(|term| value.contains(term.as_str())) {
+            return SUBSTRING_MATCH;
+        }
+
+        0.0
+    }
+}
+
+fn save_plugins(
+    storage: &SearchIndexStorage,
+    entrypoint_data: &Mutex<HashMap<PluginId, PluginData>>,
+    unsaved_plugins: &Mutex<HashSet<PluginId>>,
+) {
+    // lock is held while writing, so plugin which is being removed is not written back
+    let entrypoint_data = entrypoint_data.lock().expect("lock is poisoned");
+    let unsaved_plugins = std::mem::take(&mut *unsaved_plugins.lock().expect("lock is poisoned"));
+
+    for plugin_id in unsaved_plugins {
+        let Some(plugin_data) = entrypoint_data.get(&plugin_id) else {
+            continue;
+        };
+
+        if let Err(err) = storage.write_plugin(&plugin_id, plugin_data) {
+            tracing::warn!("Unable to store entrypoint data of plugin {:?}: {:?}", plugin_id, err);
+        }
+    }
+}
+
+/// "gh some text" finds entrypoints with "gh" keyword alias, "some text" is then passed to entrypoint when it is run
+fn keyword_alias_search(
+    entrypoint_data: &HashMap<PluginId, PluginData>,
+    query: &str,
+    scope: Option<&PluginId>,
+) -> Vec<SearchResult> {
+    let Some((keyword, rest)) = query.trim_start().split_once(char::is_whitespace) else {
+        return vec![];
+    };
+
+    let rest = rest.trim();
+
+    let mut result = entrypoint_data
+        .iter()
+        .filter(|(plugin_id, _)| scope.map(|scope| scope == *plugin_id).unwrap_or(true))
+        .flat_map(|(plugin_id, plugin_data)| {
+            plugin_data
+                .entrypoints
+                .iter()
+                .filter(|(_, entrypoint_data)| {
+                    entrypoint_data
+                        .search_aliases
+                        .iter()
+                        .any(|alias| alias.keyword && alias.alias.eq_ignore_ascii_case(keyword))
+                })
+                .map(|(entrypoint_id, entrypoint_data)| {
+                    SearchResult {
+                        keyword_alias_match: true,
+                        keyword_alias_query: (!rest.is_empty()).then(|| rest.to_owned()),
+                        ..search_result(plugin_id.clone(), plugin_data, entrypoint_id.clone(), entrypoint_data)
+                    }
+                })
+        })
+        .collect::<Vec<_>>();
+
+    result.sort_by(|item_a, item_b| item_a.entrypoint_name.cmp(&item_b.entrypoint_name));
+
+    result
+}
+
+fn search_result(
+    plugin_id: PluginId,
+    plugin_data: &PluginData,
+    entrypoint_id: EntrypointId,
+    entrypoint_data: &EntrypointData,
+) -> SearchResult {
+    let entrypoint_actions = entrypoint_data
+        .actions
+        .iter()
+        .map(|data| {
+            SearchResultEntrypointAction {
+                id: data.id.clone(),
+                action_type: match data.action_type {
+                    EntrypointActionType::Command => SearchResultEntrypointActionType::Command,
+                    EntrypointActionType::View => SearchResultEntrypointActionType::View,
+                },
+                label: data.label.clone(),
+                shortcut: data.shortcut.clone(),
+            }
+        })
+        .collect();
+
+    let entrypoint_accessories = entrypoint_data.accessories.iter().cloned().collect();
+
+    let entrypoint_aliases = entrypoint_data
+        .search_aliases
+        .iter()
+        .map(|alias| alias.alias.clone())
+        .collect();
+
+    SearchResult {
+        entrypoint_type: entrypoint_data.entrypoint_type.clone(),
+        entrypoint_name: entrypoint_data.entrypoint_name.clone(),
+        entrypoint_generator_name: entrypoint_data
+            .entrypoint_generator
+            .as_ref()
+            .map(|(_, name)| name.clone()),
+        entrypoint_id,
+        entrypoint_icon: entrypoint_data.icon.clone(),
+        plugin_name: plugin_data.plugin_name.clone(),
+        plugin_id,
+        entrypoint_actions,
+        entrypoint_accessories,
+        entrypoint_aliases,
+        entrypoint_pinned: false,
+        keyword_alias_match: false,
+        keyword_alias_query: None,
+        entrypoint_last_run: None,
+    }
+}
+
+/// Splits `<keyword>:` or `<keyword> ` prefix off the query if the keyword is assigned to one of the plugins
+/// e.g. "app: fire" -> (applications plugin, "fire")
+pub fn parse_search_scope<'a>(query: &'a str, scopes: &HashMap<PluginId, String>) -> Option<(PluginId, &'a str)> {
+    let query = query.trim_start();
+
+    let (keyword, rest) = query.split_once(|char: char| char == ':' || char.is_whitespace())?;
+
+    let (plugin_id, _) = scopes
+        .iter()
+        .find(|(_, scope_keyword)| !scope_keyword.is_empty() && scope_keyword.eq_ignore_ascii_case(keyword))?;
+
+    Some((plugin_id.clone(), rest.trim_start()))
+}
+
+/// Allowed number of typos scales with term length, short terms are matched exactly
+fn fuzzy_distance(term: &str) -> u8 {
+    match term.chars().count() {
+        0..=2 => 0,
+        3..=5 => 1,
+        _ => 2,
+    }
+}
+
+/// First letters of each word
+/// e.g. "Visual Studio Code" -> "vsc", "gauntlet-dev-tools" -> "gdt", "openTerminalHere" -> "oth"
+fn word_initials(name: &str) -> String {
+    words(name).iter().filter_map(|word| word.chars().next()).collect()
+}
+
+/// Lowercase words, separated by whitespace, punctuation or camelCase boundary
+fn words(name: &str) -> Vec<String> {
+    let chars = name.chars().collect::<Vec<_>>();
+
+    let mut words: Vec<String> = vec![];
+
+    for (index, char) in chars.iter().enumerate() {
+        if !char.is_alphanumeric() {
+            continue;
+        }
+
+        let word_start = match index.checked_sub(1).map(|prev| chars[prev]) {
+            None => true,
+            Some(prev) if !prev.is_alphanumeric() => true,
+            Some(prev) if prev.is_lowercase() && char.is_uppercase() => true,
+            Some(prev) if prev.is_alphabetic() != char.is_alphabetic() => true,
+            Some(_) => false,
+        };
+
+        match words.last_mut() {
+            Some(word) if !word_start => word.extend(char.to_lowercase()),
+            _ => words.push(char.to_lowercase().collect()),
+        }
+    }
+
+    words
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn query_parser() -> QueryParser {
+        QueryParser::new(
+            TokenizerManager::default(),
+            Field::from_field_id(0),
+            Field::from_field_id(1),
+            Field::from_field_id(2),
+            Field::from_field_id(3),
+            Field::from_field_id(4),
+            Field::from_field_id(5),
+            Field::from_field_id(6),
+        )
+    }
+
+    fn item(entrypoint_name: &str, frecency: f64) -> (SearchResult, f64) {
+        let item = SearchResult {
+            plugin_id: PluginId::from_string("file:///plugin"),
+            plugin_name: "Plugin".to_string(),
+            entrypoint_id: EntrypointId::from_string(entrypoint_name.to_lowercase().replace(' ', "-")),
+            entrypoint_name: entrypoint_name.to_string(),
+            entrypoint_generator_name: None,
+            entrypoint_icon: None,
+            entrypoint_type: SearchResultEntrypointType::Command,
+            entrypoint_actions: vec![],
+            entrypoint_accessories: vec![],
+            entrypoint_aliases: vec![],
+            entrypoint_pinned: false,
+            keyword_alias_match: false,
+            keyword_alias_query: None,
+            entrypoint_last_run: None,
+        };
+
+        (item, frecency)
+    }
+
+    fn rank(query: &str, items: Vec<(SearchResult, f64)>) -> Vec<String> {
+        let query_parser = query_parser();
+        let entrypoint_data = HashMap::new();
+        let query_frecency = HashMap::new();
+
+        // same as defaults in config
+        let ranking = Ranking {
+            terms: query_parser.tokenize(query),
+            entrypoint_data: &entrypoint_data,
+            query_frecency: &query_frecency,
+            weights: RankingWeights {
+                match_weight: 1.0,
+                frecency_weight: 1.5,
+                query_frecency_weight: 3.0,
+                initials_weight: 2.0,
+            },
+        };
+
+        ranking
+            .rank(&query_parser, items)
+            .into_iter()
+            .map(|item| item.entrypoint_name)
+            .collect()
+    }
+
+    #[test]
+    fn match_kinds_are_ordered_by_quality() {
+        // fuzzy match is returned by index, but doesn't match the query literally
+        let items = vec![
+            item("Tetminal Emulator", 0.0),
+            item("Intermediate", 0.0),
+            item("Open Terminal", 0.0),
+            item("Terminal", 0.0),
+            item("Term", 0.0),
+        ];
+
+        assert_eq!(
+            rank("term", items),
+            vec!["Term", "Terminal", "Open Terminal", "Intermediate", "Tetminal Emulator"]
+        );
+    }
+
+    #[test]
+    fn match_quality_of_each_kind() {
+        let query_parser = query_parser();
+        let entrypoint_data = HashMap::new();
+        let query_frecency = HashMap::new();
+
+        let ranking = Ranking {
+            terms: query_parser.tokenize("vsc"),
+            entrypoint_data: &entrypoint_data,
+            query_frecency: &query_frecency,
+            weights: RankingWeights {
+                match_weight: 1.0,
+                frecency_weight: 1.5,
+                query_frecency_weight: 3.0,
+                initials_weight: 2.5,
+            },
+        };
+
+        assert_eq!(ranking.match_quality(&query_parser, "VSC"), EXACT_MATCH);
+        assert_eq!(ranking.match_quality(&query_parser, "vscodium"), PREFIX_MATCH);
+        assert_eq!(ranking.match_quality(&query_parser, "open vscodium"), WORD_START_MATCH);
+        assert_eq!(ranking.match_quality(&query_parser, "Visual Studio Code"), 2.5);
+        assert_eq!(ranking.match_quality(&query_parser, "mvsc"), SUBSTRING_MATCH);
+        assert_eq!(ranking.match_quality(&query_parser, "Vim"), 0.0);
+    }
+
+    #[test]
+    fn initials_match() {
+        let items = vec![
+            item("Services", 0.0),
+            item("Visual Studio Code", 0.0),
+            item("VSCodium", 0.0),
+        ];
+
+        assert_eq!(rank("vsc", items), vec!["VSCodium", "Visual Studio Code", "Services"]);
+    }
+
+    #[test]
+    fn frecency_breaks_ties_between_equal_matches() {
+        let items = vec![
+            item("Firefox Private Window", 0.2),
+            item("Firefox New Window", 0.0),
+            item("Firefox Profiles", 1.0),
+            item("Firefox Addons", 0.0),
+        ];
+
+        // entrypoints which were never used are ordered by name
+        assert_eq!(
+            rank("firefox", items),
+            vec![
+                "Firefox Profiles",
+                "Firefox Private Window",
+                "Firefox Addons",
+                "Firefox New Window"
+            ]
+        );
+    }
+
+    #[test]
+    fn frecency_is_blended_with_match_quality() {
+        let items = vec![item("Firefox", 0.0), item("Firefox Profiles", 1.0)];
+
+        assert_eq!(rank("firefox", items), vec!["Firefox Profiles", "Firefox"]);
+
+        let items = vec![item("Firefox", 0.5), item("Firefox Profiles", 1.0)];
+
+        assert_eq!(rank("firefox", items), vec!["Firefox", "Firefox Profiles"]);
+    }
+
+    #[test]
+    fn frecency_orders_results_without_query() {
+        let items = vec![item("Calculator", 0.5), item("Alarm", 0.0), item("Browser", 1.0)];
+
+        assert_eq!(rank("", items), vec!["Browser", "Calculator", "Alarm"]);
+    }
+}
@@ -0,0 +1,135 @@
+// run with `cargo test --release -p gauntlet-server search::benches -- --ignored --nocapture`
+
+use std::time::Duration;
+use std::time::Instant;
+
+use criterion::Criterion;
+use criterion::SamplingMode;
+use gauntlet_common::dirs::Dirs;
+use gauntlet_common::model::EntrypointId;
+use gauntlet_common::model::PluginId;
+use gauntlet_common::model::SearchResultEntrypointType;
+use gauntlet_common::rpc::frontend_api::FrontendApiProxy;
+use gauntlet_common::rpc::frontend_api::FrontendApiRequestData;
+use gauntlet_common::rpc::frontend_api::FrontendApiResponseData;
+use gauntlet_utils::channel::channel;
+use tempfile::TempDir;
+
+use crate::plugins::DataDbRepository;
+use crate::plugins::Settings;
+use crate::search::SearchIndex;
+use crate::search::SearchIndexItem;
+
+const ENTRYPOINT_COUNT: usize = 1000;
+const UPDATE_INTERVAL: Duration = Duration::from_millis(100);
+const UPDATES_PER_ITERATION: u32 = 10;
+
+fn search_index(dir: &TempDir) -> SearchIndex {
+    let (frontend_sender, _) = channel::<FrontendApiRequestData, FrontendApiResponseData>();
+    let frontend_api = FrontendApiProxy::new(frontend_sender);
+
+    // config of installed gauntlet is not read
+    let settings = Settings::new(
+        Dirs::with_root(dir.path()),
+        DataDbRepository::new_in_memory().unwrap(),
+        frontend_api.clone(),
+        false,
+    )
+    .unwrap();
+
+    // kept only in memory, so that search index of installed gauntlet is not touched
+    SearchIndex::create_index_with_storage(frontend_api, settings, None).unwrap()
+}
+
+// simulates generator plugin like window tracking, which reports all its generated entrypoints on every change
+fn update(search_index: &SearchIndex, generation: u32) {
+    let search_items = (0..ENTRYPOINT_COUNT)
+        .map(|index| {
+            SearchIndexItem {
+                entrypoint_type: SearchResultEntrypointType::Generated,
+                entrypoint_name: format!("Window {} - generation {}", index, generation),
+                entrypoint_description: String::new(),
+                entrypoint_keywords: vec![],
+                entrypoint_generator: Some((EntrypointId::from_string("windows"), "Windows".to_string())),
+                entrypoint_id: EntrypointId::from_string(format!("window-{}", index)),
+                entrypoint_icon: None,
+                entrypoint_frecency: 0.0,
+                entrypoint_actions: vec![],
+                entrypoint_accessories: vec![],
+            }
+        })
+        .collect();
+
+    search_index
+        .save_for_plugin(
+            PluginId::from_string("bundled://windows"),
+            "Windows".to_string(),
+            "1".to_string(),
+            search_items,
+            false,
+        )
+        .unwrap();
+}
+
+#[test]
+#[ignore = "benchmark"]
+fn search_index_benchmark() {
+    // index writer uses tokio handle of the runtime it was created in
+    let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+    let _guard = runtime.enter();
+
+    let mut criterion = Criterion::default();
+    let mut group = criterion.benchmark_group("search_index");
+
+    group.bench_function("replace 1000 generated entrypoints and commit", |b| {
+        let dir = tempfile::tempdir().unwrap();
+        let search_index = search_index(&dir);
+        let mut generation = 0;
+
+        b.iter(|| {
+            generation += 1;
+
+            update(&search_index, generation);
+            search_index.flush();
+        })
+    });
+
+    group.sample_size(10);
+    group.sampling_mode(SamplingMode::Flat);
+    group.measurement_time(Duration::from_secs(15));
+
+    // measures time callers are blocked by index updates, which happen at 10 Hz for one second per iteration
+    group.bench_function("1000 generated entrypoints updated at 10 Hz", |b| {
+        let dir = tempfile::tempdir().unwrap();
+        let search_index = search_index(&dir);
+        let mut generation = 0;
+
+        b.iter_custom(|iters| {
+            let mut blocked = Duration::ZERO;
+
+            for _ in 0..iters {
+                for _ in 0..UPDATES_PER_ITERATION {
+                    generation += 1;
+
+                    let start = Instant::now();
+                    update(&search_index, generation);
+                    blocked += start.elapsed();
+
+                    std::thread::sleep(UPDATE_INTERVAL);
+                }
+
+                let start = Instant::now();
+                search_index.flush();
+                blocked += start.elapsed();
+            }
+
+            assert_eq!(search_index.index_reader.searcher().num_docs(), ENTRYPOINT_COUNT as u64);
+
+            blocked
+        });
+    });
+
+    group.finish();
+
+    criterion.final_summary();
+}
@@ -0,0 +1,286 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use anyhow::Context;
+use bincode::Decode;
+use bincode::Encode;
+use gauntlet_common::dirs::Dirs;
+use gauntlet_common::model::EntrypointId;
+use gauntlet_common::model::PhysicalShortcut;
+use gauntlet_common::model::PluginId;
+use gauntlet_common::model::SearchResultAccessory;
+use gauntlet_common::model::SearchResultEntrypointType;
+use sha2::Digest;
+use sha2::Sha256;
+use tantivy::Index;
+use tantivy::directory::MmapDirectory;
+use tantivy::schema::Schema;
+
+use crate::search::EntrypointActionData;
+use crate::search::EntrypointActionType;
+use crate::search::EntrypointData;
+use crate::search::PluginData;
+
+// has to be incremented every time index schema, format or file names of stored entrypoint data change
+const STORAGE_FORMAT_VERSION: u32 = 2;
+
+/// Keeps search index and entrypoint data on disk, so search results are available
+/// right after startup, before plugin runtimes are started and have reported their entrypoints
+#[derive(Clone)]
+pub struct SearchIndexStorage {
+    dir: PathBuf,
+}
+
+#[derive(Encode, Decode)]
+struct StoredPluginData {
+    plugin_id: PluginId,
+    plugin_name: String,
+    plugin_version: String,
+    entrypoints: Vec<StoredEntrypointData>,
+}
+
+#[derive(Encode, Decode)]
+struct StoredEntrypointData {
+    entrypoint_id: EntrypointId,
+    entrypoint_name: String,
+    entrypoint_description: String,
+    entrypoint_keywords: Vec<String>,
+    entrypoint_generator: Option<(EntrypointId, String)>,
+    entrypoint_type: SearchResultEntrypointType,
+    icon: Option<Vec<u8>>,
+    frecency: f64,
+    actions: Vec<StoredEntrypointActionData>,
+    accessories: Vec<SearchResultAccessory>,
+}
+
+#[derive(Encode, Decode)]
+struct StoredEntrypointActionData {
+    id: Option<String>,
+    label: String,
+    view: bool,
+    shortcut: Option<PhysicalShortcut>,
+}
+
+impl SearchIndexStorage {
+    pub fn new(dirs: &Dirs) -> Self {
+        Self {
+            dir: dirs.search_index_dir(),
+        }
+    }
+
+    /// Opens index stored on disk, everything stored is discarded if it was written by a different version of Gauntlet or is corrupted
+    pub fn open(&self, schema: &Schema) -> anyhow::Result<(Index, HashMap<PluginId, PluginData>)> {
+        if self.stored_version().as_deref() != Some(&current_version()) {
+            tracing::info!("Search index on disk is missing or outdated, creating a new one");
+
+            self.clear()?;
+        }
+
+        let index = match self.open_index(schema) {
+            Ok(index) => index,
+            Err(err) => {
+                tracing::warn!(
+                    "Unable to open search index stored on disk, creating a new one: {:?}",
+                    err
+                );
+
+                self.clear()?;
+                self.open_index(schema)?
+            }
+        };
+
+        std::fs::write(self.version_file(), current_version()).context("Unable to write search index version")?;
+
+        Ok((index, self.read_plugins()?))
+    }
+
+    pub fn write_plugin(&self, plugin_id: &PluginId, plugin_data: &PluginData) -> anyhow::Result<()> {
+        let data = StoredPluginData {
+            plugin_id: plugin_id.clone(),
+            plugin_name: plugin_data.plugin_name.clone(),
+            plugin_version: plugin_data.plugin_version.clone(),
+            entrypoints: plugin_data
+                .entrypoints
+                .iter()
+                .map(|(entrypoint_id, data)| stored_entrypoint_data(entrypoint_id, data))
+                .collect(),
+        };
+
+        let data = bincode::encode_to_vec(&data, bincode::config::standard())?;
+
+        let path = self.plugin_file(plugin_id);
+        let temp_path = path.with_extension("tmp");
+
+        // rename to avoid reading partially written file if gauntlet is stopped while writing
+        std::fs::write(&temp_path, data)?;
+        std::fs::rename(&temp_path, &path)?;
+
+        Ok(())
+    }
+
+    pub fn remove_plugin(&self, plugin_id: &PluginId) -> anyhow::Result<()> {
+        let path = self.plugin_file(plugin_id);
+
+        if path.exists() {
+            std::fs::remove_file(path)?;
+        }
+
+        Ok(())
+    }
+
+    fn open_index(&self, schema: &Schema) -> anyhow::Result<Index> {
+        std::fs::create_dir_all(self.index_dir())?;
+        std::fs::create_dir_all(self.plugins_dir())?;
+
+        let directory = MmapDirectory::open(self.index_dir())?;
+
+        Ok(Index::open_or_create(directory, schema.clone())?)
+    }
+
+    fn read_plugins(&self) -> anyhow::Result<HashMap<PluginId, PluginData>> {
+        let mut result = HashMap::new();
+
+        for entry in std::fs::read_dir(self.plugins_dir())? {
+            let path = entry?.path();
+
+            if path.extension().and_then(|extension| extension.to_str()) != Some("bin") {
+                continue;
+            }
+
+            let data = std::fs::read(&path).map_err(anyhow::Error::from).and_then(|data| {
+                let (data, _) = bincode::decode_from_slice::<StoredPluginData, _>(&data, bincode::config::standard())?;
+
+                Ok(data)
+            });
+
+            match data {
+                Ok(data) => {
+                    let (plugin_id, plugin_data) = plugin_data(data);
+
+                    result.insert(plugin_id, plugin_data);
+                }
+                Err(err) => {
+                    // plugin will be removed from index as inconsistent and added back when its runtime is started
+                    tracing::warn!("Unable to read stored entrypoint data from {:?}: {:?}", path, err);
+
+                    std::fs::remove_file(&path)?;
+                }
+            }
+        }
+
+        Ok(result)
+    }
+
+    fn clear(&self) -> anyhow::Result<()> {
+        if self.dir.exists() {
+            std::fs::remove_dir_all(&self.dir).context("Unable to remove search index directory")?;
+        }
+
+        Ok(())
+    }
+
+    fn stored_version(&self) -> Option<String> {
+        std::fs::read_to_string(self.version_file()).ok()
+    }
+
+    fn version_file(&self) -> PathBuf {
+        self.dir.join("version")
+    }
+
+    fn index_dir(&self) -> PathBuf {
+        self.dir.join("index")
+    }
+
+    fn plugins_dir(&self) -> PathBuf {
+        self.dir.join("entrypoint_data")
+    }
+
+    fn plugin_file(&self, plugin_id: &PluginId) -> PathBuf {
+        // plugin id is an url, so it cannot be used as a file name as is.
+        // hash has to be stable across gauntlet versions, so std hasher is not used
+        let hash = Sha256::digest(plugin_id.to_string().as_bytes());
+
+        self.plugins_dir().join(format!("{:x}.bin", hash))
+    }
+}
+
+fn current_version() -> String {
+    let gauntlet_version = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/../../VERSION")).trim();
+
+    format!("{}-{}", gauntlet_version, STORAGE_FORMAT_VERSION)
+}
+
+fn stored_entrypoint_data(entrypoint_id: &EntrypointId, data: &EntrypointData) -> StoredEntrypointData {
+    StoredEntrypointData {
+        entrypoint_id: entrypoint_id.clone(),
+        entrypoint_name: data.entrypoint_name.clone(),
+        entrypoint_description: data.entrypoint_description.clone(),
+        entrypoint_keywords: data.entrypoint_keywords.clone(),
+        entrypoint_generator: data.entrypoint_generator.clone(),
+        entrypoint_type: data.entrypoint_type.clone(),
+        icon: data.icon.as_ref().map(|icon| icon.to_vec()),
+        frecency: data.frecency,
+        actions: data
+            .actions
+            .iter()
+            .map(|action| {
+                StoredEntrypointActionData {
+                    id: action.id.clone(),
+                    label: action.label.clone(),
+                    view: matches!(action.action_type, EntrypointActionType::View),
+                    shortcut: action.shortcut.clone(),
+                }
+            })
+            .collect(),
+        accessories: data.accessories.clone(),
+    }
+}
+
+fn plugin_data(data: StoredPluginData) -> (PluginId, PluginData) {
+    let entrypoints = data
+        .entrypoints
+        .into_iter()
+        .map(|data| {
+            let actions = data
+                .actions
+                .into_iter()
+                .map(|action| {
+                    EntrypointActionData {
+                        id: action.id,
+                        label: action.label,
+                        action_type: if action.view {
+                            EntrypointActionType::View
+                        } else {
+                            EntrypointActionType::Command
+                        },
+                        shortcut: action.shortcut,
+                    }
+                })
+                .collect();
+
+            let entrypoint_data = EntrypointData {
+                entrypoint_name: data.entrypoint_name,
+                entrypoint_description: data.entrypoint_description,
+                entrypoint_keywords: data.entrypoint_keywords,
+                entrypoint_generator: data.entrypoint_generator,
+                entrypoint_type: data.entrypoint_type,
+                icon: data.icon.map(bytes::Bytes::from),
+                frecency: data.frecency,
+                actions,
+                accessories: data.accessories,
+                // aliases are stored in settings
+                search_aliases: vec![],
+            };
+
+            (data.entrypoint_id, entrypoint_data)
+        })
+        .collect();
+
+    let plugin_data = PluginData {
+        plugin_name: data.plugin_name,
+        plugin_version: data.plugin_version,
+        entrypoints,
+    };
+
+    (data.plugin_id, plugin_data)
+}
@@ -0,0 +1,178 @@
+use std::sync::mpsc;
+use std::sync::mpsc::RecvTimeoutError;
+use std::thread;
+use std::time::Duration;
+use std::time::Instant;
+
+use tantivy::Index;
+use tantivy::IndexReader;
+use tantivy::IndexWriter;
+use tantivy::TantivyDocument;
+use tantivy::query::Query;
+
+// changes are committed only after index was not changed for this long, so frequent updates are committed together
+const COMMIT_DEBOUNCE: Duration = Duration::from_millis(100);
+// but index which is changed continuously still has to show the changes at some point
+const COMMIT_MAX_DELAY: Duration = Duration::from_millis(500);
+
+enum WriterCommand {
+    Update {
+        delete: Box<dyn Query>,
+        documents: Vec<TantivyDocument>,
+        refresh_search_list: bool,
+    },
+    // only used to measure and check results of updates
+    #[cfg(test)]
+    Flush(mpsc::Sender<()>),
+}
+
+struct PendingCommit {
+    first_change: Instant,
+    last_change: Instant,
+    refresh_search_list: bool,
+}
+
+/// Owns the only index writer of the search index.
+/// Changes are applied on a background thread and committed in batches,
+/// because creating a writer and committing on every change is expensive
+#[derive(Clone)]
+pub struct SearchIndexWriter {
+    sender: mpsc::Sender<WriterCommand>,
+}
+
+impl SearchIndexWriter {
+    /// `on_commit` is called after every commit, when committed changes are already visible to `index_reader`,
+    /// its argument is true if any of committed changes requested refresh of search list
+    pub fn new(
+        index: &Index,
+        index_reader: IndexReader,
+        on_commit: impl Fn(bool) + Send + 'static,
+    ) -> anyhow::Result<Self> {
+        let index_writer = index.writer::<TantivyDocument>(15_000_000)?;
+
+        let (sender, receiver) = mpsc::channel();
+
+        thread::Builder::new()
+            .name("gauntlet-search-index-writer".to_string())
+            .spawn(move || run_writer(index_writer, index_reader, receiver, on_commit))?;
+
+        Ok(Self { sender })
+    }
+
+    /// Deletes documents matching `delete` query and adds `documents`, both become searchable after the next commit
+    pub fn update(&self, delete: Box<dyn Query>, documents: Vec<TantivyDocument>, refresh_search_list: bool) {
+        let command = WriterCommand::Update {
+            delete,
+            documents,
+            refresh_search_list,
+        };
+
+        if self.sender.send(command).is_err() {
+            tracing::error!("search index writer is stopped, search index update is lost");
+        }
+    }
+
+    /// Commits pending changes right away and waits until they are visible to searches
+    #[cfg(test)]
+    pub fn flush(&self) {
+        let (sender, receiver) = mpsc::channel();
+
+        if self.sender.send(WriterCommand::Flush(sender)).is_err() {
+            tracing::error!("search index writer is stopped, unable to flush search index");
+            return;
+        }
+
+        let _ = receiver.recv();
+    }
+}
+
+fn run_writer(
+    mut index_writer: IndexWriter<TantivyDocument>,
+    index_reader: IndexReader,
+    receiver: mpsc::Receiver<WriterCommand>,
+    on_commit: impl Fn(bool),
+) {
+    let mut pending: Option<PendingCommit> = None;
+
+    loop {
+        let command = match &pending {
+            None => receiver.recv().map_err(|_| RecvTimeoutError::Disconnected),
+            Some(pending) => {
+                let deadline = (pending.last_change + COMMIT_DEBOUNCE).min(pending.first_change + COMMIT_MAX_DELAY);
+
+                receiver.recv_timeout(deadline.saturating_duration_since(Instant::now()))
+            }
+        };
+
+        match command {
+            Ok(WriterCommand::Update {
+                delete,
+                documents,
+                refresh_search_list,
+            }) => {
+                if let Err(err) = apply(&mut index_writer, delete, documents) {
+                    tracing::error!("error when updating search index: {:?}", err);
+                }
+
+                let now = Instant::now();
+
+                let pending = pending.get_or_insert(PendingCommit {
+                    first_change: now,
+                    last_change: now,
+                    refresh_search_list: false,
+                });
+
+                pending.last_change = now;
+                pending.refresh_search_list |= refresh_search_list;
+            }
+            #[cfg(test)]
+            Ok(WriterCommand::Flush(done)) => {
+                commit(&mut index_writer, &index_reader, pending.take(), &on_commit);
+
+                let _ = done.send(());
+            }
+            Err(RecvTimeoutError::Timeout) => {
+                commit(&mut index_writer, &index_reader, pending.take(), &on_commit);
+            }
+            Err(RecvTimeoutError::Disconnected) => {
+                commit(&mut index_writer, &index_reader, pending.take(), &on_commit);
+
+                break;
+            }
+        }
+    }
+}
+
+fn apply(
+    index_writer: &mut IndexWriter<TantivyDocument>,
+    delete: Box<dyn Query>,
+    documents: Vec<TantivyDocument>,
+) -> tantivy::Result<()> {
+    index_writer.delete_query(delete)?;
+
+    for document in documents {
+        index_writer.add_document(document)?;
+    }
+
+    Ok(())
+}
+
+fn commit(
+    index_writer: &mut IndexWriter<TantivyDocument>,
+    index_reader: &IndexReader,
+    pending: Option<PendingCommit>,
+    on_commit: &impl Fn(bool),
+) {
+    let Some(pending) = pending else {
+        return;
+    };
+
+    let result = index_writer.commit().and_then(|_| index_reader.reload());
+
+    match result {
+        Ok(()) => on_commit(pending.refresh_search_list),
+        Err(err) => {
+            tracing::error!("error when committing search index: {:?}", err);
+        }
+    }
+}